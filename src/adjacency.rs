@@ -0,0 +1,135 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::{Coordinate, MatrixAddress};
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+use crate::iter::MatrixForwardIterator;
+
+/// AdjacencyMatrix is a graph derived from the cells of a matrix, together with
+/// the table relating node numbers to the addresses of the cells they represent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdjacencyMatrix<I, J>
+where
+    I: Coordinate,
+    J: Coordinate,
+{
+    pub(crate) matrix: DenseMatrix<bool, J>,
+    pub(crate) addresses: Vec<MatrixAddress<I>>,
+    pub(crate) columns: I,
+}
+
+impl <I, J> AdjacencyMatrix<I, J>
+where
+    I: Coordinate,
+    J: Coordinate,
+{
+    /// matrix returns the n x n adjacency matrix, in which (a, b) is true when
+    /// there is an edge from node a to node b.
+    pub fn matrix(&self) -> &DenseMatrix<bool, J> {
+        &self.matrix
+    }
+
+    /// into_matrix consumes the table, returning the adjacency matrix.
+    pub fn into_matrix(self) -> DenseMatrix<bool, J> {
+        self.matrix
+    }
+
+    /// addresses returns the address of each node, indexed by node number.
+    pub fn addresses(&self) -> &[MatrixAddress<I>] {
+        &self.addresses
+    }
+
+    /// address returns the address of the cell represented by node.
+    pub fn address(&self, node: J) -> Option<MatrixAddress<I>> {
+        let index: usize = node.try_into().ok()?;
+        self.addresses.get(index).copied()
+    }
+
+    /// node returns the node number representing the cell at address.
+    pub fn node(&self, address: MatrixAddress<I>) -> Option<J> {
+        let row: usize = address.row.try_into().ok()?;
+        let column: usize = address.column.try_into().ok()?;
+        let columns: usize = self.columns.try_into().ok()?;
+        if column >= columns {
+            return None;
+        }
+        let index = row * columns + column;
+        if index >= self.addresses.len() {
+            return None;
+        }
+        index.try_into().ok()
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    /// adjacency_matrix treats every cell as a graph node, numbered by its
+    /// row-major index, and returns an n x n matrix in which (a, b) is true when
+    /// relation(address of a, value of a, address of b, value of b) is true,
+    /// along with the table relating node numbers and addresses.  relation is
+    /// evaluated for every ordered pair of distinct cells.  An error is returned
+    /// if the node count cannot be represented by J.
+    pub fn adjacency_matrix<J>(&self, relation: impl Fn(MatrixAddress<I>, &T, MatrixAddress<I>, &T) -> bool) -> Result<AdjacencyMatrix<I, J>>
+    where
+        J: Coordinate,
+    {
+        let addresses: Vec<MatrixAddress<I>> = MatrixForwardIterator::new(MatrixAddress { row: self.rows, column: self.columns }).collect();
+        let n = addresses.len();
+        let nodes: J = match n.try_into() {
+            Ok(v) => v,
+            Err(_) => return Err(Error::new("node count overflows adjacency index type".to_string())),
+        };
+        let len = match n.checked_mul(n) {
+            Some(v) => v,
+            None => return Err(Error::new("adjacency matrix size overflows usize".to_string())),
+        };
+        let mut data = vec![false; len];
+        for (a, (a_addr, a_value)) in addresses.iter().zip(self.data.iter()).enumerate() {
+            for (b, (b_addr, b_value)) in addresses.iter().zip(self.data.iter()).enumerate() {
+                if a != b && relation(*a_addr, a_value, *b_addr, b_value) {
+                    data[a * n + b] = true;
+                }
+            }
+        }
+        Ok(AdjacencyMatrix {
+            matrix: DenseMatrix::new(nodes, nodes, data),
+            addresses,
+            columns: self.columns,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Tensor};
+    use crate::format::FormatOptions;
+    use super::*;
+
+    #[test]
+    fn orthogonal_open_cells() {
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>("..\n#.", |x| x.chars().next().unwrap())
+            .unwrap();
+        let graph = m.adjacency_matrix::<u8>(|a, av, b, bv| {
+            let distance = a.row.abs_diff(b.row) + a.column.abs_diff(b.column);
+            *av == '.' && *bv == '.' && distance == 1
+        }).unwrap();
+        assert_eq!(graph.matrix().row_count(), 4);
+        assert_eq!(graph.address(3), Some(MatrixAddress { row: 1, column: 1 }));
+        assert_eq!(graph.addresses().len(), 4);
+        let got = FormatOptions::default().format(graph.matrix(), |b| if *b { "1".to_string() } else { "0".to_string() });
+        assert_eq!(got, "0100\n1001\n0000\n0100");
+        let from = graph.node(MatrixAddress { row: 0, column: 1 }).unwrap();
+        assert_eq!(graph.matrix().get(MatrixAddress { row: 0, column: from }), Some(&true));
+        assert_eq!(graph.node(MatrixAddress { row: 2, column: 0 }), None);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn node_count_overflow() {
+        let m = crate::factories::new_default_matrix::<u8, u8>(16, 16).unwrap();
+        assert!(m.adjacency_matrix::<u8>(|_, _, _, _| false).is_err());
+    }
+}
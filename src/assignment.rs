@@ -0,0 +1,231 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::Add;
+use crate::{Coordinate, Matrix};
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Copy + Default + Add<Output = T> + TryInto<i128> + 'static,
+    I: Coordinate,
+{
+    /// min_cost_assignment treats the matrix as a table of costs, cell (i, j)
+    /// being the cost of giving row i task j, and returns the pairing of rows
+    /// with distinct columns of least total cost, sorted by row, along with that
+    /// cost.  When the matrix is not square, every cell of the shorter dimension
+    /// is paired and the rest of the longer one goes unused.  It uses the
+    /// Hungarian algorithm, which runs in O(n^2 m) for n <= m, computing in
+    /// i128 so that unsigned costs work too.  It is an error if a cost does not
+    /// fit in an i128.
+    pub fn min_cost_assignment(&self) -> Result<(Vec<(I, I)>, T)> {
+        let (rows, columns) = (dimension(self.rows), dimension(self.columns));
+        let wide: Vec<i128> = self.data.iter()
+            .zip(self.addresses())
+            .map(|(v, address)| (*v).try_into().map_err(|_| Error::new(format!("cost at {} does not fit in i128", address))))
+            .collect::<Result<_>>()?;
+        let cost = |r: usize, c: usize| wide[r * columns + c];
+        let mut pairs: Vec<(usize, usize)> = if rows <= columns {
+            hungarian(rows, columns, cost)
+        } else {
+            hungarian(columns, rows, |r, c| cost(c, r)).into_iter().map(|(c, r)| (r, c)).collect()
+        };
+        pairs.sort();
+        let total = pairs.iter().fold(T::default(), |sum, (r, c)| sum + self.data[r * columns + c]);
+        let pairs = pairs.into_iter()
+            .map(|(r, c)| match (r.try_into(), c.try_into()) {
+                (Ok(r), Ok(c)) => (r, c),
+                _ => panic!("index overflows index type.  This should be unreachable."),
+            })
+            .collect();
+        Ok((pairs, total))
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    /// eliminate_unique deduces a one-to-one pairing of rows with columns from
+    /// a matrix of candidates, cell (i, j) being a candidate when
+    /// candidate_pred is true for it.  It repeatedly locks any row with a single
+    /// remaining candidate and removes that column from every other row; for
+    /// square matrices it likewise locks any column with a single remaining
+    /// candidate.  The pairs are returned sorted by row.  It is an error if a
+    /// row runs out of candidates or the deduction stalls before every row is
+    /// paired.
+    pub fn eliminate_unique(&self, candidate_pred: impl Fn(&T) -> bool) -> Result<Vec<(I, I)>> {
+        let (rows, columns) = (dimension(self.rows), dimension(self.columns));
+        let mut candidates: Vec<bool> = self.data.iter().map(candidate_pred).collect();
+        let mut paired: Vec<Option<usize>> = vec![None; rows];
+        let mut column_taken = vec![false; columns];
+        let mut remaining = rows;
+        while remaining > 0 {
+            let mut lock = None;
+            for row in (0..rows).filter(|r| paired[*r].is_none()) {
+                let mut open = (0..columns).filter(|c| candidates[row * columns + c]);
+                match (open.next(), open.next()) {
+                    (None, _) => return Err(Error::new(format!("row {} has no remaining candidates", row))),
+                    (Some(column), None) => {
+                        lock = Some((row, column));
+                        break;
+                    },
+                    _ => {},
+                }
+            }
+            if lock.is_none() && rows == columns {
+                lock = (0..columns).filter(|c| !column_taken[*c]).find_map(|column| {
+                    let mut open = (0..rows).filter(|r| paired[*r].is_none() && candidates[r * columns + column]);
+                    match (open.next(), open.next()) {
+                        (Some(row), None) => Some((row, column)),
+                        _ => None,
+                    }
+                });
+            }
+            let (row, column) = match lock {
+                Some(lock) => lock,
+                None => return Err(Error::new(format!("elimination stalled with {} rows unresolved", remaining))),
+            };
+            for c in 0..columns {
+                candidates[row * columns + c] = c == column;
+            }
+            for r in (0..rows).filter(|r| *r != row) {
+                candidates[r * columns + column] = false;
+            }
+            paired[row] = Some(column);
+            column_taken[column] = true;
+            remaining -= 1;
+        }
+        Ok(paired.into_iter()
+            .enumerate()
+            .filter_map(|(row, column)| match (row.try_into(), column?.try_into()) {
+                (Ok(r), Ok(c)) => Some((r, c)),
+                _ => panic!("index overflows index type.  This should be unreachable."),
+            })
+            .collect())
+    }
+}
+
+// hungarian assigns each of n rows a distinct one of m >= n columns at least
+// total cost, using row and column potentials, and returns (row, column) pairs.
+fn hungarian(n: usize, m: usize, cost: impl Fn(usize, usize) -> i128) -> Vec<(usize, usize)> {
+    const INFINITY: i128 = i128::MAX / 4;
+    // Rows and columns are numbered from 1 here; column 0 is a sentinel whose
+    // assigned row is the one being added.
+    let mut u = vec![0i128; n + 1];
+    let mut v = vec![0i128; m + 1];
+    let mut assigned = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+    for row in 1..=n {
+        assigned[0] = row;
+        let mut column = 0;
+        let mut slack = vec![INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[column] = true;
+            let current = assigned[column];
+            let (mut delta, mut next) = (INFINITY, 0);
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let reduced = cost(current - 1, j - 1) - u[current] - v[j];
+                if reduced < slack[j] {
+                    slack[j] = reduced;
+                    way[j] = column;
+                }
+                if slack[j] < delta {
+                    delta = slack[j];
+                    next = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[assigned[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    slack[j] -= delta;
+                }
+            }
+            column = next;
+            if assigned[column] == 0 {
+                break;
+            }
+        }
+        // Flip the alternating path back to the sentinel.
+        while column != 0 {
+            let previous = way[column];
+            assigned[column] = assigned[previous];
+            column = previous;
+        }
+    }
+    (1..=m).filter(|j| assigned[*j] != 0).map(|j| (assigned[j] - 1, j - 1)).collect()
+}
+
+fn dimension<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("dimension overflows usize.  This should be unreachable."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::factories::new_matrix;
+
+    #[test]
+    fn square_assignment() {
+        let costs = new_matrix::<u32, u8>(3, vec![
+            4, 1, 3,
+            2, 0, 5,
+            3, 2, 2,
+        ]).unwrap();
+        assert_eq!(costs.min_cost_assignment().unwrap(), (vec![(0, 1), (1, 0), (2, 2)], 5));
+        let negative = new_matrix::<i64, u8>(2, vec![-5, 0, 0, -5]).unwrap();
+        assert_eq!(negative.min_cost_assignment().unwrap(), (vec![(0, 0), (1, 1)], -10));
+    }
+
+    #[test]
+    fn eliminate_unique_candidates() {
+        // Rows lock in turn: row 2 has one candidate, which settles row 0,
+        // which settles row 1.
+        let fits = new_matrix::<bool, u8>(3, vec![
+            true, false, true,
+            true, true, true,
+            false, false, true,
+        ]).unwrap();
+        assert_eq!(fits.eliminate_unique(|b| *b).unwrap(), vec![(0, 0), (1, 1), (2, 2)]);
+        // No row is forced, but column 1 has a single candidate.
+        let columns = new_matrix::<u8, u8>(2, vec![1, 1, 1, 0]).unwrap();
+        assert_eq!(columns.eliminate_unique(|v| *v == 1).unwrap(), vec![(0, 1), (1, 0)]);
+        let stalled = new_matrix::<bool, u8>(2, vec![true; 4]).unwrap();
+        assert!(stalled.eliminate_unique(|b| *b).is_err());
+        let impossible = new_matrix::<bool, u8>(2, vec![true, false, true, false]).unwrap();
+        assert!(impossible.eliminate_unique(|b| *b).is_err());
+    }
+
+    #[test]
+    fn rectangular_assignment() {
+        let wide = new_matrix::<u8, u8>(2, vec![
+            9, 1, 9, 9,
+            9, 9, 9, 2,
+        ]).unwrap();
+        assert_eq!(wide.min_cost_assignment().unwrap(), (vec![(0, 1), (1, 3)], 3));
+        let tall = wide.transposed();
+        assert_eq!(tall.min_cost_assignment().unwrap(), (vec![(1, 0), (3, 1)], 3));
+        let empty = new_matrix::<u8, u8>(0, vec![]).unwrap();
+        assert_eq!(empty.min_cost_assignment().unwrap(), (vec![], 0));
+    }
+
+    #[test]
+    fn wide_cost_types() {
+        let sizes = new_matrix::<usize, u8>(2, vec![3, 1, 1, 3]).unwrap();
+        assert_eq!(sizes.min_cost_assignment().unwrap(), (vec![(0, 1), (1, 0)], 2));
+        let huge = new_matrix::<u128, u8>(1, vec![u128::MAX, 0]).unwrap();
+        assert_eq!(
+            huge.min_cost_assignment().err(),
+            Some(Error::new("cost at (row=0,col=0) does not fit in i128".to_string()))
+        );
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::Range;
+use crate::{Coordinate, DenseMatrix, Matrix, MatrixAddress, Tensor};
+use crate::error::Result;
+
+/// AttributedMatrix pairs a matrix of values with a matrix of per-cell
+/// attributes, such as visited flags or distances, that always has the same
+/// shape.  Crops and transposes apply to both together, so the two cannot
+/// drift out of sync.  Build one with new_attributed_matrix or
+/// new_attributed_matrix_default.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttributedMatrix<T, A, I>
+where
+    I: Coordinate,
+{
+    pub(crate) values: DenseMatrix<T, I>,
+    pub(crate) attributes: DenseMatrix<A, I>,
+}
+
+impl <T, A, I> AttributedMatrix<T, A, I>
+where
+    T: 'static,
+    A: 'static,
+    I: Coordinate,
+{
+    /// values returns the matrix of values.
+    pub fn values(&self) -> &DenseMatrix<T, I> {
+        &self.values
+    }
+
+    /// attributes returns the matrix of attributes.
+    pub fn attributes(&self) -> &DenseMatrix<A, I> {
+        &self.attributes
+    }
+
+    /// into_parts returns the values and attributes as separate matrices.
+    pub fn into_parts(self) -> (DenseMatrix<T, I>, DenseMatrix<A, I>) {
+        (self.values, self.attributes)
+    }
+
+    /// value returns the value at address, or None if it is out of bounds.
+    pub fn value(&self, address: MatrixAddress<I>) -> Option<&T> {
+        self.values.get(address)
+    }
+
+    /// value_mut returns the value at address for modification.
+    pub fn value_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        self.values.get_mut(address)
+    }
+
+    /// attr returns the attribute at address, or None if it is out of bounds.
+    pub fn attr(&self, address: MatrixAddress<I>) -> Option<&A> {
+        self.attributes.get(address)
+    }
+
+    /// attr_mut returns the attribute at address for modification.
+    pub fn attr_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut A> {
+        self.attributes.get_mut(address)
+    }
+
+    /// get returns the value and attribute at address together.
+    pub fn get(&self, address: MatrixAddress<I>) -> Option<(&T, &A)> {
+        Some((self.values.get(address)?, self.attributes.get(address)?))
+    }
+
+    /// get_mut returns the value and attribute at address for modification.
+    pub fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<(&mut T, &mut A)> {
+        Some((self.values.get_mut(address)?, self.attributes.get_mut(address)?))
+    }
+
+    /// iter returns each cell's address, value and attribute in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (MatrixAddress<I>, &T, &A)> {
+        self.values.addresses()
+            .zip(self.values.data.iter().zip(self.attributes.data.iter()))
+            .map(|(addr, (value, attr))| (addr, value, attr))
+    }
+
+    /// iter_mut returns each cell's address, value and attribute in row-major
+    /// order, for modification.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (MatrixAddress<I>, &mut T, &mut A)> {
+        self.values.addresses()
+            .zip(self.values.data.iter_mut().zip(self.attributes.data.iter_mut()))
+            .map(|(addr, (value, attr))| (addr, value, attr))
+    }
+}
+
+impl <T, A, I> AttributedMatrix<T, A, I>
+where
+    T: Clone + 'static,
+    A: Clone + 'static,
+    I: Coordinate,
+{
+    /// transposed returns a copy with the rows and columns of both matrices
+    /// swapped.
+    pub fn transposed(&self) -> AttributedMatrix<T, A, I> {
+        AttributedMatrix { values: self.values.transposed(), attributes: self.attributes.transposed() }
+    }
+
+    /// cropped returns a copy of a non-empty, in-bounds range of cells from
+    /// both matrices.
+    pub fn cropped(&self, range: Range<MatrixAddress<I>>) -> Result<AttributedMatrix<T, A, I>> {
+        self.values.check_window(&range)?;
+        Ok(AttributedMatrix {
+            values: self.values.copy_window(range.clone()),
+            attributes: self.attributes.copy_window(range),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_attributed_matrix, new_attributed_matrix_default, new_matrix};
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    #[test]
+    fn values_and_attributes_stay_aligned() {
+        let values = new_matrix::<char, u8>(2, vec!['a', 'b', 'c', 'd', 'e', 'f']).unwrap();
+        let mut m = new_attributed_matrix_default::<char, Option<u32>, u8>(values.clone()).unwrap();
+        *m.attr_mut(u8addr(1, 2)).unwrap() = Some(7);
+        assert_eq!(m.get(u8addr(1, 2)), Some((&'f', &Some(7))));
+        assert_eq!(m.attr(u8addr(2, 0)), None);
+        for (addr, value, attr) in m.iter_mut() {
+            if addr.row == 0 {
+                *value = value.to_ascii_uppercase();
+                *attr = Some(0);
+            }
+        }
+        let t = m.transposed();
+        assert_eq!(t.get(u8addr(2, 1)), Some((&'f', &Some(7))));
+        assert_eq!(t.get(u8addr(2, 0)), Some((&'C', &Some(0))));
+        let cropped = m.cropped(u8addr(0, 1)..u8addr(2, 2)).unwrap();
+        let cells: Vec<(char, Option<u32>)> = cropped.iter().map(|(_, v, a)| (*v, *a)).collect();
+        assert_eq!(cells, vec![('B', Some(0)), ('e', None)]);
+        assert!(m.cropped(u8addr(0, 0)..u8addr(0, 2)).is_err());
+        let short = new_matrix::<bool, u8>(1, vec![false; 6]).unwrap();
+        assert!(new_attributed_matrix(values, short).is_err());
+    }
+}
@@ -0,0 +1,571 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Index, Range};
+use crate::{Coordinate, Matrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixRowsIterator, MatrixValueIterator, Tensor};
+use crate::column::Column;
+use crate::row::Row;
+
+pub(crate) const WORD_BITS: usize = u64::BITS as usize;
+
+/// BitMatrix is a boolean matrix storing one bit per cell.  Each row starts on
+/// a fresh u64 word, and any padding bits at the end of a row are always zero.
+///
+/// A single bit cannot be borrowed mutably, so get_mut returns None and the
+/// matrix does not implement MatrixMut.  Use set and toggle to modify cells.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BitMatrix<I>
+where
+    I: Coordinate,
+{
+    pub(crate) columns: I,
+    pub(crate) rows: I,
+    pub(crate) words_per_row: usize,
+    pub(crate) words: Vec<u64>,
+}
+
+impl <I> BitMatrix<I>
+where
+    I: Coordinate,
+{
+    /// bit_position returns the word index and bit offset of an in-bounds address.
+    pub(crate) fn bit_position(&self, address: MatrixAddress<I>) -> (usize, usize) {
+        match (address.row.try_into(), address.column.try_into()) {
+            (Ok(row), Ok(column)) => {
+                let row: usize = row;
+                let column: usize = column;
+                (row * self.words_per_row + column / WORD_BITS, column % WORD_BITS)
+            },
+            _ => panic!("address overflows usize.  This should be unreachable."),
+        }
+    }
+
+    /// bit returns the value of a cell, or None for out of bounds addresses.
+    pub fn bit(&self, address: MatrixAddress<I>) -> Option<bool> {
+        if !self.contains(address) {
+            return None;
+        }
+        let (word, offset) = self.bit_position(address);
+        Some(self.words[word] >> offset & 1 == 1)
+    }
+
+    /// set writes a cell.  Returns false for out of bounds addresses.
+    pub fn set(&mut self, address: MatrixAddress<I>, value: bool) -> bool {
+        if !self.contains(address) {
+            return false;
+        }
+        let (word, offset) = self.bit_position(address);
+        if value {
+            self.words[word] |= 1 << offset;
+        } else {
+            self.words[word] &= !(1 << offset);
+        }
+        true
+    }
+
+    /// toggle inverts a cell.  Returns false for out of bounds addresses.
+    pub fn toggle(&mut self, address: MatrixAddress<I>) -> bool {
+        if !self.contains(address) {
+            return false;
+        }
+        let (word, offset) = self.bit_position(address);
+        self.words[word] ^= 1 << offset;
+        true
+    }
+
+    /// count_ones returns the number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// iter_non_default returns the addresses of the set cells, paired with true,
+    /// in row-major order.  Clear words are skipped without visiting their cells.
+    pub fn iter_non_default(&self) -> std::vec::IntoIter<(MatrixAddress<I>, &bool)> {
+        let mut set = Vec::with_capacity(self.count_ones());
+        for (index, word) in self.words.iter().enumerate() {
+            let mut bits = *word;
+            while bits != 0 {
+                let offset = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                let row = index / self.words_per_row;
+                let column = (index % self.words_per_row) * WORD_BITS + offset;
+                match (row.try_into(), column.try_into()) {
+                    (Ok(row), Ok(column)) => set.push((MatrixAddress { row, column }, &true)),
+                    _ => panic!("address overflows index type.  This should be unreachable."),
+                }
+            }
+        }
+        set.into_iter()
+    }
+
+    /// fill sets every cell to value.
+    pub fn fill(&mut self, value: bool) {
+        if !value {
+            self.words.iter_mut().for_each(|w| *w = 0);
+            return;
+        }
+        let columns: usize = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        for row in self.words.chunks_exact_mut(self.words_per_row.max(1)) {
+            for (i, word) in row.iter_mut().enumerate() {
+                let used = columns.saturating_sub(i * WORD_BITS).min(WORD_BITS);
+                *word = if used == WORD_BITS { u64::MAX } else { (1 << used) - 1 };
+            }
+        }
+    }
+
+    /// row_nonzero_counts returns the number of set cells in each row, counted
+    /// a word at a time.
+    pub fn row_nonzero_counts(&self) -> Vec<usize> {
+        self.words.chunks_exact(self.words_per_row.max(1))
+            .map(|row| row.iter().map(|w| w.count_ones() as usize).sum())
+            .collect()
+    }
+
+    /// column_nonzero_counts returns the number of set cells in each column.
+    /// Clear words are skipped without visiting their cells.
+    pub fn column_nonzero_counts(&self) -> Vec<usize> {
+        let columns: usize = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        let mut counts = vec![0; columns];
+        for (index, word) in self.words.iter().enumerate() {
+            let mut bits = *word;
+            while bits != 0 {
+                let offset = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+                counts[(index % self.words_per_row) * WORD_BITS + offset] += 1;
+            }
+        }
+        counts
+    }
+
+    /// degree returns the number of set cells in row node, the out-degree of
+    /// node when the matrix is an adjacency matrix.  Out of range nodes have
+    /// degree 0.
+    pub fn degree(&self, node: I) -> usize {
+        if node < I::default() || node >= self.rows {
+            return 0;
+        }
+        let (start, _) = self.bit_position(MatrixAddress { row: node, column: I::default() });
+        self.words[start..start + self.words_per_row].iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// rank_select builds per-row counts of set cells, answering rank and
+    /// select queries without rescanning the matrix.  The index borrows the
+    /// matrix, so it cannot go stale.  To query a DenseMatrix of bools, pack
+    /// it with new_bit_matrix_from first.
+    pub fn rank_select(&self) -> RankSelect<'_, I> {
+        let mut before_row = Vec::with_capacity(self.words.len() / self.words_per_row.max(1) + 1);
+        let mut total = 0;
+        before_row.push(0);
+        for row in self.words.chunks_exact(self.words_per_row.max(1)) {
+            total += row.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+            before_row.push(total);
+        }
+        RankSelect { matrix: self, before_row }
+    }
+
+    /// shift_left returns a copy with every cell moved n columns left.  Cells
+    /// shifted past the edge are lost, and vacated cells are clear.
+    pub fn shift_left(&self, n: usize) -> BitMatrix<I> {
+        let mut shifted = self.clone();
+        shifted.shift_left_mut(n);
+        shifted
+    }
+
+    /// shift_right returns a copy with every cell moved n columns right.
+    /// Cells shifted past the edge are lost, and vacated cells are clear.
+    pub fn shift_right(&self, n: usize) -> BitMatrix<I> {
+        let mut shifted = self.clone();
+        shifted.shift_right_mut(n);
+        shifted
+    }
+
+    /// shift_up returns a copy with every cell moved n rows up.  Cells shifted
+    /// past the edge are lost, and vacated cells are clear.
+    pub fn shift_up(&self, n: usize) -> BitMatrix<I> {
+        let mut shifted = self.clone();
+        shifted.shift_up_mut(n);
+        shifted
+    }
+
+    /// shift_down returns a copy with every cell moved n rows down.  Cells
+    /// shifted past the edge are lost, and vacated cells are clear.
+    pub fn shift_down(&self, n: usize) -> BitMatrix<I> {
+        let mut shifted = self.clone();
+        shifted.shift_down_mut(n);
+        shifted
+    }
+
+    /// shift_left_mut moves every cell n columns left in place, a word at a
+    /// time with carries between the words of a row.
+    pub fn shift_left_mut(&mut self, n: usize) {
+        let (skip, offset) = (n / WORD_BITS, n % WORD_BITS);
+        for row in self.words.chunks_exact_mut(self.words_per_row.max(1)) {
+            for i in 0..row.len() {
+                let low = row.get(i + skip).map_or(0, |w| w >> offset);
+                let high = match row.get(i + skip + 1) {
+                    Some(w) if offset > 0 => w << (WORD_BITS - offset),
+                    _ => 0,
+                };
+                row[i] = low | high;
+            }
+        }
+    }
+
+    /// shift_right_mut moves every cell n columns right in place, a word at a
+    /// time with carries between the words of a row.
+    pub fn shift_right_mut(&mut self, n: usize) {
+        let (skip, offset) = (n / WORD_BITS, n % WORD_BITS);
+        for row in self.words.chunks_exact_mut(self.words_per_row.max(1)) {
+            for i in (0..row.len()).rev() {
+                let high = i.checked_sub(skip).map_or(0, |j| row[j] << offset);
+                let low = match i.checked_sub(skip + 1) {
+                    Some(j) if offset > 0 => row[j] >> (WORD_BITS - offset),
+                    _ => 0,
+                };
+                row[i] = high | low;
+            }
+        }
+        self.clear_padding();
+    }
+
+    /// shift_up_mut moves every cell n rows up in place.
+    pub fn shift_up_mut(&mut self, n: usize) {
+        let moved = n.saturating_mul(self.words_per_row).min(self.words.len());
+        self.words.copy_within(moved.., 0);
+        let kept = self.words.len() - moved;
+        self.words[kept..].fill(0);
+    }
+
+    /// shift_down_mut moves every cell n rows down in place.
+    pub fn shift_down_mut(&mut self, n: usize) {
+        let moved = n.saturating_mul(self.words_per_row).min(self.words.len());
+        let kept = self.words.len() - moved;
+        self.words.copy_within(..kept, moved);
+        self.words[..moved].fill(0);
+    }
+
+    // clear_padding zeroes the bits past the last column of each row.
+    fn clear_padding(&mut self) {
+        let columns: usize = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        let used = columns % WORD_BITS;
+        if used == 0 {
+            return;
+        }
+        for row in self.words.chunks_exact_mut(self.words_per_row.max(1)) {
+            if let Some(last) = row.last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+}
+
+/// RankSelect answers reading-order rank and select queries over the set
+/// cells of a BitMatrix.  Build one with BitMatrix::rank_select.
+pub struct RankSelect<'a, I>
+where
+    I: Coordinate,
+{
+    matrix: &'a BitMatrix<I>,
+    // before_row[r] is the number of set cells in rows before r; the last entry
+    // is the total.
+    before_row: Vec<usize>,
+}
+
+impl <'a, I> RankSelect<'a, I>
+where
+    I: Coordinate,
+{
+    /// count returns the number of set cells.
+    pub fn count(&self) -> usize {
+        self.before_row.last().copied().unwrap_or_default()
+    }
+
+    /// rank returns the number of set cells before address in reading order.
+    /// None is returned for out of bounds addresses.
+    pub fn rank(&self, address: MatrixAddress<I>) -> Option<usize> {
+        if !self.matrix.contains(address) {
+            return None;
+        }
+        let (word, offset) = self.matrix.bit_position(address);
+        let row = word / self.matrix.words_per_row;
+        let row_start = row * self.matrix.words_per_row;
+        let whole: usize = self.matrix.words[row_start..word].iter().map(|w| w.count_ones() as usize).sum();
+        let partial = (self.matrix.words[word] & ((1u64 << offset) - 1)).count_ones() as usize;
+        Some(self.before_row[row] + whole + partial)
+    }
+
+    /// select returns the address of the set cell with rank n, counting from
+    /// zero in reading order.  None is returned if fewer than n + 1 cells are set.
+    pub fn select(&self, n: usize) -> Option<MatrixAddress<I>> {
+        if n >= self.count() {
+            return None;
+        }
+        let row = self.before_row.partition_point(|before| *before <= n) - 1;
+        let mut remaining = n - self.before_row[row];
+        let row_start = row * self.matrix.words_per_row;
+        for (i, word) in self.matrix.words[row_start..row_start + self.matrix.words_per_row].iter().enumerate() {
+            let ones = word.count_ones() as usize;
+            if remaining < ones {
+                let mut bits = *word;
+                for _ in 0..remaining {
+                    bits &= bits - 1;
+                }
+                let column = i * WORD_BITS + bits.trailing_zeros() as usize;
+                return match (row.try_into(), column.try_into()) {
+                    (Ok(row), Ok(column)) => Some(MatrixAddress { row, column }),
+                    _ => panic!("address overflows index type.  This should be unreachable."),
+                };
+            }
+            remaining -= ones;
+        }
+        None
+    }
+}
+
+impl <'a, I> Matrix<'a, bool, I> for BitMatrix<I>
+where
+    I: Coordinate,
+{
+    fn row_count(&self) -> I {
+        self.rows
+    }
+
+    fn column_count(&self) -> I {
+        self.columns
+    }
+
+    fn iter(&'a self) -> MatrixValueIterator<'a, bool, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        MatrixForwardIterator::new(MatrixAddress {
+            column: self.columns,
+            row: self.rows,
+        })
+    }
+
+    fn indexed_iter(&'a self) -> MatrixForwardIndexedIterator<'a, bool, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'a self, row_num: I) -> Option<Row<'a, bool, I>> {
+        if row_num < I::default() || row_num >= self.rows {
+            None
+        } else {
+            Some(Row::new(self, row_num))
+        }
+    }
+
+    fn column(&'a self, column_num: I) -> Option<Column<'a, bool, I>> {
+        if column_num < I::default() || column_num >= self.columns {
+            None
+        } else {
+            Some(Column::new(self, column_num))
+        }
+    }
+
+    fn rows(&'a self) -> MatrixRowsIterator<'a, bool, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'a self) -> MatrixColumnsIterator<'a, bool, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+impl <I> Tensor<bool, I, MatrixAddress<I>, 2> for BitMatrix<I>
+where
+    I: Coordinate,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        Range {
+            start: MatrixAddress::default(),
+            end: MatrixAddress {
+                column: self.columns,
+                row: self.rows,
+            },
+        }
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&bool> {
+        self.bit(address).map(|b| if b { &true } else { &false })
+    }
+
+    /// get_mut always returns None, as a single bit cannot be borrowed mutably.
+    fn get_mut(&mut self, _address: MatrixAddress<I>) -> Option<&mut bool> {
+        None
+    }
+}
+
+impl<I> Index<MatrixAddress<I>> for BitMatrix<I>
+where
+    I: Coordinate,
+{
+    type Output = bool;
+
+    fn index(&self, index: MatrixAddress<I>) -> &Self::Output {
+        match self.get(index) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::factories::{new_bit_matrix, new_bit_matrix_from, new_bit_matrix_of};
+    use crate::format::FormatOptions;
+    use crate::shape::Shape;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    #[test]
+    fn nonzero_counts_and_degree() {
+        let mut m = new_bit_matrix::<u8>(3, 70).unwrap();
+        m.set(u8addr(0, 0), true);
+        m.set(u8addr(0, 69), true);
+        m.set(u8addr(2, 69), true);
+        assert_eq!(m.row_nonzero_counts(), vec![2, 0, 1]);
+        let columns = m.column_nonzero_counts();
+        assert_eq!(columns.len(), 70);
+        assert_eq!((columns[0], columns[1], columns[69]), (1, 0, 2));
+        assert_eq!((m.degree(0), m.degree(1), m.degree(3)), (2, 0, 0));
+        let dense = m.to_dense();
+        assert_eq!(dense.row_nonzero_counts(), m.row_nonzero_counts());
+        assert_eq!(dense.column_nonzero_counts(), columns);
+        assert_eq!(dense.degree(2), 1);
+    }
+
+    #[test]
+    fn set_and_read_bits() {
+        let mut m = new_bit_matrix::<u8>(3, 70).unwrap();
+        assert_eq!(m.words.len(), 6);
+        assert!(m.set(u8addr(1, 65), true));
+        assert!(m.set(u8addr(2, 0), true));
+        assert!(!m.set(u8addr(3, 0), true));
+        assert_eq!(m.bit(u8addr(1, 65)), Some(true));
+        assert_eq!(m.bit(u8addr(1, 64)), Some(false));
+        assert_eq!(m.bit(u8addr(0, 70)), None);
+        assert!(m[u8addr(2, 0)]);
+        assert!(m.toggle(u8addr(2, 0)));
+        assert!(!m[u8addr(2, 0)]);
+        assert_eq!(m.count_ones(), 1);
+        let set: Vec<MatrixAddress<u8>> = m.iter_non_default().map(|(addr, _)| addr).collect();
+        assert_eq!(set, vec![u8addr(1, 65)]);
+    }
+
+    #[test]
+    fn fill_leaves_padding_clear() {
+        let mut m = new_bit_matrix::<u8>(2, 70).unwrap();
+        m.fill(true);
+        assert_eq!(m.count_ones(), 140);
+        assert_eq!(m.words[1], (1 << 6) - 1);
+        m.fill(false);
+        assert_eq!(m.count_ones(), 0);
+    }
+
+    #[test]
+    fn row_and_column_iterators_yield_bools() {
+        let dense = FormatOptions::default()
+            .parse_matrix::<bool, u8>("#..\n.##", |x| x == "#")
+            .unwrap();
+        let m = new_bit_matrix_from(&dense).unwrap();
+        let row1: Vec<bool> = m.row(1).unwrap().iter().copied().collect();
+        assert_eq!(row1, vec![false, true, true]);
+        let column0: Vec<bool> = m.column(0).unwrap().iter().copied().collect();
+        assert_eq!(column0, vec![true, false]);
+        let got = FormatOptions::default().format(&m, |b| if *b { "#".to_string() } else { ".".to_string() });
+        assert_eq!(got, "#..\n.##");
+    }
+
+    #[test]
+    fn rank_and_select() {
+        let mut m = new_bit_matrix::<u8>(3, 70).unwrap();
+        for addr in [u8addr(0, 3), u8addr(0, 66), u8addr(2, 0), u8addr(2, 64), u8addr(2, 69)] {
+            m.set(addr, true);
+        }
+        let index = m.rank_select();
+        assert_eq!(index.count(), 5);
+        assert_eq!(index.rank(u8addr(0, 0)), Some(0));
+        assert_eq!(index.rank(u8addr(0, 66)), Some(1));
+        assert_eq!(index.rank(u8addr(1, 5)), Some(2));
+        assert_eq!(index.rank(u8addr(2, 65)), Some(4));
+        assert_eq!(index.rank(u8addr(3, 0)), None);
+        let selected: Vec<MatrixAddress<u8>> = (0..5).map(|n| index.select(n).unwrap()).collect();
+        assert_eq!(selected, vec![u8addr(0, 3), u8addr(0, 66), u8addr(2, 0), u8addr(2, 64), u8addr(2, 69)]);
+        assert_eq!(index.select(5), None);
+        for (n, addr) in selected.iter().enumerate() {
+            assert_eq!(index.rank(*addr), Some(n));
+        }
+        let empty = new_bit_matrix::<u8>(0, 0).unwrap();
+        assert_eq!(empty.rank_select().select(0), None);
+    }
+
+    #[test]
+    fn shifts_match_cell_moves() {
+        let mut m = new_bit_matrix::<u8>(3, 130).unwrap();
+        for (i, addr) in m.addresses().collect::<Vec<_>>().into_iter().enumerate() {
+            m.set(addr, (i * 7 + i / 5).is_multiple_of(3));
+        }
+        type Shift = fn(&BitMatrix<u8>, usize) -> BitMatrix<u8>;
+        let cases: [(Shift, isize, isize); 4] = [
+            (BitMatrix::shift_left, 0, 1),
+            (BitMatrix::shift_right, 0, -1),
+            (BitMatrix::shift_up, 1, 0),
+            (BitMatrix::shift_down, -1, 0),
+        ];
+        for (shift, dr, dc) in cases {
+            for n in [0, 1, 2, 63, 64, 65, 129, 200] {
+                let shifted = shift(&m, n);
+                for addr in m.addresses() {
+                    let (row, column) = (addr.row as isize + dr * n as isize, addr.column as isize + dc * n as isize);
+                    let expected = (0..3).contains(&row) && (0..130).contains(&column)
+                        && m.bit(u8addr(row as u8, column as u8)) == Some(true);
+                    assert_eq!(shifted.bit(addr), Some(expected), "n={} {:?}", n, addr);
+                }
+                assert_eq!(shifted.words.len(), m.words.len());
+            }
+        }
+        let mut padded = m.clone();
+        padded.fill(true);
+        padded.shift_right_mut(1);
+        assert_eq!(padded.count_ones(), 3 * 129);
+    }
+
+    #[test]
+    fn cells_cannot_be_borrowed_mutably() {
+        let mut m = new_bit_matrix::<u8>(1, 1).unwrap();
+        assert!(m.get_mut(u8addr(0, 0)).is_none());
+    }
+
+    #[test]
+    fn dimension_validation() {
+        assert_eq!(
+            new_bit_matrix::<i8>(2, -1).err(),
+            Some(Error::new("negative dimensions not supported".to_string()))
+        );
+        let empty = new_bit_matrix::<u8>(4, 0).unwrap();
+        assert_eq!(empty.row_count(), 0);
+        assert!(empty.addresses().next().is_none());
+    }
+
+    #[test]
+    fn shaped_factory() {
+        let m = new_bit_matrix_of::<u8>(Shape { rows: 2, columns: 70 }).unwrap();
+        assert_eq!(m.shape(), Shape { rows: 2, columns: 70 });
+        assert_eq!(m, new_bit_matrix(2, 70).unwrap());
+    }
+}
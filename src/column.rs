@@ -1,4 +1,5 @@
 use crate::{Coordinate, Matrix, MatrixAddress, MatrixColumnIterator};
+use crate::error::{Error, Result};
 
 /// Column is a quality-of-life assistant to ease processing matrices
 /// in a column-major fashion.
@@ -31,8 +32,101 @@ where
         MatrixColumnIterator::new(self.matrix, self.column)
     }
 
+    /// iter_range returns a bidirectional iterator over the rows
+    /// from_row..to_row of the column.  An error is returned unless
+    /// 0 <= from_row <= to_row <= row count.
+    pub fn iter_range(&self, from_row: I, to_row: I) -> Result<MatrixColumnIterator<'a, T, I>> {
+        if from_row < I::default() || from_row > to_row || to_row > self.matrix.row_count() {
+            return Err(Error::new(format!(
+                "row range {}..{} is out of bounds for {} rows",
+                from_row, to_row, self.matrix.row_count()
+            )));
+        }
+        Ok(MatrixColumnIterator::over(self.matrix, self.column, from_row, to_row))
+    }
+
     /// get retrieves a specified row's cell entry from this column.
     pub fn get(&self, row: I) -> Option<&'a T> {
         self.matrix.get(MatrixAddress{column: self.column, row})
     }
-}
\ No newline at end of file
+
+    /// iter_with_addresses returns a bidirectional iterator over the column that
+    /// pairs each cell with its address.
+    pub fn iter_with_addresses(&self) -> impl DoubleEndedIterator<Item = (MatrixAddress<I>, &'a T)> + 'a
+    where
+        T: 'static,
+        I: 'a,
+    {
+        let matrix = self.matrix;
+        let column = self.column;
+        let rows: usize = matrix.row_count().try_into().unwrap_or_default();
+        (0..rows).filter_map(move |r| {
+            let addr = MatrixAddress { row: r.try_into().ok()?, column };
+            Some((addr, matrix.get(addr)?))
+        })
+    }
+}
+
+/// ColumnMut is a mutable handle on a single column of a DenseMatrix, for
+/// rewriting a column in place without address bookkeeping.
+pub struct ColumnMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    cells: Vec<&'a mut T>,
+    column: I,
+}
+
+impl <'a, T, I> ColumnMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// cells must hold the column's entries from top to bottom.
+    pub(crate) fn new(cells: Vec<&'a mut T>, column: I) -> Self {
+        ColumnMut{
+            cells,
+            column,
+        }
+    }
+
+    /// column returns the column number this ColumnMut represents, 0-based.
+    pub fn column(&self) -> I {
+        self.column
+    }
+
+    /// iter returns an iterator over the column, from the top.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().map(|v| &**v)
+    }
+
+    /// iter_mut returns a mutable iterator over the column, from the top.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.cells.iter_mut().map(|v| &mut **v)
+    }
+
+    /// get retrieves a specified row's cell entry from this column.
+    pub fn get(&self, row: I) -> Option<&T> {
+        let index: usize = row.try_into().ok()?;
+        self.cells.get(index).map(|v| &**v)
+    }
+
+    /// get_mut retrieves a mutable reference to a specified row's cell entry.
+    pub fn get_mut(&mut self, row: I) -> Option<&mut T> {
+        let index: usize = row.try_into().ok()?;
+        self.cells.get_mut(index).map(|v| &mut **v)
+    }
+
+    /// swap exchanges the entries of two rows in this column.
+    /// Panics if either row is out of bounds.
+    pub fn swap(&mut self, a: I, b: I) {
+        let (a, b): (usize, usize) = match (a.try_into(), b.try_into()) {
+            (Ok(a), Ok(b)) => (a, b),
+            _ => panic!("out of range row in ColumnMut::swap"),
+        };
+        if a != b {
+            let (low, high) = (a.min(b), a.max(b));
+            let (head, tail) = self.cells.split_at_mut(high);
+            std::mem::swap(head[low], tail[0]);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::{Coordinate, DenseMatrix, MatrixAddress};
+use crate::error::{Error, Result};
+
+/// CompressionMap relates the sparse coordinates passed to compress_coordinates
+/// to the dense coordinates of the matrix it returned.  Compressed row r
+/// stands for the r-th smallest distinct row among the points, and likewise
+/// for columns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompressionMap<I, J>
+where
+    I: Coordinate,
+    J: Coordinate,
+{
+    pub(crate) rows: Vec<I>,
+    pub(crate) columns: Vec<I>,
+    pub(crate) marker: std::marker::PhantomData<J>,
+}
+
+impl <I, J> CompressionMap<I, J>
+where
+    I: Coordinate,
+    J: Coordinate,
+{
+    /// rows returns the distinct original rows, in ascending order.
+    pub fn rows(&self) -> &[I] {
+        &self.rows
+    }
+
+    /// columns returns the distinct original columns, in ascending order.
+    pub fn columns(&self) -> &[I] {
+        &self.columns
+    }
+
+    /// compress maps an original address onto the compact matrix.  None is
+    /// returned if its row or column did not occur among the points.
+    pub fn compress(&self, address: MatrixAddress<I>) -> Option<MatrixAddress<J>> {
+        let row = self.rows.binary_search(&address.row).ok()?;
+        let column = self.columns.binary_search(&address.column).ok()?;
+        Some(MatrixAddress { row: row.try_into().ok()?, column: column.try_into().ok()? })
+    }
+
+    /// expand maps an address of the compact matrix back to original
+    /// coordinates.  None is returned for out of bounds addresses.
+    pub fn expand(&self, address: MatrixAddress<J>) -> Option<MatrixAddress<I>> {
+        let row: usize = address.row.try_into().ok()?;
+        let column: usize = address.column.try_into().ok()?;
+        Some(MatrixAddress { row: *self.rows.get(row)?, column: *self.columns.get(column)? })
+    }
+}
+
+/// compress_coordinates maps widely spread points onto a compact matrix with
+/// one row per distinct row and one column per distinct column among them,
+/// preserving order.  Cells holding a point are true.  Gaps between
+/// coordinates are discarded, so cells adjacent in the compact matrix need not
+/// be adjacent originally.  An error is returned if the compact dimensions
+/// cannot be represented by J.
+pub fn compress_coordinates<I, J>(points: &[MatrixAddress<I>]) -> Result<(DenseMatrix<bool, J>, CompressionMap<I, J>)>
+where
+    I: Coordinate,
+    J: Coordinate,
+{
+    let distinct = |f: fn(&MatrixAddress<I>) -> I| {
+        let mut values: Vec<I> = points.iter().map(f).collect();
+        values.sort();
+        values.dedup();
+        values
+    };
+    let map = CompressionMap {
+        rows: distinct(|a| a.row),
+        columns: distinct(|a| a.column),
+        marker: std::marker::PhantomData,
+    };
+    let (rows, columns): (J, J) = match (map.rows.len().try_into(), map.columns.len().try_into()) {
+        (Ok(r), Ok(c)) => (r, c),
+        _ => return Err(Error::new("compressed dimensions exceed chosen index size".to_string())),
+    };
+    let mut data = vec![false; map.rows.len() * map.columns.len()];
+    for point in points {
+        if let (Ok(row), Ok(column)) = (map.rows.binary_search(&point.row), map.columns.binary_search(&point.column)) {
+            data[row * map.columns.len() + column] = true;
+        }
+    }
+    Ok((DenseMatrix::new(columns, rows, data), map))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::FormatOptions;
+    use super::*;
+
+    #[test]
+    fn sparse_points_compress() {
+        let points = vec![
+            MatrixAddress { row: 1_000_000u32, column: 7 },
+            MatrixAddress { row: 5, column: 90_000 },
+            MatrixAddress { row: 5, column: 7 },
+            MatrixAddress { row: 70_000, column: 3 },
+        ];
+        let (m, map) = compress_coordinates::<u32, u8>(&points).unwrap();
+        let got = FormatOptions::default().format(&m, |b| if *b { "#".to_string() } else { ".".to_string() });
+        assert_eq!(got, ".##\n#..\n.#.");
+        assert_eq!(map.rows(), &[5, 70_000, 1_000_000]);
+        assert_eq!(map.compress(MatrixAddress { row: 70_000, column: 90_000 }), Some(MatrixAddress { row: 1, column: 2 }));
+        assert_eq!(map.compress(MatrixAddress { row: 6, column: 7 }), None);
+        assert_eq!(map.expand(MatrixAddress { row: 2, column: 1 }), Some(MatrixAddress { row: 1_000_000, column: 7 }));
+        assert_eq!(map.expand(MatrixAddress { row: 3, column: 0 }), None);
+    }
+
+    #[test]
+    fn overflow_and_empty() {
+        let points: Vec<MatrixAddress<u16>> = (0..300).map(|i| MatrixAddress { row: i, column: 0 }).collect();
+        assert!(compress_coordinates::<u16, u8>(&points).is_err());
+        let (m, _) = compress_coordinates::<u16, u8>(&[]).unwrap();
+        assert_eq!(m.data.len(), 0);
+    }
+}
@@ -0,0 +1,90 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Add, Mul};
+use crate::Coordinate;
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+
+/// Boundary decides what a kernel sees where it overhangs the edge of the
+/// matrix being convolved.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Boundary<T> {
+    /// Clamp repeats the nearest edge cell.
+    Clamp,
+    /// Wrap treats the matrix as a torus.
+    Wrap,
+    /// ConstantFill reads the given value outside the matrix.
+    ConstantFill(T),
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Copy + Default + Add<Output = T> + Mul<Output = T>,
+    I: Coordinate,
+{
+    /// convolve returns the matrix in which each cell is the sum of kernel
+    /// weights times the cells beneath them, with the kernel centered on the
+    /// cell (at row kernel_rows / 2, column kernel_columns / 2).  The kernel is
+    /// applied as is, without flipping, as image processing libraries do;
+    /// symmetric kernels give the same result either way.  Cells beyond the
+    /// edge are supplied by boundary.
+    pub fn convolve(&self, kernel: &DenseMatrix<T, I>, boundary: Boundary<T>) -> Result<DenseMatrix<T, I>> {
+        let (kernel_rows, kernel_columns) = (dimension(kernel.rows), dimension(kernel.columns));
+        if kernel_rows == 0 || kernel_columns == 0 {
+            return Err(Error::new("convolution kernel must have positive dimensions".to_string()));
+        }
+        let (rows, columns) = (dimension(self.rows) as isize, dimension(self.columns) as isize);
+        let (center_row, center_column) = ((kernel_rows / 2) as isize, (kernel_columns / 2) as isize);
+        let mut data = Vec::with_capacity(self.data.len());
+        for row in 0..rows {
+            for column in 0..columns {
+                let mut sum = T::default();
+                for (k, weight) in kernel.data.iter().enumerate() {
+                    let r = row + (k / kernel_columns) as isize - center_row;
+                    let c = column + (k % kernel_columns) as isize - center_column;
+                    let value = match boundary {
+                        _ if r >= 0 && r < rows && c >= 0 && c < columns => self.data[(r * columns + c) as usize],
+                        Boundary::Clamp => self.data[(r.clamp(0, rows - 1) * columns + c.clamp(0, columns - 1)) as usize],
+                        Boundary::Wrap => self.data[(r.rem_euclid(rows) * columns + c.rem_euclid(columns)) as usize],
+                        Boundary::ConstantFill(fill) => fill,
+                    };
+                    sum = sum + *weight * value;
+                }
+                data.push(sum);
+            }
+        }
+        Ok(DenseMatrix::new(self.columns, self.rows, data))
+    }
+}
+
+fn dimension<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("dimension overflows usize.  This should be unreachable."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::new_matrix;
+    use super::*;
+
+    #[test]
+    fn convolve_with_each_boundary() {
+        let m = new_matrix::<i32, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let cross = new_matrix::<i32, u8>(3, vec![0, 1, 0, 1, 1, 1, 0, 1, 0]).unwrap();
+        assert_eq!(m.convolve(&cross, Boundary::ConstantFill(0)).unwrap().data, vec![7, 11, 11, 10, 17, 14]);
+        assert_eq!(m.convolve(&cross, Boundary::ConstantFill(10)).unwrap().data, vec![27, 21, 31, 30, 27, 34]);
+        assert_eq!(m.convolve(&cross, Boundary::Clamp).unwrap().data, vec![9, 13, 17, 18, 22, 26]);
+        assert_eq!(m.convolve(&cross, Boundary::Wrap).unwrap().data, vec![14, 16, 18, 17, 19, 21]);
+    }
+
+    #[test]
+    fn asymmetric_kernel_is_not_flipped() {
+        let m = new_matrix::<i32, u8>(1, vec![1, 2, 3]).unwrap();
+        let right = new_matrix::<i32, u8>(1, vec![0, 0, 1]).unwrap();
+        assert_eq!(m.convolve(&right, Boundary::ConstantFill(0)).unwrap().data, vec![2, 3, 0]);
+        let empty = new_matrix::<i32, u8>(0, vec![]).unwrap();
+        assert!(m.convolve(&empty, Boundary::Wrap).is_err());
+    }
+}
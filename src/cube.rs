@@ -0,0 +1,233 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Index, IndexMut, Range};
+use crate::cube_address::CubeAddress;
+use crate::dense_matrix::DenseMatrix;
+use crate::iter::{CubeForwardIndexedIterator, CubeForwardIterator};
+use crate::traits::{Coordinate, Tensor};
+
+/// Cube is a dense three dimensional store of type T.  Storage is a stack of
+/// row-major layers, so iteration visits every cell of layer z = 0, then every
+/// cell of layer z = 1, and so on.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cube<T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) x_size: I,
+    pub(crate) y_size: I,
+    pub(crate) z_size: I,
+    pub(crate) data: Vec<T>,
+}
+
+impl <T, I> Cube<T, I>
+where
+    I: Coordinate,
+{
+    /// x_size returns the number of columns in each layer.
+    pub fn x_size(&self) -> I {
+        self.x_size
+    }
+
+    /// y_size returns the number of rows in each layer.
+    pub fn y_size(&self) -> I {
+        self.y_size
+    }
+
+    /// z_size returns the number of layers.
+    pub fn z_size(&self) -> I {
+        self.z_size
+    }
+
+    /// addresses returns every address in the cube, layer by layer.
+    pub fn addresses(&self) -> CubeForwardIterator<I> {
+        CubeForwardIterator::new(self.range().end)
+    }
+
+    /// iter returns the values of the cube in address order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// iter_mut returns mutable references to the values of the cube in address order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// indexed_iter returns (address, value) tuples in address order.
+    pub fn indexed_iter(&self) -> CubeForwardIndexedIterator<'_, T, I> {
+        CubeForwardIndexedIterator::new(self.addresses(), self.data.iter())
+    }
+
+    // index_address works in usize, since the cell count may not fit in I even
+    // though every coordinate does.
+    fn index_address(&self, address: CubeAddress<I>) -> usize {
+        let [x, y, z, x_size, y_size] = [address.x, address.y, address.z, self.x_size, self.y_size].map(to_usize);
+        (z * y_size + y) * x_size + x
+    }
+}
+
+fn to_usize<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("address overflows usize.  This should be unreachable."),
+    }
+}
+
+impl <T, I> Cube<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    /// layer copies the z'th layer into a matrix.  None is returned for out of
+    /// bounds layer numbers.
+    pub fn layer(&self, z: I) -> Option<DenseMatrix<T, I>> {
+        if z < I::default() || z >= self.z_size {
+            return None;
+        }
+        let start = self.index_address(CubeAddress { x: I::default(), y: I::default(), z });
+        let end = self.index_address(CubeAddress { x: I::default(), y: I::default(), z: z + I::unit() });
+        Some(DenseMatrix::new(self.x_size, self.y_size, self.data[start..end].to_vec()))
+    }
+}
+
+impl<T, I> Tensor<T, I, CubeAddress<I>, 3> for Cube<T, I>
+where
+    I: Coordinate,
+{
+    fn range(&self) -> Range<CubeAddress<I>> {
+        Range {
+            start: CubeAddress::default(),
+            end: CubeAddress {
+                x: self.x_size,
+                y: self.y_size,
+                z: self.z_size,
+            },
+        }
+    }
+
+    fn get(&self, address: CubeAddress<I>) -> Option<&T> {
+        if !self.contains(address) {
+            None
+        } else {
+            let addr = self.index_address(address);
+            self.data.get(addr)
+        }
+    }
+
+    fn get_mut(&mut self, address: CubeAddress<I>) -> Option<&mut T> {
+        if !self.contains(address) {
+            None
+        } else {
+            let addr = self.index_address(address);
+            self.data.get_mut(addr)
+        }
+    }
+}
+
+impl<T, I> Index<CubeAddress<I>> for Cube<T, I>
+where
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, index: CubeAddress<I>) -> &Self::Output {
+        match self.get(index) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl<T, I> IndexMut<CubeAddress<I>> for Cube<T, I>
+where
+    I: Coordinate,
+{
+    fn index_mut(&mut self, index: CubeAddress<I>) -> &mut T {
+        match self.get_mut(index) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::factories::{new_cube, new_cube_of, new_default_cube, new_default_cube_of};
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn u8addr(x: u8, y: u8, z: u8) -> CubeAddress<u8> {
+        CubeAddress { x, y, z }
+    }
+
+    #[test]
+    fn storage_is_layer_major() {
+        let cube = new_cube::<u8, u8>(2, 3, 2, (0..12).collect()).unwrap();
+        assert_eq!(cube[u8addr(1, 0, 0)], 1);
+        assert_eq!(cube[u8addr(0, 1, 0)], 2);
+        assert_eq!(cube[u8addr(0, 0, 1)], 6);
+        assert_eq!(cube.get(u8addr(2, 0, 0)), None);
+        let indexed: Vec<(CubeAddress<u8>, &u8)> = cube.indexed_iter().skip(5).take(2).collect();
+        assert_eq!(indexed, vec![(u8addr(1, 2, 0), &5), (u8addr(0, 0, 1), &6)]);
+        assert_eq!(cube.layer(1).unwrap().data, vec![6, 7, 8, 9, 10, 11]);
+        assert!(cube.layer(2).is_none());
+    }
+
+    #[test]
+    fn mutation() {
+        let mut cube = new_default_cube::<u8, u8>(2, 2, 2).unwrap();
+        cube[u8addr(1, 1, 1)] = 9;
+        *cube.get_mut(u8addr(0, 0, 1)).unwrap() = 4;
+        cube.iter_mut().for_each(|v| *v += 1);
+        assert_eq!(cube.iter().copied().collect::<Vec<u8>>(), vec![1, 1, 1, 1, 5, 1, 1, 10]);
+    }
+
+    #[test]
+    fn index_exceeding_coordinate_type() {
+        let mut cube = new_default_cube::<u8, u8>(7, 7, 7).unwrap();
+        cube[u8addr(6, 6, 6)] = 1;
+        assert_eq!(cube.get(u8addr(6, 6, 6)), Some(&1));
+        assert_eq!(cube.iter().position(|v| *v == 1), Some(342));
+        assert_eq!(cube.layer(6).unwrap().data.len(), 49);
+    }
+
+    #[test]
+    fn parse_and_format_layers() {
+        let text = "#.\n..\n\n.#\n#.\n\n..\n.#";
+        let opts = FormatOptions::default();
+        let cube = opts.parse_cube::<char, u8>(text, "\n\n", |x| x.chars().next().unwrap()).unwrap();
+        assert_eq!((cube.x_size(), cube.y_size(), cube.z_size()), (2, 2, 3));
+        assert_eq!(cube[u8addr(0, 1, 1)], '#');
+        assert_eq!(opts.format_cube(&cube, "\n\n", |c| c.to_string()), text);
+        assert_eq!(
+            opts.parse_cube::<char, u8>("#.\n..\n\n#\n.", "\n\n", |x| x.chars().next().unwrap()).err(),
+            Some(Error::new("layer 1 dimensions differ from layer 0".to_string()))
+        );
+    }
+
+    #[test]
+    fn dimension_validation() {
+        assert_eq!(
+            new_default_cube::<u8, i8>(2, -1, 2).err(),
+            Some(Error::new("negative dimensions not supported".to_string()))
+        );
+        assert_eq!(
+            new_cube::<u8, u8>(2, 2, 2, vec![0; 7]).err(),
+            Some(Error::new("data length 7 does not match cube dimensions (8)".to_string()))
+        );
+        let empty = new_default_cube::<u8, u8>(3, 0, 3).unwrap();
+        assert_eq!(empty.z_size(), 0);
+        assert!(empty.addresses().next().is_none());
+    }
+
+    #[test]
+    fn shaped_factories() {
+        let shape = u8addr(2, 3, 2);
+        let cube = new_cube_of::<u8, u8>(shape, (0..12).collect()).unwrap();
+        assert_eq!(cube, new_cube(2, 3, 2, (0..12).collect()).unwrap());
+        let empty = new_default_cube_of::<u8, u8>(shape).unwrap();
+        assert_eq!((empty.x_size(), empty.y_size(), empty.z_size()), (2, 3, 2));
+    }
+}
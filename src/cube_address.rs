@@ -0,0 +1,212 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::traits::{Address, Coordinate, Dimension, Tensor};
+use crate::cube::Cube;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Index, Sub};
+
+/// CubeAddress references a cell in a cube by its x (column), y (row) and
+/// z (layer) position.  Layers are numbered from zero at the first layer of
+/// layered text input, and within a layer, rows and columns are numbered as
+/// they are in a MatrixAddress.  The field order gives the same z-major,
+/// then row-major, ordering that cube iteration uses.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct CubeAddress<I>
+where
+    I: Coordinate,
+{
+    pub z: I,
+    pub y: I,
+    pub x: I,
+}
+
+impl <I> CubeAddress<I>
+where
+    I: Coordinate {
+
+    // neighbors returns the addresses of the up to 26 adjacent cube addresses in the given
+    // cube.  All returned addresses are guaranteed to be in-bounds for the given cube.
+    // An address outside the cube has no neighbors.
+    pub fn neighbors<T>(&self, cube: &Cube<T, I>) -> Vec<CubeAddress<I>> {
+        if !cube.contains(*self) {
+            return Vec::new();
+        }
+        let ione = I::unit();
+        let (x_low, x_high) = neighbor_span(self.x, cube.x_size());
+        let (y_low, y_high) = neighbor_span(self.y, cube.y_size());
+        let (z_low, z_high) = neighbor_span(self.z, cube.z_size());
+        let mut neighbors = Vec::new();
+        let mut z = z_low;
+        while z <= z_high {
+            let mut y = y_low;
+            while y <= y_high {
+                let mut x = x_low;
+                while x <= x_high {
+                    let candidate = CubeAddress { x, y, z };
+                    if candidate != *self {
+                        neighbors.push(candidate);
+                    }
+                    x = x + ione;
+                }
+                y = y + ione;
+            }
+            z = z + ione;
+        }
+        neighbors
+    }
+}
+
+/// neighbor_span returns the lowest and highest in-bounds coordinates adjacent
+/// to or at v, along a dimension of the given size.  v must be in bounds, which
+/// keeps v + 1 from overflowing.
+pub(crate) fn neighbor_span<I: Coordinate>(v: I, size: I) -> (I, I) {
+    let ione = I::unit();
+    let low = if v > I::default() { v - ione } else { v };
+    let high = if size - v > ione { v + ione } else { v };
+    (low, high)
+}
+
+impl<I> Index<Dimension> for CubeAddress<I>
+where
+    I: Coordinate,
+{
+    type Output = I;
+
+    fn index(&self, index: Dimension) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("invalid dimension"),
+        }
+    }
+}
+
+impl<I> Address<I, 3usize> for CubeAddress<I> where I: Coordinate {}
+
+impl<I> Display for CubeAddress<I>
+where
+    I: Coordinate,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("(x={},y={},z={})", self.x, self.y, self.z))
+    }
+}
+
+impl<I> From<[I; 3]> for CubeAddress<I>
+where
+    I: Coordinate,
+{
+    fn from(value: [I; 3]) -> Self {
+        Self {
+            x: value[0],
+            y: value[1],
+            z: value[2],
+        }
+    }
+}
+
+impl <I> From<CubeAddress<I>> for [I; 3]
+where
+    I: Coordinate,{
+    fn from(value: CubeAddress<I>) -> Self {
+        [value.x, value.y, value.z]
+    }
+}
+
+impl<I> Add for CubeAddress<I>
+where
+    I: Coordinate,
+{
+    type Output = CubeAddress<I>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Warning: result can be out of bounds
+        CubeAddress {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<I> Sub for CubeAddress<I>
+where
+    I: Coordinate,
+{
+    type Output = CubeAddress<I>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        // Warning: result can be out of bounds
+        CubeAddress {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<I> Default for CubeAddress<I>
+where
+    I: Coordinate,
+{
+    fn default() -> Self {
+        CubeAddress {
+            x: I::default(),
+            y: I::default(),
+            z: I::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::new_default_cube;
+    use super::*;
+
+    fn u8addr(x: u8, y: u8, z: u8) -> CubeAddress<u8> {
+        CubeAddress { x, y, z }
+    }
+
+    #[test]
+    fn test_display_and_dimensions() {
+        let addr = u8addr(1, 2, 3);
+        assert_eq!(addr.to_string(), "(x=1,y=2,z=3)");
+        assert_eq!((addr[0], addr[1], addr[2]), (1, 2, 3));
+        let rep: [u8; 3] = addr.into();
+        assert_eq!(rep, [1, 2, 3]);
+        assert_eq!(CubeAddress::from(rep), addr);
+        assert_eq!(addr + u8addr(1, 1, 1) - u8addr(2, 0, 1), u8addr(0, 3, 3));
+    }
+
+    #[test]
+    fn test_ordering_is_layer_major() {
+        assert!(u8addr(5, 5, 0) < u8addr(0, 0, 1));
+        assert!(u8addr(5, 0, 1) < u8addr(0, 1, 1));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let cube = new_default_cube::<u8, u8>(3, 3, 3).unwrap();
+        assert_eq!(u8addr(1, 1, 1).neighbors(&cube).len(), 26);
+        assert_eq!(u8addr(0, 0, 0).neighbors(&cube), vec![
+            u8addr(1, 0, 0),
+            u8addr(0, 1, 0),
+            u8addr(1, 1, 0),
+            u8addr(0, 0, 1),
+            u8addr(1, 0, 1),
+            u8addr(0, 1, 1),
+            u8addr(1, 1, 1),
+        ]);
+        assert_eq!(u8addr(2, 1, 2).neighbors(&cube).len(), 11);
+    }
+
+    #[test]
+    fn test_out_of_bounds_neighbors() {
+        let cube = new_default_cube::<u8, u8>(3, 3, 3).unwrap();
+        assert!(u8addr(255, 1, 1).neighbors(&cube).is_empty());
+        assert!(u8addr(1, 3, 1).neighbors(&cube).is_empty());
+        let signed = new_default_cube::<u8, i32>(3, 3, 3).unwrap();
+        assert!(CubeAddress { x: -3, y: 1, z: 1 }.neighbors(&signed).is_empty());
+    }
+}
@@ -3,10 +3,16 @@
 use crate::iter::{MatrixForwardIndexedIterator, MatrixForwardIterator};
 use crate::matrix_address::MatrixAddress;
 use crate::traits::{Coordinate, Tensor};
-use std::ops::{Index, IndexMut, Range};
-use crate::{Matrix, MatrixColumnsIterator, MatrixRowsIterator, MatrixValueIterator};
-use crate::column::Column;
-use crate::row::Row;
+use std::collections::HashMap;
+use std::slice::{ChunksExact, ChunksExactMut};
+use crate::error::{Error, Result};
+use std::hash::Hash;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Range, Sub};
+use crate::{Matrix, MatrixColumnsIterMut, MatrixColumnsIterator, MatrixForwardIndexedIterMut, MatrixIntoIndexedIterator, MatrixMut, MatrixRowsIterMut, MatrixRowsIterator, MatrixValueIterMut, MatrixValueIterator};
+use crate::column::{Column, ColumnMut};
+use crate::row::{Row, RowMut};
+use crate::factories::new_matrix_of;
+use crate::shape::{assert_same_shape, Shape};
 
 /// DenseMatrix pre-allocates storage for every storage cell.
 #[derive(Debug)]
@@ -14,12 +20,12 @@ pub struct DenseMatrix<T, I>
 where
     I: Coordinate,
 {
-    columns: I,
-    rows: I,
+    pub(crate) columns: I,
+    pub(crate) rows: I,
     pub(crate) data: Vec<T>,
 }
 
-impl <'a, T, I> DenseMatrix<T, I>
+impl <T, I> DenseMatrix<T, I>
 where
     I: Coordinate,
 {
@@ -27,7 +33,191 @@ where
         Self { columns, rows, data }
     }
 
-    fn index_address(&self, address: MatrixAddress<I>) -> usize {
+    /// from_raw_parts adopts a row-major buffer as a rows x columns matrix without
+    /// copying it.  It is the inverse of into_inner.  data must hold exactly
+    /// rows * columns values.  A matrix with zero rows or zero columns is
+    /// normalized to zero rows and zero columns.
+    pub fn from_raw_parts(rows: I, columns: I, data: Vec<T>) -> Result<Self> {
+        let zero = I::default();
+        if rows < zero || columns < zero {
+            return Err(Error::new("negative dimensions not supported".to_string()));
+        }
+        let len = match rows.checked_multiply(columns) {
+            Some(v) => v,
+            None => return Err(Error::new("matrix dimensions exceed chosen index size".to_string())),
+        };
+        if data.len() != len {
+            return Err(Error::new(format!("data length {} does not match matrix dimensions ({})", data.len(), len)));
+        }
+        if len == 0 {
+            return Ok(Self::new(zero, zero, data));
+        }
+        Ok(Self::new(columns, rows, data))
+    }
+
+    /// from_raw_parts_of adopts a row-major buffer as a matrix of the given shape
+    /// without copying it, as from_raw_parts does.
+    pub fn from_raw_parts_of(shape: Shape<I>, data: Vec<T>) -> Result<Self> {
+        Self::from_raw_parts(shape.rows, shape.columns, data)
+    }
+
+    /// into_inner consumes the matrix, returning its row count, column count and
+    /// row-major storage.  It is the inverse of from_raw_parts.
+    pub fn into_inner(self) -> (I, I, Vec<T>) {
+        (self.rows, self.columns, self.data)
+    }
+
+    /// row_mut retrieves a mutable handle on a row by index.  None is returned for
+    /// out of bounds row numbers.
+    pub fn row_mut(&mut self, row_num: I) -> Option<RowMut<'_, T, I>> {
+        if row_num < I::default() || row_num >= self.rows {
+            return None;
+        }
+        let start = self.index_address(MatrixAddress { row: row_num, column: I::default() });
+        let end = self.index_address(MatrixAddress { row: row_num + I::unit(), column: I::default() });
+        Some(RowMut::new(&mut self.data[start..end], row_num))
+    }
+
+    /// rows_mut returns an iterator over mutable handles on the rows of the matrix.
+    pub fn rows_mut(&mut self) -> MatrixRowsIterMut<'_, T, I> {
+        let columns = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        MatrixRowsIterMut::new(&mut self.data, columns)
+    }
+
+    /// rows_chunks returns the rows of the matrix as contiguous slices of the
+    /// underlying storage, in row order.
+    pub fn rows_chunks(&self) -> ChunksExact<'_, T> {
+        let width = self.chunk_width();
+        self.data.chunks_exact(width)
+    }
+
+    /// rows_chunks_mut returns the rows of the matrix as contiguous mutable slices
+    /// of the underlying storage, in row order.  The slices are disjoint, so they
+    /// may be handed to separate threads.
+    pub fn rows_chunks_mut(&mut self) -> ChunksExactMut<'_, T> {
+        let width = self.chunk_width();
+        self.data.chunks_exact_mut(width)
+    }
+
+    // chunk_width is the row length in storage.  Empty matrices are 0x0, so a
+    // width of 1 over their empty storage yields no rows.
+    fn chunk_width(&self) -> usize {
+        match self.columns.try_into() {
+            Ok(0) => 1,
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        }
+    }
+
+    /// into_indexed_iter consumes the matrix, returning its addresses and values
+    /// in row-major order.
+    pub fn into_indexed_iter(self) -> MatrixIntoIndexedIterator<T, I> {
+        let addrs = MatrixForwardIterator::new(MatrixAddress { row: self.rows, column: self.columns });
+        MatrixIntoIndexedIterator::new(addrs, self.data.into_iter())
+    }
+
+    /// bounding_box returns the smallest range of addresses containing every cell
+    /// that is not background.  None is returned if every cell is background.
+    pub fn bounding_box(&self, is_background: impl Fn(&T) -> bool) -> Option<Range<MatrixAddress<I>>> {
+        let mut bounds: Option<(MatrixAddress<I>, MatrixAddress<I>)> = None;
+        let addrs = MatrixForwardIterator::new(MatrixAddress { row: self.rows, column: self.columns });
+        for (addr, value) in addrs.zip(self.data.iter()) {
+            if is_background(value) {
+                continue;
+            }
+            bounds = match bounds {
+                None => Some((addr, addr)),
+                Some((low, high)) => Some((
+                    MatrixAddress { row: low.row.min(addr.row), column: low.column.min(addr.column) },
+                    MatrixAddress { row: high.row.max(addr.row), column: high.column.max(addr.column) },
+                )),
+            };
+        }
+        bounds.map(|(low, high)| Range {
+            start: low,
+            end: MatrixAddress { row: high.row + I::unit(), column: high.column + I::unit() },
+        })
+    }
+
+    /// column_mut retrieves a mutable handle on a column by index.  None is returned
+    /// for out of bounds column numbers.
+    pub fn column_mut(&mut self, column_num: I) -> Option<ColumnMut<'_, T, I>> {
+        if column_num < I::default() || column_num >= self.columns {
+            return None;
+        }
+        let (start, step) = match (column_num.try_into(), self.columns.try_into()) {
+            (Ok(start), Ok(step)) => (start, step),
+            _ => panic!("column overflows usize.  This should be unreachable."),
+        };
+        let cells = self.data.iter_mut().skip(start).step_by(step).collect();
+        Some(ColumnMut::new(cells, column_num))
+    }
+
+    /// columns_mut returns an iterator over mutable handles on the columns of the matrix.
+    pub fn columns_mut(&mut self) -> MatrixColumnsIterMut<'_, T, I> {
+        let columns = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        MatrixColumnsIterMut::new(&mut self.data, columns)
+    }
+
+    /// get_many looks up each address in turn, returning None for out of bounds
+    /// addresses.
+    pub fn get_many(&self, addrs: &[MatrixAddress<I>]) -> Vec<Option<&T>> {
+        addrs.iter()
+            .map(|addr| if self.contains(*addr) {
+                Some(&self.data[self.index_address(*addr)])
+            } else {
+                None
+            })
+            .collect()
+    }
+
+    /// upscale replaces every cell with the block_rows x block_columns tile
+    /// computed from it by expand, returning a matrix block_rows times taller and
+    /// block_columns times wider.  An error is returned if a tile has the wrong
+    /// shape or the result cannot be indexed by I.
+    pub fn upscale<U>(&self, block_rows: I, block_columns: I, expand: impl Fn(&T) -> DenseMatrix<U, I>) -> Result<DenseMatrix<U, I>> {
+        let zero = I::default();
+        if block_rows <= zero || block_columns <= zero {
+            return Err(Error::new("upscale block must have positive dimensions".to_string()));
+        }
+        let scale = |extent: I, block: I| -> Option<I> { extent.checked_multiply(block)?.try_into().ok() };
+        let (rows, columns) = match (scale(self.rows, block_rows), scale(self.columns, block_columns)) {
+            (Some(r), Some(c)) => (r, c),
+            _ => return Err(Error::new("upscaled dimensions exceed chosen index size".to_string())),
+        };
+        let mut tiles = Vec::with_capacity(self.data.len());
+        for value in &self.data {
+            let tile = expand(value);
+            if tile.rows != block_rows || tile.columns != block_columns {
+                return Err(Error::new(format!(
+                    "upscale tile is {}x{}, expected {}x{}",
+                    tile.rows, tile.columns, block_rows, block_columns)));
+            }
+            tiles.push(tile.data.into_iter());
+        }
+        let (block_rows, block_columns) = match (block_rows.try_into(), block_columns.try_into()) {
+            (Ok(r), Ok(c)) => (r, c),
+            _ => panic!("block size overflows usize.  This should be unreachable."),
+        };
+        let width = self.chunk_width();
+        let mut data = Vec::with_capacity(tiles.len() * block_rows * block_columns);
+        for tile_row in tiles.chunks_exact_mut(width) {
+            for _ in 0..block_rows {
+                for tile in tile_row.iter_mut() {
+                    data.extend(tile.by_ref().take(block_columns));
+                }
+            }
+        }
+        Ok(DenseMatrix::new(columns, rows, data))
+    }
+
+    pub(crate) fn index_address(&self, address: MatrixAddress<I>) -> usize {
         match (address.row * self.columns + address.column).try_into() {
             Ok(v) => v,
             Err(_) => panic!("address overflows usize.  This should be unreachable."),
@@ -35,6 +225,290 @@ where
     }
 }
 
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    /// trimmed returns a copy of the matrix with any borders consisting entirely
+    /// of background cells removed.  If every cell is background, the result is
+    /// an empty matrix.
+    pub fn trimmed(&self, is_background: impl Fn(&T) -> bool) -> DenseMatrix<T, I> {
+        match self.bounding_box(is_background) {
+            Some(range) => self.copy_window(range),
+            None => DenseMatrix::new(I::default(), I::default(), Vec::new()),
+        }
+    }
+
+    /// set_many writes each value at its address, in order, so later entries win
+    /// when an address is repeated.  Every address is validated before any cell is
+    /// written, so an out of bounds address is an error that leaves the matrix
+    /// unchanged.
+    pub fn set_many(&mut self, entries: &[(MatrixAddress<I>, T)]) -> Result<()> {
+        if let Some((addr, _)) = entries.iter().find(|(addr, _)| !self.contains(*addr)) {
+            return Err(Error::new(format!("address {} is out of bounds", addr)));
+        }
+        for (addr, value) in entries {
+            let index = self.index_address(*addr);
+            self.data[index] = value.clone();
+        }
+        Ok(())
+    }
+
+    /// transposed returns a new matrix with the rows and columns of this one
+    /// swapped.  Unlike TransposedMatrix, this copies the cells, so both
+    /// orientations can be kept alive independently.
+    pub fn transposed(&self) -> DenseMatrix<T, I> {
+        let columns: usize = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        let rows = self.data.len().checked_div(columns).unwrap_or(0);
+        let mut data = Vec::with_capacity(self.data.len());
+        for column in 0..columns {
+            for row in 0..rows {
+                data.push(self.data[row * columns + column].clone());
+            }
+        }
+        DenseMatrix::new(self.rows, self.columns, data)
+    }
+
+    /// convert_index_type returns a copy of the matrix addressed by a different
+    /// Coordinate type, e.g. to grow a u8 grid past 255 rows.  It is an error if
+    /// the dimensions do not fit in J.
+    pub fn convert_index_type<J>(&self) -> Result<DenseMatrix<T, J>>
+    where
+        J: Coordinate,
+    {
+        let dimensions = match (self.rows.try_into(), self.columns.try_into()) {
+            (Ok(rows), Ok(columns)) => {
+                let (rows, columns): (usize, usize) = (rows, columns);
+                (J::try_from(rows).ok(), J::try_from(columns).ok())
+            },
+            _ => (None, None),
+        };
+        match dimensions {
+            (Some(rows), Some(columns)) => Ok(DenseMatrix::new(columns, rows, self.data.clone())),
+            _ => Err(Error::new(format!(
+                "dimensions {}x{} do not fit the target index type",
+                self.rows, self.columns
+            ))),
+        }
+    }
+
+    /// padded returns a copy of the matrix surrounded by a border of fill
+    /// cells, thickness cells wide on every side, so that the original cell
+    /// (r, c) moves to (r + thickness, c + thickness).  It is an error if
+    /// thickness is negative or the padded dimensions overflow I or usize.
+    pub fn padded(&self, thickness: I, fill: T) -> Result<DenseMatrix<T, I>> {
+        let (rows, columns, border): (usize, usize, usize) = match (self.rows.try_into(), self.columns.try_into(), thickness.try_into()) {
+            (Ok(r), Ok(c), Ok(t)) => (r, c, t),
+            _ => return Err(Error::new(format!("cannot pad by {}", thickness))),
+        };
+        let overflow = || Error::new(format!("padding {}x{} by {} overflows usize", self.rows, self.columns, thickness));
+        let grow = |n: usize| border.checked_mul(2).and_then(|b| n.checked_add(b));
+        let (padded_rows, padded_columns) = match (grow(rows), grow(columns)) {
+            (Some(r), Some(c)) => (r, c),
+            _ => return Err(overflow()),
+        };
+        let shape = match (padded_rows.try_into(), padded_columns.try_into()) {
+            (Ok(rows), Ok(columns)) => Shape { rows, columns },
+            _ => return Err(Error::new(format!(
+                "padded dimensions {}x{} overflow index type", padded_rows, padded_columns
+            ))),
+        };
+        let mut data = Vec::with_capacity(padded_rows.checked_mul(padded_columns).ok_or_else(overflow)?);
+        data.resize(border * padded_columns, fill.clone());
+        for row in self.data.chunks_exact(columns.max(1)) {
+            data.extend(std::iter::repeat_n(fill.clone(), border));
+            data.extend_from_slice(row);
+            data.extend(std::iter::repeat_n(fill.clone(), border));
+        }
+        data.resize(padded_rows * padded_columns, fill);
+        new_matrix_of(shape, data)
+    }
+
+    /// upscale_cloned replaces every cell with a block_rows x block_columns block
+    /// of copies of its value.
+    pub fn upscale_cloned(&self, block_rows: I, block_columns: I) -> Result<DenseMatrix<T, I>> {
+        let zero = I::default();
+        let count = match block_rows.checked_multiply(block_columns) {
+            Some(count) => count,
+            None if block_rows > zero && block_columns > zero =>
+                return Err(Error::new("upscaled dimensions exceed chosen index size".to_string())),
+            // upscale reports the non-positive block size.
+            None => 0,
+        };
+        self.upscale(block_rows, block_columns, |v| DenseMatrix::new(block_columns, block_rows, vec![v.clone(); count]))
+    }
+
+    /// submatrix_copy copies a non-empty range of cells within the matrix into a
+    /// new, owned matrix.  Unlike SubMatrix, the copy does not borrow the
+    /// original.
+    pub fn submatrix_copy(&self, range: Range<MatrixAddress<I>>) -> Result<DenseMatrix<T, I>> {
+        self.check_window(&range)?;
+        Ok(self.copy_window(range))
+    }
+
+    /// check_window returns an error unless range is a non-empty range of cells
+    /// within the matrix, as copy_window requires.
+    pub(crate) fn check_window(&self, range: &Range<MatrixAddress<I>>) -> Result<()> {
+        let zero = I::default();
+        if range.start.row < zero || range.start.column < zero
+            || range.end.row > self.rows || range.end.column > self.columns
+            || range.start.row >= range.end.row || range.start.column >= range.end.column {
+            return Err(Error::new(format!(
+                "crop range {}..{} is empty or outside the matrix", range.start, range.end)));
+        }
+        Ok(())
+    }
+
+    /// copy_window copies an in-bounds, non-empty range of cells into a new matrix.
+    pub(crate) fn copy_window(&self, range: Range<MatrixAddress<I>>) -> DenseMatrix<T, I> {
+        let rows = range.end.row - range.start.row;
+        let columns = range.end.column - range.start.column;
+        let mut data = Vec::new();
+        let mut row = range.start.row;
+        while row < range.end.row {
+            let start = self.index_address(MatrixAddress { row, column: range.start.column });
+            let end = self.index_address(MatrixAddress { row, column: range.end.column - I::unit() });
+            data.extend_from_slice(&self.data[start..=end]);
+            row = row + I::unit();
+        }
+        DenseMatrix::new(columns, rows, data)
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Default + PartialEq,
+    I: Coordinate,
+{
+    /// iter_non_default returns (address, value) tuples in row-major order for
+    /// only those cells whose value differs from T::default().
+    pub fn iter_non_default(&self) -> impl Iterator<Item = (MatrixAddress<I>, &T)> + '_ {
+        let default = T::default();
+        let addrs = MatrixForwardIterator::new(MatrixAddress { row: self.rows, column: self.columns });
+        addrs.zip(self.data.iter()).filter(move |(_, value)| **value != default)
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Clone + Eq + Hash,
+    I: Coordinate,
+{
+    /// translate_values replaces every cell that appears as a key in map with the
+    /// corresponding value.  Cells that are not keys of map are left unchanged.
+    pub fn translate_values(&mut self, map: &HashMap<T, T>) {
+        for cell in self.data.iter_mut() {
+            if let Some(replacement) = map.get(cell) {
+                *cell = replacement.clone();
+            }
+        }
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: 'static + Clone,
+    I: Coordinate,
+{
+    /// expand_rows_where returns a copy of the matrix in which every row matching
+    /// pred is followed by copies additional duplicates of itself.  An error is
+    /// returned if the expanded row count cannot be represented by I.
+    pub fn expand_rows_where(&self, pred: impl Fn(Row<T, I>) -> bool, copies: usize) -> Result<DenseMatrix<T, I>> {
+        let mut rows = 0usize;
+        let mut data: Vec<T> = Vec::new();
+        for row in self.rows() {
+            let values: Vec<T> = row.iter().cloned().collect();
+            let repeat = if pred(row) { copies + 1 } else { 1 };
+            for _ in 0..repeat {
+                data.extend_from_slice(&values);
+            }
+            rows += repeat;
+        }
+        let rows: I = match rows.try_into() {
+            Ok(v) => v,
+            Err(_) => return Err(Error::new("expanded row count overflows index type".to_string())),
+        };
+        Ok(DenseMatrix::new(self.columns, rows, data))
+    }
+
+    /// expand_columns_where returns a copy of the matrix in which every column
+    /// matching pred is followed by copies additional duplicates of itself.  An
+    /// error is returned if the expanded column count cannot be represented by I.
+    pub fn expand_columns_where(&self, pred: impl Fn(Column<T, I>) -> bool, copies: usize) -> Result<DenseMatrix<T, I>> {
+        let repeats: Vec<usize> = self.columns()
+            .map(|column| if pred(column) { copies + 1 } else { 1 })
+            .collect();
+        let columns: I = match repeats.iter().sum::<usize>().try_into() {
+            Ok(v) => v,
+            Err(_) => return Err(Error::new("expanded column count overflows index type".to_string())),
+        };
+        let mut data: Vec<T> = Vec::new();
+        for row in self.rows() {
+            for (value, repeat) in row.iter().zip(repeats.iter()) {
+                for _ in 0..*repeat {
+                    data.push(value.clone());
+                }
+            }
+        }
+        Ok(DenseMatrix::new(columns, self.rows, data))
+    }
+
+    /// split_on_rows_where splits the matrix into pieces at every row matching
+    /// pred, returning copies of the pieces from top to bottom.  Separator rows
+    /// are not included in any piece, and no empty pieces are returned.
+    pub fn split_on_rows_where(&self, pred: impl Fn(Row<T, I>) -> bool) -> Vec<DenseMatrix<T, I>> {
+        let mut pieces = Vec::new();
+        let mut start: Option<I> = None;
+        for row in self.rows() {
+            let row_num = row.row();
+            match (pred(row), start) {
+                (true, Some(first)) => {
+                    pieces.push(self.copy_window(
+                        MatrixAddress { row: first, column: I::default() }..MatrixAddress { row: row_num, column: self.columns }));
+                    start = None;
+                },
+                (false, None) => start = Some(row_num),
+                _ => {},
+            }
+        }
+        if let Some(first) = start {
+            pieces.push(self.copy_window(
+                MatrixAddress { row: first, column: I::default() }..MatrixAddress { row: self.rows, column: self.columns }));
+        }
+        pieces
+    }
+
+    /// split_on_columns_where splits the matrix into pieces at every column
+    /// matching pred, returning copies of the pieces from left to right.
+    /// Separator columns are not included in any piece, and no empty pieces are
+    /// returned.
+    pub fn split_on_columns_where(&self, pred: impl Fn(Column<T, I>) -> bool) -> Vec<DenseMatrix<T, I>> {
+        let mut pieces = Vec::new();
+        let mut start: Option<I> = None;
+        for column in self.columns() {
+            let column_num = column.column();
+            match (pred(column), start) {
+                (true, Some(first)) => {
+                    pieces.push(self.copy_window(
+                        MatrixAddress { row: I::default(), column: first }..MatrixAddress { row: self.rows, column: column_num }));
+                    start = None;
+                },
+                (false, None) => start = Some(column_num),
+                _ => {},
+            }
+        }
+        if let Some(first) = start {
+            pieces.push(self.copy_window(
+                MatrixAddress { row: I::default(), column: first }..MatrixAddress { row: self.rows, column: self.columns }));
+        }
+        pieces
+    }
+}
+
 impl<'a, T: 'a, I> Matrix<'a, T, I> for DenseMatrix<T, I>
 where
     T: 'static,
@@ -89,7 +563,21 @@ where
     }
 }
 
-impl<'a, T: 'a, I> Tensor<T, I, MatrixAddress<I>, 2> for DenseMatrix<T, I>
+impl<'a, T: 'a, I> MatrixMut<'a, T, I> for DenseMatrix<T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn iter_mut(&mut self) -> MatrixValueIterMut<'_, T, I> {
+        MatrixValueIterMut::new(Box::new(self.data.iter_mut()))
+    }
+
+    fn indexed_iter_mut(&mut self) -> MatrixForwardIndexedIterMut<'_, T, I> {
+        MatrixForwardIndexedIterMut::new(self.addresses(), Box::new(self.data.iter_mut()))
+    }
+}
+
+impl<T, I> Tensor<T, I, MatrixAddress<I>, 2> for DenseMatrix<T, I>
 where
     I: Coordinate,
 {
@@ -127,7 +615,7 @@ where
     }
 }
 
-impl<'a, T, I> Index<MatrixAddress<I>> for DenseMatrix<T, I>
+impl<T, I> Index<MatrixAddress<I>> for DenseMatrix<T, I>
 where
     I: Coordinate,
 {
@@ -153,6 +641,156 @@ where
     }
 }
 
+/// Adding two matrices adds them cell by cell.  Like Index, it panics if the
+/// shapes differ; use combine for a Result instead.
+impl<T, I> Add for &DenseMatrix<T, I>
+where
+    T: Clone + Add<Output = T> + 'static,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn add(self, other: Self) -> DenseMatrix<T, I> {
+        elementwise(self, other, |a, b| a.clone() + b.clone())
+    }
+}
+
+impl<T, I> Add for DenseMatrix<T, I>
+where
+    T: Clone + Add<Output = T> + 'static,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn add(self, other: Self) -> DenseMatrix<T, I> {
+        &self + &other
+    }
+}
+
+/// Subtracting two matrices subtracts them cell by cell.  Like Index, it
+/// panics if the shapes differ; use combine for a Result instead.
+impl<T, I> Sub for &DenseMatrix<T, I>
+where
+    T: Clone + Sub<Output = T> + 'static,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn sub(self, other: Self) -> DenseMatrix<T, I> {
+        elementwise(self, other, |a, b| a.clone() - b.clone())
+    }
+}
+
+impl<T, I> Sub for DenseMatrix<T, I>
+where
+    T: Clone + Sub<Output = T> + 'static,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn sub(self, other: Self) -> DenseMatrix<T, I> {
+        &self - &other
+    }
+}
+
+/// Multiplying a matrix by a value scales every cell by it.
+impl<T, I> Mul<T> for &DenseMatrix<T, I>
+where
+    T: Clone + Mul<Output = T>,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn mul(self, k: T) -> DenseMatrix<T, I> {
+        self.scale(k)
+    }
+}
+
+impl<T, I> Mul<T> for DenseMatrix<T, I>
+where
+    T: Clone + Mul<Output = T>,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn mul(mut self, k: T) -> DenseMatrix<T, I> {
+        self.scale_mut(k);
+        self
+    }
+}
+
+/// Dividing a matrix by a value divides every cell by it.
+impl<T, I> Div<T> for &DenseMatrix<T, I>
+where
+    T: Clone + Div<Output = T>,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn div(self, k: T) -> DenseMatrix<T, I> {
+        let data = self.data.iter().map(|v| v.clone() / k.clone()).collect();
+        DenseMatrix::new(self.columns, self.rows, data)
+    }
+}
+
+impl<T, I> Div<T> for DenseMatrix<T, I>
+where
+    T: Clone + Div<Output = T>,
+    I: Coordinate,
+{
+    type Output = DenseMatrix<T, I>;
+
+    fn div(self, k: T) -> DenseMatrix<T, I> {
+        &self / k
+    }
+}
+
+impl<T, I> DenseMatrix<T, I>
+where
+    T: Clone + Mul<Output = T>,
+    I: Coordinate,
+{
+    /// scale returns a copy of the matrix with every cell multiplied by k.
+    pub fn scale(&self, k: T) -> DenseMatrix<T, I> {
+        let data = self.data.iter().map(|v| v.clone() * k.clone()).collect();
+        DenseMatrix::new(self.columns, self.rows, data)
+    }
+
+    /// scale_mut multiplies every cell by k in place.
+    pub fn scale_mut(&mut self, k: T) {
+        for v in self.data.iter_mut() {
+            *v = v.clone() * k.clone();
+        }
+    }
+}
+
+// elementwise applies op to corresponding cells of a and b, panicking with
+// assert_same_shape's error if their shapes differ.
+fn elementwise<T, I>(a: &DenseMatrix<T, I>, b: &DenseMatrix<T, I>, op: impl Fn(&T, &T) -> T) -> DenseMatrix<T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    if let Err(e) = assert_same_shape(a, b) {
+        panic!("{}", e);
+    }
+    let data = a.data.iter().zip(b.data.iter()).map(|(x, y)| op(x, y)).collect();
+    DenseMatrix::new(a.columns, a.rows, data)
+}
+
+/// Consuming a DenseMatrix yields its values in row-major order.
+impl<T, I> IntoIterator for DenseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
 impl<T, I> Clone for DenseMatrix<T, I>
 where
     T: Clone,
@@ -195,7 +833,9 @@ mod tests {
     use crate::error::Error;
     use crate::factories::*;
     use crate::format::FormatOptions;
-    use crate::traits::MatrixMap;
+    use crate::matrix_address::{Direction, NeighborKind};
+    use crate::shape::Shape;
+    use crate::traits::{CollectMatrix, MatrixMap};
     use super::*;
 
     fn ascii_formatting_options() -> FormatOptions {
@@ -362,6 +1002,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn dimensions_exceed_memory() {
         match panic::catch_unwind(|| {
             _ = new_default_matrix::<u32, u32>(u32::MAX, u32::MAX);
@@ -376,6 +1017,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn new_default_matrix_test() {
         let matrix = match new_default_matrix::<u8, u8>(1, 1) {
             Ok(g) => Box::new(g),
@@ -387,6 +1029,227 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
+    fn shaped_factories() {
+        let shim = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        let shaped = new_default_matrix_of::<u8, u8>(Shape { rows: 2, columns: 3 }).unwrap();
+        assert_eq!(shim, shaped);
+        let m = new_matrix_of(Shape { rows: 2, columns: 3 }, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m.shape(), Shape { rows: 2u8, columns: 3 });
+        assert_eq!(m[u8addr(1, 0)], 4);
+        assert_eq!(
+            new_matrix_of(Shape { rows: 2u8, columns: 2 }, vec![1, 2, 3]),
+            Err(Error::new("data length 3 does not match shape 2x2".to_string()))
+        );
+        let raw = DenseMatrix::from_raw_parts_of(Shape { rows: 2u8, columns: 3 }, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(raw, m);
+    }
+
+    #[test]
+    fn distance_matrix() {
+        let points = [(0i32, 0i32), (3, 4), (6, 0)];
+        let calls = std::cell::Cell::new(0);
+        let d = new_distance_matrix::<_, i32, u8>(&points, |a, b| {
+            calls.set(calls.get() + 1);
+            (a.0 - b.0).abs() + (a.1 - b.1).abs()
+        }).unwrap();
+        assert_eq!(d.data, vec![0, 7, 6, 7, 0, 7, 6, 7, 0]);
+        assert_eq!(calls.get(), 6);
+        let empty = new_distance_matrix::<u8, u8, u8>(&[], |_, _| 0).unwrap();
+        assert_eq!(empty.shape(), Shape::default());
+    }
+
+    #[test]
+    fn k_smallest_and_largest() {
+        let m = new_matrix::<u8, u8>(2, vec![5, 1, 9, 5, 7, 1]).unwrap();
+        assert_eq!(m.k_smallest(3), vec![(u8addr(0, 1), &1), (u8addr(1, 2), &1), (u8addr(0, 0), &5)]);
+        assert_eq!(m.k_largest(2), vec![(u8addr(0, 2), &9), (u8addr(1, 1), &7)]);
+        assert_eq!(m.k_largest(4)[2..], [(u8addr(0, 0), &5), (u8addr(1, 0), &5)]);
+        assert_eq!(m.k_largest(10).len(), 6);
+        assert!(m.k_smallest(0).is_empty());
+    }
+
+    #[test]
+    fn stack_matrices() {
+        let a = new_matrix::<u8, u8>(1, vec![1, 2]).unwrap();
+        let b = new_matrix::<u8, u8>(2, vec![3, 4, 5, 6]).unwrap();
+        let empty = new_matrix::<u8, u8>(0, vec![]).unwrap();
+        let tall = vstack(&[&a, &empty, &b]).unwrap();
+        assert_eq!(tall.shape(), Shape { rows: 3, columns: 2 });
+        assert_eq!(tall.data, vec![1, 2, 3, 4, 5, 6]);
+        let wide = hstack(&[&b, &b.transposed()]).unwrap();
+        assert_eq!(wide.data, vec![3, 4, 3, 5, 5, 6, 4, 6]);
+        assert_eq!(
+            hstack(&[&a, &b]),
+            Err(Error::new("cannot hstack matrices with 1 and 2 rows".to_string()))
+        );
+        assert!(vstack(&[&a, &a.transposed()]).is_err());
+        assert_eq!(vstack::<u8, u8>(&[]).unwrap().shape(), Shape::default());
+    }
+
+    #[test]
+    fn convert_index_types() {
+        let small = new_matrix::<u8, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let wide: DenseMatrix<u8, u32> = small.convert_index_type().unwrap();
+        assert_eq!(wide.shape(), Shape { rows: 2u32, columns: 3 });
+        assert_eq!(wide[MatrixAddress { row: 1u32, column: 2 }], 6);
+        let big = new_matrix::<u8, u16>(300, vec![0; 300]).unwrap();
+        assert_eq!(
+            big.convert_index_type::<u8>(),
+            Err(Error::new("dimensions 300x1 do not fit the target index type".to_string()))
+        );
+        assert!(big.convert_index_type::<i16>().is_ok());
+    }
+
+    #[test]
+    fn padded_border() {
+        let m = new_matrix::<char, u8>(1, vec!['a', 'b']).unwrap();
+        let p = m.padded(1, '#').unwrap();
+        assert_eq!(FormatOptions::default().format(&p, |c| c.to_string()), "####\n#ab#\n####");
+        assert_eq!(m.padded(0, '#').unwrap(), m);
+        let empty = new_matrix::<char, u8>(0, vec![]).unwrap();
+        assert_eq!(empty.padded(2, '.').unwrap().shape(), Shape { rows: 4, columns: 4 });
+        assert!(m.padded(200, '#').is_err());
+        let signed = new_matrix::<u8, i8>(1, vec![1]).unwrap();
+        assert!(signed.padded(-1, 0).is_err());
+        let wide = new_matrix::<u8, u64>(1, vec![1]).unwrap();
+        assert_eq!(
+            wide.padded(u64::MAX / 2, 0),
+            Err(Error::new(format!("padding 1x1 by {} overflows usize", u64::MAX / 2)))
+        );
+        assert!(wide.padded(u64::MAX, 0).is_err());
+    }
+
+    #[test]
+    fn submatrix_copies() {
+        let m = new_matrix::<u8, u8>(3, (1..=9).collect()).unwrap();
+        let tile = m.submatrix_copy(u8addr(1, 1)..u8addr(3, 3)).unwrap();
+        assert_eq!(tile.data, vec![5, 6, 8, 9]);
+        assert_eq!(m.submatrix_copy(u8addr(0, 0)..u8addr(3, 3)).unwrap(), m);
+        assert_eq!(
+            m.submatrix_copy(u8addr(2, 0)..u8addr(4, 1)),
+            Err(Error::new("crop range (row=2,col=0)..(row=4,col=1) is empty or outside the matrix".to_string()))
+        );
+        assert!(m.submatrix_copy(u8addr(1, 1)..u8addr(1, 2)).is_err());
+    }
+
+    #[test]
+    fn find_and_find_all() {
+        let m = FormatOptions::default().parse_matrix::<char, u8>(".S0\n0#.", |x| x.chars().next().unwrap()).unwrap();
+        assert_eq!(m.find(|c| *c == 'S'), Some(u8addr(0, 1)));
+        assert_eq!(m.find(|c| *c == 'E'), None);
+        assert_eq!(m.find_all(|c| *c == '0').collect::<Vec<_>>(), vec![u8addr(0, 2), u8addr(1, 0)]);
+        assert_eq!(m.find_all(|c| *c == 'E').count(), 0);
+    }
+
+    #[test]
+    fn count_any_all() {
+        let seats = new_matrix::<char, u8>(2, vec!['#', 'L', '#', '.']).unwrap();
+        assert_eq!(seats.count_where(|c| *c == '#'), 2);
+        assert!(seats.any(|c| *c == 'L'));
+        assert!(!seats.all(|c| *c != '.'));
+        let empty = new_default_matrix_of::<char, u8>(Shape::default()).unwrap();
+        assert!(!empty.any(|_| true));
+        assert!(empty.all(|_| false));
+    }
+
+    #[test]
+    fn min_max_by_address() {
+        let m = new_matrix::<i32, u8>(2, vec![3, -7, 9, 9, -7, 1]).unwrap();
+        assert_eq!(m.min_by_address(), Some((u8addr(0, 1), &-7)));
+        assert_eq!(m.max_by_address(), Some((u8addr(0, 2), &9)));
+        assert_eq!(m.min_by_address_by_key(|v| v.abs()), Some((u8addr(1, 2), &1)));
+        assert_eq!(m.max_by_address_by_key(|v| v.abs()), Some((u8addr(0, 2), &9)));
+        let empty = new_default_matrix_of::<i32, u8>(Shape::default()).unwrap();
+        assert_eq!(empty.max_by_address(), None);
+    }
+
+    #[test]
+    fn try_map() {
+        let m = new_matrix::<&str, u8>(2, vec!["1", "2", "3", "4"]).unwrap();
+        let parsed = m.try_map_matrix(|s| s.parse::<u32>()).unwrap();
+        assert_eq!(parsed, new_matrix::<u32, u8>(2, vec![1, 2, 3, 4]).unwrap());
+        let bad = new_matrix::<&str, u8>(1, vec!["1", "x", "y"]).unwrap();
+        assert!(bad.try_map_matrix(|s| s.parse::<u32>()).is_err());
+        let indexed = m.try_map_indexed_matrix(|addr, s| match addr.row {
+            0 => Ok(s.to_string()),
+            _ => Err(format!("row {}", addr.row)),
+        });
+        assert_eq!(indexed, Err("row 1".to_string()));
+    }
+
+    #[test]
+    fn read_line() {
+        let m = FormatOptions::default().parse_matrix::<char, u8>("XMAS\nSAMX\nAXAM", |x| x.chars().next().unwrap()).unwrap();
+        assert_eq!(m.read_line(u8addr(0, 0), Direction::East, 4), Some(vec![&'X', &'M', &'A', &'S']));
+        assert_eq!(m.read_line(u8addr(1, 3), Direction::West, 4), Some(vec![&'X', &'M', &'A', &'S']));
+        assert_eq!(m.read_line(u8addr(0, 0), Direction::SouthEast, 3), Some(vec![&'X', &'A', &'A']));
+        assert_eq!(m.read_line(u8addr(0, 2), Direction::East, 3), None);
+        assert_eq!(m.read_line(u8addr(0, 0), Direction::North, 1), Some(vec![&'X']));
+        assert_eq!(m.read_line(u8addr(5, 0), Direction::North, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn zip_matrices() {
+        let before = new_matrix::<char, u8>(2, vec!['.', '#', '#', '.']).unwrap();
+        let after = new_matrix::<char, u8>(2, vec!['#', '#', '.', '.']).unwrap();
+        let pairs = before.zip(&after).unwrap();
+        assert_eq!(pairs[u8addr(0, 0)], ('.', '#'));
+        assert_eq!(pairs[u8addr(1, 1)], ('.', '.'));
+        let changed: Vec<MatrixAddress<u8>> = before.zip_iter(&after).unwrap()
+            .filter(|(_, b, a)| b != a)
+            .map(|(addr, _, _)| addr)
+            .collect();
+        assert_eq!(changed, vec![u8addr(0, 0), u8addr(1, 0)]);
+        let wide = new_matrix::<char, u8>(1, vec!['.'; 4]).unwrap();
+        assert_eq!(
+            before.zip(&wide).err(),
+            Some(Error::new("matrix shapes differ: 2x2 vs 1x4".to_string()))
+        );
+    }
+
+    #[test]
+    fn combine_matrices() {
+        let costs = new_matrix::<u32, u8>(2, vec![1, 2, 3, 4]).unwrap();
+        let extra = new_matrix::<u32, u8>(2, vec![10, 20, 30, 40]).unwrap();
+        assert_eq!(costs.combine(&extra, |a, b| a + b).unwrap().data, vec![11, 22, 33, 44]);
+        let mask = new_matrix::<bool, u8>(2, vec![true, false, false, true]).unwrap();
+        assert_eq!(costs.combine(&mask, |c, m| if *m { *c } else { 0 }).unwrap().data, vec![1, 0, 0, 4]);
+        let row = new_matrix::<bool, u8>(1, vec![true; 4]).unwrap();
+        assert!(costs.combine(&row, |c, _| *c).is_err());
+    }
+
+    #[test]
+    fn add_and_sub_operators() {
+        let a = new_matrix::<i32, u8>(2, vec![1, 2, 3, 4]).unwrap();
+        let b = new_matrix::<i32, u8>(2, vec![10, 20, 30, 40]).unwrap();
+        assert_eq!((&a + &b).data, vec![11, 22, 33, 44]);
+        assert_eq!((&a - &b).data, vec![-9, -18, -27, -36]);
+        assert_eq!(b.clone() - a.clone() + a.clone(), b);
+        let column = new_matrix::<i32, u8>(4, vec![1, 2, 3, 4]).unwrap();
+        assert!(panic::catch_unwind(|| &a + &column).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "matrix shapes differ: 2x2 vs 4x1")]
+    fn sub_mismatched_shapes() {
+        let a = new_matrix::<i32, u8>(2, vec![1, 2, 3, 4]).unwrap();
+        let _ = a - new_matrix::<i32, u8>(4, vec![1, 2, 3, 4]).unwrap();
+    }
+
+    #[test]
+    fn scalar_operators() {
+        let mut a = new_matrix::<i32, u8>(2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(a.scale(3).data, vec![3, 6, 9, 12]);
+        assert_eq!((&a * -1).data, vec![-1, -2, -3, -4]);
+        a.scale_mut(10);
+        assert_eq!(a.data, vec![10, 20, 30, 40]);
+        assert_eq!((a.clone() / 10).data, vec![1, 2, 3, 4]);
+        assert_eq!(a * 2 / 4, new_matrix::<i32, u8>(2, vec![5, 10, 15, 20]).unwrap());
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn row_column_access() {
         let g = match new_default_matrix::<u8, u8>(1, 1) {
             Ok(res) => res,
@@ -403,6 +1266,191 @@ mod tests {
     }
 
     #[test]
+    fn iter_mut_updates_every_cell() {
+        let mut m = new_matrix::<u32, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        m.iter_mut().for_each(|v| *v += 10);
+        assert_eq!(m.data, vec![11, 12, 13, 14, 15, 16]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn indexed_iter_mut_yields_addresses() {
+        let mut m = new_default_matrix::<u32, u8>(3, 2).unwrap();
+        for (addr, v) in m.indexed_iter_mut() {
+            *v = (10 * addr.row + addr.column) as u32;
+        }
+        assert_eq!(m.data, vec![0, 1, 2, 10, 11, 12]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn rows_mut_rewrites_rows() {
+        let mut m = new_matrix::<u32, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        for mut row in m.rows_mut() {
+            let shift = row.row() as usize + 1;
+            row.as_mut_slice().rotate_left(shift);
+            *row.get_mut(0).unwrap() *= 10;
+        }
+        assert_eq!(m.data, vec![20, 3, 1, 60, 4, 5]);
+        assert!(new_default_matrix::<u8, u8>(0, 0).unwrap().rows_mut().next().is_none());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn rows_chunks_split_storage() {
+        let mut m = new_matrix::<u32, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let rows: Vec<&[u32]> = m.rows_chunks().collect();
+        assert_eq!(rows, vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+        std::thread::scope(|s| {
+            for chunk in m.rows_chunks_mut() {
+                s.spawn(move || chunk.reverse());
+            }
+        });
+        assert_eq!(m.data, vec![3, 2, 1, 6, 5, 4]);
+        assert_eq!(new_default_matrix::<u8, u8>(0, 0).unwrap().rows_chunks().count(), 0);
+    }
+
+    #[test]
+    fn upscale_expands_cells() {
+        let m = new_matrix::<char, u8>(2, vec!['|', '-', 'L', '.']).unwrap();
+        let zoomed = m.upscale(3, 3, |c| {
+            let tile = match c {
+                '|' => ".#..#..#.",
+                '-' => "...###...",
+                'L' => ".#..##...",
+                _ => ".........",
+            };
+            new_matrix(3, tile.chars().collect()).unwrap()
+        }).unwrap();
+        let got = ascii_formatting_options().format(&zoomed, |c| c.to_string());
+        assert_eq!(got, ".#....\n.#.###\n.#....\n.#....\n.##...\n......");
+        let cloned = m.upscale_cloned(1, 2).unwrap();
+        assert_eq!(cloned.data, vec!['|', '|', '-', '-', 'L', 'L', '.', '.']);
+        assert_eq!(
+            m.upscale(2, 2, |c| new_matrix(1, vec![*c; 4]).unwrap()).err(),
+            Some(Error::new("upscale tile is 1x4, expected 2x2".to_string()))
+        );
+        assert!(m.upscale_cloned(0, 2).is_err());
+        assert!(m.upscale_cloned(200, 1).is_err());
+        let single = new_matrix::<char, u64>(1, vec!['x']).unwrap();
+        assert_eq!(
+            single.upscale_cloned(u64::MAX, u64::MAX).err(),
+            Some(Error::new("upscaled dimensions exceed chosen index size".to_string()))
+        );
+    }
+
+    #[test]
+    fn row_mut_access() {
+        let mut m = new_matrix::<u32, i8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert!(m.row_mut(2).is_none());
+        assert!(m.row_mut(-1).is_none());
+        let mut row = m.row_mut(1).unwrap();
+        assert_eq!(row.get(3), None);
+        row.iter_mut().for_each(|v| *v = 0);
+        assert_eq!(row.iter().collect::<Vec<&u32>>(), vec![&0, &0, &0]);
+        assert_eq!(m.data, vec![1, 2, 3, 0, 0, 0]);
+    }
+
+    #[test]
+    fn trimmed_removes_background_borders() {
+        let opts = ascii_formatting_options();
+        let m = opts.parse_matrix::<char, u8>("......\n..#...\n...#..\n......", |x| x.chars().next().unwrap())
+            .unwrap();
+        assert_eq!(m.bounding_box(|c| *c == '.'), Some(u8addr(1, 2)..u8addr(3, 4)));
+        let got = m.trimmed(|c| *c == '.');
+        assert_eq!(opts.format(&got, |c| c.to_string()), "#.\n.#");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn trimmed_all_background() {
+        let m = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        assert_eq!(m.bounding_box(|v| *v == 0), None);
+        let got = m.trimmed(|v| *v == 0);
+        assert_eq!(got.row_count(), 0);
+        assert_eq!(got.column_count(), 0);
+        assert_eq!(m.trimmed(|v| *v == 1), m);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn columns_mut_rewrites_columns() {
+        let mut m = new_matrix::<u32, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let mut columns: Vec<ColumnMut<u32, u8>> = m.columns_mut().collect();
+        assert_eq!(columns.len(), 3);
+        columns[0].swap(0, 1);
+        *columns[2].get_mut(0).unwrap() = 30;
+        columns[1].iter_mut().for_each(|v| *v *= 100);
+        assert_eq!(columns[1].column(), 1);
+        assert!(new_default_matrix::<u8, u8>(0, 0).unwrap().columns_mut().next().is_none());
+        assert_eq!(m.data, vec![4, 200, 30, 1, 500, 6]);
+    }
+
+    #[test]
+    fn column_mut_access() {
+        let mut m = new_matrix::<u32, i8>(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert!(m.column_mut(2).is_none());
+        assert!(m.column_mut(-1).is_none());
+        let mut column = m.column_mut(1).unwrap();
+        assert_eq!(column.get(3), None);
+        assert_eq!(column.iter().rev().collect::<Vec<&u32>>(), vec![&6, &4, &2]);
+        column.swap(0, 2);
+        assert_eq!(m.data, vec![1, 6, 3, 4, 5, 2]);
+    }
+
+    #[test]
+    fn into_iter_moves_values() {
+        let m = new_matrix::<Box<u8>, u8>(2, vec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)]).unwrap();
+        let got: Vec<u8> = m.into_iter().map(|v| *v).collect();
+        assert_eq!(got, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn into_indexed_iter_moves_values() {
+        let m = new_matrix::<Box<u8>, u8>(2, vec![Box::new(1), Box::new(2), Box::new(3), Box::new(4)]).unwrap();
+        let got: Vec<(MatrixAddress<u8>, u8)> = m.into_indexed_iter().map(|(a, v)| (a, *v)).collect();
+        assert_eq!(got, vec![
+            (u8addr(0, 0), 1),
+            (u8addr(0, 1), 2),
+            (u8addr(1, 0), 3),
+            (u8addr(1, 1), 4),
+        ]);
+        assert!(new_default_matrix::<u8, u8>(0, 0).unwrap().into_indexed_iter().next().is_none());
+    }
+
+    #[test]
+    fn collect_into_matrix() {
+        let m = (1..=6u32).collect_matrix(3u8).unwrap();
+        assert_eq!(m.row_count(), 3);
+        assert_eq!(m.column_count(), 2);
+        assert_eq!(m[u8addr(2, 1)], 6);
+        let built = new_matrix_from_iter(2u8, "abcd".chars()).unwrap();
+        assert_eq!(built[u8addr(1, 0)], 'c');
+        let err = (1..=5u32).collect_matrix(2u8).err().unwrap();
+        assert_eq!(err, Error::new("data length 5 is not a multiple of rows (2)".to_string()));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn linear_index_conversions() {
+        let m = new_default_matrix::<u8, i8>(3, 2).unwrap();
+        let mut seen = 0;
+        for (i, addr) in m.addresses().enumerate() {
+            assert_eq!(m.address_to_index(addr), Some(i));
+            assert_eq!(m.index_to_address(i), Some(addr));
+            seen += 1;
+        }
+        assert_eq!(seen, 6);
+        assert_eq!(m.index_to_address(6), None);
+        assert_eq!(m.address_to_index(MatrixAddress { row: 0, column: 3 }), None);
+        assert_eq!(m.address_to_index(MatrixAddress { row: -1, column: 0 }), None);
+        let empty = new_default_matrix::<u8, u8>(0, 0).unwrap();
+        assert_eq!(empty.index_to_address(0), None);
+    }
+
+    #[test]
+    #[allow(clippy::map_clone)]
     fn test_map_matrix() {
         let m = FormatOptions::default()
             .parse_matrix::<String, u8>("123\n456", |v| v.to_string())
@@ -416,6 +1464,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::map_clone)]
     fn test_indexed_map_matrix() {
         let m = FormatOptions::default()
             .parse_matrix::<String, u8>("123\n456", |v| v.to_string())
@@ -435,4 +1484,197 @@ mod tests {
             .collect::<Vec<u64>>();
         assert_eq!(row1_values, vec!(5u64, 16u64, 27u64));
     }
+
+    #[test]
+    fn test_map_with_neighbors() {
+        let m = new_matrix::<u8, u8>(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+        let sum = |v: &u8, n: &[&u8]| n.iter().map(|x| **x).sum::<u8>() + *v;
+        let orthogonal = m.map_with_neighbors(NeighborKind::Orthogonal4, &sum);
+        assert_eq!(orthogonal.data, vec![7, 11, 11, 17, 25, 23, 19, 29, 23]);
+        let seen = m.map_with_neighbors(NeighborKind::Diagonal4, &|_, n| n.iter().map(|x| **x).collect::<Vec<u8>>());
+        assert_eq!(seen[MatrixAddress { row: 1, column: 1 }], vec![1, 3, 7, 9]);
+        assert_eq!(seen[MatrixAddress { row: 2, column: 0 }], vec![5]);
+        let counts = m.map_with_neighbors(NeighborKind::All8, &|_, n| n.len());
+        assert_eq!(counts.data, vec![3, 5, 3, 5, 8, 5, 3, 5, 3]);
+    }
+
+    #[test]
+    fn test_get_many_and_set_many() {
+        let mut m = new_matrix::<u8, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let addr = |row, column| MatrixAddress { row, column };
+        assert_eq!(m.get_many(&[addr(1, 2), addr(2, 0), addr(0, 0)]), vec![Some(&6), None, Some(&1)]);
+        m.set_many(&[(addr(0, 1), 9), (addr(1, 1), 8), (addr(0, 1), 7)]).unwrap();
+        assert_eq!(m.data, vec![1, 7, 3, 4, 8, 6]);
+        assert_eq!(
+            m.set_many(&[(addr(0, 0), 0), (addr(0, 3), 0)]),
+            Err(Error::new("address (row=0,col=3) is out of bounds".to_string()))
+        );
+        assert_eq!(m.data, vec![1, 7, 3, 4, 8, 6]);
+    }
+
+    #[test]
+    fn test_translate_values() {
+        let mut m = FormatOptions::default()
+            .parse_matrix::<char, u8>("#O.\n.O#", |x| x.chars().next().unwrap())
+            .unwrap();
+        let map = HashMap::from([('#', 'X'), ('O', '#')]);
+        m.translate_values(&map);
+        assert_eq!(FormatOptions::default().format(&m, |c| c.to_string()), "X#.\n.#X");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_expand_rows_and_columns_where() {
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>("#..\n...\n..#", |x| x.chars().next().unwrap())
+            .unwrap();
+        let rows = m.expand_rows_where(|row| row.iter().all(|c| *c == '.'), 2).unwrap();
+        assert_eq!(FormatOptions::default().format(&rows, |c| c.to_string()), "#..\n...\n...\n...\n..#");
+        let columns = m.expand_columns_where(|column| column.iter().all(|c| *c == '.'), 1).unwrap();
+        assert_eq!(FormatOptions::default().format(&columns, |c| c.to_string()), "#...\n....\n...#");
+        let big = new_default_matrix::<u8, u8>(1, 200).unwrap();
+        assert!(big.expand_rows_where(|_| true, 1).is_err());
+    }
+
+    #[test]
+    fn test_iter_non_default() {
+        let m = new_matrix::<u8, u8>(2, vec![0, 3, 0, 5, 0, 0]).unwrap();
+        let got: Vec<(MatrixAddress<u8>, &u8)> = m.iter_non_default().collect();
+        assert_eq!(got, vec![
+            (MatrixAddress { row: 0, column: 1 }, &3),
+            (MatrixAddress { row: 1, column: 0 }, &5),
+        ]);
+    }
+
+    #[test]
+    fn test_split_on_rows_and_columns_where() {
+        let opts = FormatOptions::default();
+        let m = opts
+            .parse_matrix::<char, u8>("ab.c\n....\nde.f\ngh.i\n....", |x| x.chars().next().unwrap())
+            .unwrap();
+        let blank_row = |row: Row<char, u8>| row.iter().all(|c| *c == '.');
+        let pieces: Vec<String> = m.split_on_rows_where(blank_row)
+            .iter()
+            .map(|p| opts.format(p, |c| c.to_string()))
+            .collect();
+        assert_eq!(pieces, vec!["ab.c", "de.f\ngh.i"]);
+        let top = &m.split_on_rows_where(blank_row)[0];
+        let pieces: Vec<String> = top.split_on_columns_where(|column| column.iter().all(|c| *c == '.'))
+            .iter()
+            .map(|p| opts.format(p, |c| c.to_string()))
+            .collect();
+        assert_eq!(pieces, vec!["ab", "c"]);
+        assert!(m.split_on_rows_where(|_| true).is_empty());
+    }
+
+    #[test]
+    fn test_raw_parts_round_trip() {
+        let m = DenseMatrix::<u8, u8>::from_raw_parts(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m[MatrixAddress { row: 1, column: 0 }], 4);
+        let (rows, columns, data) = m.into_inner();
+        assert_eq!((rows, columns, data), (2, 3, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(
+            DenseMatrix::<u8, u8>::from_raw_parts(2, 3, vec![1, 2]).err(),
+            Some(Error::new("data length 2 does not match matrix dimensions (6)".to_string()))
+        );
+        let empty = DenseMatrix::<u8, u8>::from_raw_parts(4, 0, Vec::new()).unwrap();
+        assert_eq!(empty.row_count(), 0);
+    }
+
+    #[test]
+    fn test_owned_transposed() {
+        let m = new_matrix::<u8, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let t = m.transposed();
+        assert_eq!(t.row_count(), 3);
+        assert_eq!(t.column_count(), 2);
+        assert_eq!(t.data, vec![1, 4, 2, 5, 3, 6]);
+        assert_eq!(t.transposed(), m);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_diagonals() {
+        let m = new_matrix::<u8, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(m.diagonal().copied().collect::<Vec<u8>>(), vec![1, 5]);
+        assert_eq!(m.anti_diagonal().copied().collect::<Vec<u8>>(), vec![3, 5]);
+        assert_eq!(m.anti_diagonal().rev().copied().collect::<Vec<u8>>(), vec![5, 3]);
+        let tall = m.transposed();
+        assert_eq!(tall.diagonal().copied().collect::<Vec<u8>>(), vec![1, 5]);
+        assert_eq!(tall.anti_diagonal().copied().collect::<Vec<u8>>(), vec![4, 2]);
+        let empty = new_default_matrix::<u8, u8>(0, 0).unwrap();
+        assert_eq!(empty.anti_diagonal().count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors_with_values() {
+        let m = new_matrix::<u8, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let got: Vec<(MatrixAddress<u8>, u8)> = m.neighbors(MatrixAddress { row: 0, column: 1 }, NeighborKind::Orthogonal4)
+            .map(|(a, v)| (a, *v))
+            .collect();
+        assert_eq!(got, vec![
+            (MatrixAddress { row: 0, column: 0 }, 1),
+            (MatrixAddress { row: 0, column: 2 }, 3),
+            (MatrixAddress { row: 1, column: 1 }, 5),
+        ]);
+        assert_eq!(m.neighbors(MatrixAddress { row: 1, column: 2 }, NeighborKind::All8).count(), 3);
+        assert_eq!(m.neighbors(MatrixAddress { row: 2, column: 0 }, NeighborKind::All8).count(), 0);
+    }
+
+    #[test]
+    fn test_ray() {
+        let m = new_matrix::<u8, u8>(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+        let values = |start: MatrixAddress<u8>, d: Direction| m.ray(start, d).map(|(_, v)| *v).collect::<Vec<u8>>();
+        assert_eq!(values(MatrixAddress { row: 1, column: 0 }, Direction::East), vec![4, 5, 6]);
+        assert_eq!(values(MatrixAddress { row: 2, column: 2 }, Direction::NorthWest), vec![9, 5, 1]);
+        assert_eq!(values(MatrixAddress { row: 0, column: 1 }, Direction::North), vec![2]);
+        assert_eq!(values(MatrixAddress { row: 0, column: 1 }, Direction::SouthWest), vec![2, 4]);
+        assert!(values(MatrixAddress { row: 3, column: 0 }, Direction::North).is_empty());
+        let last = m.ray(MatrixAddress { row: 0, column: 0 }, Direction::South).skip(1).last().unwrap();
+        assert_eq!(last.0, MatrixAddress { row: 2, column: 0 });
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_value_at_tiled() {
+        let m = new_matrix::<u8, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(*m.value_at_tiled(0, 0), 1);
+        assert_eq!(*m.value_at_tiled(3, 4), 5);
+        assert_eq!(*m.value_at_tiled(-1, -1), 6);
+        assert_eq!(*m.value_at_tiled(1_000_000_000_001, -3_000_000_000_002), 5);
+        let shifted = m.value_at_tiled_with(-3, 7, |v, (tr, tc)| (*v, tr, tc));
+        assert_eq!(shifted, (5, -2, 2));
+        let empty = new_default_matrix::<u8, u8>(0, 0).unwrap();
+        assert!(panic::catch_unwind(|| *empty.value_at_tiled(0, 0)).is_err());
+    }
+
+    #[test]
+    fn test_group_addresses_by_value() {
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>("a.b\n.a.\nb.a", |x| x.chars().next().unwrap())
+            .unwrap();
+        let groups = m.group_addresses_by_value();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&'a'], vec![
+            MatrixAddress { row: 0, column: 0 },
+            MatrixAddress { row: 1, column: 1 },
+            MatrixAddress { row: 2, column: 2 },
+        ]);
+        assert_eq!(groups[&'b'], vec![MatrixAddress { row: 0, column: 2 }, MatrixAddress { row: 2, column: 0 }]);
+        assert_eq!(groups[&'.'].len(), 4);
+    }
+
+    #[test]
+    fn test_address_pairs() {
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>("a..\n.b.\n..a", |x| x.chars().next().unwrap())
+            .unwrap();
+        let a = |row, column| MatrixAddress { row, column };
+        let pairs: Vec<(MatrixAddress<u8>, MatrixAddress<u8>)> = m.address_pairs(|c| *c != '.').collect();
+        assert_eq!(pairs, vec![(a(0, 0), a(1, 1)), (a(0, 0), a(2, 2)), (a(1, 1), a(2, 2))]);
+        let same: Vec<(MatrixAddress<u8>, MatrixAddress<u8>)> = m.same_value_address_pairs(|c| *c != '.').collect();
+        assert_eq!(same, vec![(a(0, 0), a(2, 2))]);
+        assert_eq!(m.address_pairs(|_| true).count(), 36);
+        assert_eq!(m.address_pairs(|c| *c == 'b').count(), 0);
+        assert_eq!(m.same_value_address_pairs(|_| true).count(), 1 + 15);
+    }
 }
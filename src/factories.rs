@@ -1,6 +1,25 @@
-use crate::{Coordinate, Matrix};
+use std::ops::Range;
+use crate::{Coordinate, Matrix, MatrixMut};
 use crate::error::Error;
+use crate::attributed_matrix::AttributedMatrix;
+use crate::bit_matrix::{BitMatrix, WORD_BITS};
+use crate::fn_matrix::{CellCache, FnMatrix};
+use crate::grid_walker::GridWalker;
+use crate::cube::Cube;
+use crate::cube_address::CubeAddress;
 use crate::dense_matrix::DenseMatrix;
+use crate::growable_grid::GrowableGrid;
+use crate::hypercube::HyperCube;
+use crate::hypercube_address::HyperCubeAddress;
+use crate::labeled_matrix::LabeledMatrix;
+use crate::matrix_address::{Direction, MatrixAddress};
+use crate::matrix_history::MatrixHistory;
+use crate::nd_tensor::NdTensor;
+use crate::overlay::OverlayMatrix;
+use crate::shape::{assert_same_shape, Shape};
+use crate::sparse_matrix::SparseMatrix;
+use crate::sub_matrix::{SubMatrix, SubMatrixMut};
+use crate::transform::{AddressTransform, FlippedMatrix, RotatedMatrix, TransformedView};
 use crate::transpose::TransposedMatrix;
 
 pub fn new_transposed_matrix<'a: 'b, 'b, T, I>(underlay: &'b mut dyn Matrix<'b, T, I>) -> TransposedMatrix<'b, T, I>
@@ -12,6 +31,144 @@ where
     }
 }
 
+/// new_transformed_view creates an untransformed TransformedView over underlay,
+/// ready to be rotated, flipped, transposed or cropped.
+pub fn new_transformed_view<'a, T, I>(underlay: &'a mut dyn Matrix<'a, T, I>) -> TransformedView<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let transform = AddressTransform::identity(underlay.row_count(), underlay.column_count());
+    TransformedView {
+        underlay,
+        transform,
+    }
+}
+
+/// new_rotated_cw creates a view of underlay turned a quarter turn clockwise.
+pub fn new_rotated_cw<'a, T, I>(underlay: &'a mut dyn Matrix<'a, T, I>) -> RotatedMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    new_transformed_view(underlay).rotated_cw()
+}
+
+/// new_rotated_180 creates a view of underlay turned a half turn.
+pub fn new_rotated_180<'a, T, I>(underlay: &'a mut dyn Matrix<'a, T, I>) -> RotatedMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    new_transformed_view(underlay).rotated_180()
+}
+
+/// new_rotated_ccw creates a view of underlay turned a quarter turn
+/// counter-clockwise, which is three quarter turns clockwise.
+pub fn new_rotated_ccw<'a, T, I>(underlay: &'a mut dyn Matrix<'a, T, I>) -> RotatedMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    new_transformed_view(underlay).rotated_ccw()
+}
+
+/// new_flipped_columns creates a view of underlay reflected across its vertical
+/// axis, so each row reads right to left.
+pub fn new_flipped_columns<'a, T, I>(underlay: &'a mut dyn Matrix<'a, T, I>) -> FlippedMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    new_transformed_view(underlay).flipped_columns()
+}
+
+/// new_flipped_rows creates a view of underlay reflected across its horizontal
+/// axis, so each column reads bottom to top.
+pub fn new_flipped_rows<'a, T, I>(underlay: &'a mut dyn Matrix<'a, T, I>) -> FlippedMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    new_transformed_view(underlay).flipped_rows()
+}
+
+/// new_transformed_view_with creates a TransformedView over underlay using an
+/// existing transform.  The transform's window must lie within the underlay.
+pub fn new_transformed_view_with<'a, T, I>(underlay: &'a mut dyn Matrix<'a, T, I>, transform: AddressTransform<I>) -> crate::error::Result<TransformedView<'a, T, I>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let window = transform.window();
+    if window.end.row > underlay.row_count() || window.end.column > underlay.column_count() {
+        return Err(Error::new("transform window exceeds the underlying matrix".to_string()));
+    }
+    Ok(TransformedView {
+        underlay,
+        transform,
+    })
+}
+
+/// new_sub_matrix creates a read-only view of a rectangular window of underlay.
+/// The window must lie within the underlay.  A window with zero rows or zero
+/// columns is normalized to zero rows and zero columns.
+pub fn new_sub_matrix<'a, T, I>(underlay: &'a dyn Matrix<'a, T, I>, window: Range<MatrixAddress<I>>) -> crate::error::Result<SubMatrix<'a, T, I>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    Ok(SubMatrix {
+        window: checked_window(underlay.range().end, window)?,
+        underlay,
+    })
+}
+
+/// new_sub_matrix_mut creates a mutable view of a rectangular window of underlay.
+/// The window must lie within the underlay.  A window with zero rows or zero
+/// columns is normalized to zero rows and zero columns.
+pub fn new_sub_matrix_mut<'a, T, I>(underlay: &'a mut dyn MatrixMut<'a, T, I>, window: Range<MatrixAddress<I>>) -> crate::error::Result<SubMatrixMut<'a, T, I>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    Ok(SubMatrixMut {
+        window: checked_window(underlay.range().end, window)?,
+        underlay,
+    })
+}
+
+/// checked_window validates that window lies within a matrix whose exclusive
+/// end address is end, normalizing empty windows.
+fn checked_window<I>(end: MatrixAddress<I>, window: Range<MatrixAddress<I>>) -> crate::error::Result<Range<MatrixAddress<I>>>
+where
+    I: Coordinate,
+{
+    let zero = I::default();
+    if window.start.row < zero || window.start.column < zero
+        || window.start.row > window.end.row || window.start.column > window.end.column {
+        return Err(Error::new("sub matrix window is inverted or negative".to_string()));
+    }
+    if window.end.row > end.row || window.end.column > end.column {
+        return Err(Error::new("sub matrix window exceeds the underlying matrix".to_string()));
+    }
+    if window.start.row == window.end.row || window.start.column == window.end.column {
+        return Ok(window.start..window.start);
+    }
+    Ok(window)
+}
+
+/// new_overlay_matrix creates an OverlayMatrix over base with a single, empty, layer.
+pub fn new_overlay_matrix<'a, T, I>(base: &'a mut dyn MatrixMut<'a, T, I>) -> OverlayMatrix<'a, T, I>
+where
+    I: Coordinate,
+{
+    OverlayMatrix{
+        base,
+        layers: vec![std::collections::HashMap::new()],
+    }
+}
+
 /// new_matrix creates a matrix from a vector of values in row-major order.
 /// The length of data must be a multiple of rows, and that multiple will become the
 /// column_count.
@@ -35,7 +192,7 @@ where
     if len == 0 {
         return Err(Error::new("missing row data".to_string()));
     }
-    if len % row_usize != 0 {
+    if !len.is_multiple_of(row_usize) {
         return Err(Error::new(format!("data length {} is not a multiple of rows ({})", len, row_usize)))
     }
     let columns_usize = len / row_usize;
@@ -46,14 +203,119 @@ where
     Ok(DenseMatrix::new(columns, rows, data))
 }
 
-/// new_default_matrix creates a matrix of type T where all cells contain T::default()
-/// (typically a zero value).
-pub fn new_default_matrix<'a, T, I>(columns: I, rows: I) -> crate::error::Result<DenseMatrix<T, I>>
+/// new_matrix_from_iter creates a matrix from an iterator of values in row-major
+/// order, collecting directly into the matrix storage.  The number of values must
+/// be a multiple of rows, and that multiple will become the column_count.
+pub fn new_matrix_from_iter<T, I>(rows: I, values: impl IntoIterator<Item = T>) -> crate::error::Result<DenseMatrix<T, I>>
+where
+    I: Coordinate,
+{
+    new_matrix(rows, values.into_iter().collect())
+}
+
+/// vstack concatenates matrices vertically, the rows of each following those
+/// of the one before.  Every non-empty matrix must have the same number of
+/// columns; empty matrices are skipped.
+pub fn vstack<'a, T, I>(matrices: &[&'a dyn Matrix<'a, T, I>]) -> crate::error::Result<DenseMatrix<T, I>>
+where
+    T: Clone + 'static,
+    I: Coordinate,
+{
+    let pieces: Vec<&&dyn Matrix<'a, T, I>> = matrices.iter().filter(|m| m.addresses().next().is_some()).collect();
+    if let Some(other) = pieces.iter().find(|m| m.column_count() != pieces[0].column_count()) {
+        return Err(Error::new(format!(
+            "cannot vstack matrices with {} and {} columns",
+            pieces[0].column_count(), other.column_count()
+        )));
+    }
+    let data: Vec<T> = pieces.iter().flat_map(|m| m.iter().cloned()).collect();
+    let rows = pieces.iter().map(|m| m.row_count().try_into().unwrap_or(0)).sum::<usize>();
+    let rows: I = match rows.try_into() {
+        Ok(v) => v,
+        Err(_) => return Err(Error::new("stacked row count overflows index type".to_string())),
+    };
+    new_matrix(rows, data)
+}
+
+/// hstack concatenates matrices horizontally, the columns of each following
+/// those of the one before.  Every non-empty matrix must have the same number
+/// of rows; empty matrices are skipped.
+pub fn hstack<'a, T, I>(matrices: &[&'a dyn Matrix<'a, T, I>]) -> crate::error::Result<DenseMatrix<T, I>>
+where
+    T: Clone + 'static,
+    I: Coordinate,
+{
+    let pieces: Vec<&&dyn Matrix<'a, T, I>> = matrices.iter().filter(|m| m.addresses().next().is_some()).collect();
+    let rows = match pieces.first() {
+        Some(first) => first.row_count(),
+        None => return new_matrix(I::default(), Vec::new()),
+    };
+    if let Some(other) = pieces.iter().find(|m| m.row_count() != rows) {
+        return Err(Error::new(format!(
+            "cannot hstack matrices with {} and {} rows",
+            rows, other.row_count()
+        )));
+    }
+    let mut data = Vec::new();
+    let mut row = I::default();
+    while row < rows {
+        for piece in pieces.iter() {
+            data.extend(piece.row(row).into_iter().flat_map(|r| r.iter().cloned()));
+        }
+        row = row + I::unit();
+    }
+    new_matrix(rows, data)
+}
+
+/// new_matrix_of creates a matrix of the given shape from a vector of values in
+/// row-major order.  The length of data must equal rows * columns.
+pub fn new_matrix_of<T, I>(shape: Shape<I>, data: Vec<T>) -> crate::error::Result<DenseMatrix<T, I>>
+where
+    I: Coordinate,
+{
+    let len = match shape.rows.checked_multiply(shape.columns) {
+        Some(v) => v,
+        None => return Err(Error::new("matrix dimensions exceed chosen index size".to_string())),
+    };
+    if data.len() != len {
+        return Err(Error::new(format!("data length {} does not match shape {}", data.len(), shape)));
+    }
+    new_matrix(shape.rows, data)
+}
+
+/// new_distance_matrix creates the square matrix of metric applied to every
+/// pair of items, so cell (i, j) holds metric(&items[i], &items[j]).  The
+/// metric is assumed symmetric: it is called once per unordered pair and the
+/// result mirrored across the diagonal.
+pub fn new_distance_matrix<P, T, I>(items: &[P], metric: impl Fn(&P, &P) -> T) -> crate::error::Result<DenseMatrix<T, I>>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    let n = items.len();
+    let size: I = match n.try_into() {
+        Ok(v) => v,
+        Err(_) => return Err(Error::new("item count overflows index type".to_string())),
+    };
+    let mut cells: Vec<Option<T>> = (0..n * n).map(|_| None).collect();
+    for i in 0..n {
+        for j in i..n {
+            let distance = metric(&items[i], &items[j]);
+            cells[j * n + i] = Some(distance.clone());
+            cells[i * n + j] = Some(distance);
+        }
+    }
+    new_matrix_of(Shape { rows: size, columns: size }, cells.into_iter().flatten().collect())
+}
+
+/// new_default_matrix_of creates a matrix of the given shape where all cells
+/// contain T::default() (typically a zero value).
+pub fn new_default_matrix_of<T, I>(shape: Shape<I>) -> crate::error::Result<DenseMatrix<T, I>>
 where
     T: Default,
     I: Coordinate,
 {
-    let len = match rows.checked_multiply(columns) {
+    let len = match shape.rows.checked_multiply(shape.columns) {
         Some(v) => v,
         None => return Err(Error::new("matrix dimensions exceed chosen index size".to_string())),
     };
@@ -61,5 +323,463 @@ where
     for _ in 0..len {
         data.push(T::default());
     }
-    new_matrix(rows, data)
+    new_matrix(shape.rows, data)
+}
+
+/// new_default_matrix creates a matrix of type T where all cells contain T::default()
+/// (typically a zero value).  Unlike the other factories it takes columns first.
+#[deprecated(note = "use new_default_matrix_of, which names rows and columns")]
+pub fn new_default_matrix<T, I>(columns: I, rows: I) -> crate::error::Result<DenseMatrix<T, I>>
+where
+    T: Default,
+    I: Coordinate,
+{
+    new_default_matrix_of(Shape { rows, columns })
+}
+
+/// new_growable_grid creates an empty GrowableGrid.  Cells that have never been
+/// written read as fill.
+pub fn new_growable_grid<T, I>(fill: T) -> GrowableGrid<T, I>
+where
+    I: Coordinate,
+{
+    GrowableGrid {
+        matrix: DenseMatrix::new(I::default(), I::default(), Vec::new()),
+        origin: MatrixAddress::default(),
+        fill,
+    }
+}
+
+/// new_growable_grid_from creates a GrowableGrid whose initial contents are matrix,
+/// with the upper left cell of matrix at logical address (0, 0).
+pub fn new_growable_grid_from<T, I>(matrix: DenseMatrix<T, I>, fill: T) -> GrowableGrid<T, I>
+where
+    I: Coordinate,
+{
+    GrowableGrid {
+        matrix,
+        origin: MatrixAddress::default(),
+        fill,
+    }
+}
+
+/// new_attributed_matrix pairs values with attributes, which must have the
+/// same shape.
+pub fn new_attributed_matrix<T, A, I>(values: DenseMatrix<T, I>, attributes: DenseMatrix<A, I>) -> crate::error::Result<AttributedMatrix<T, A, I>>
+where
+    T: 'static,
+    A: 'static,
+    I: Coordinate,
+{
+    assert_same_shape(&values, &attributes)?;
+    Ok(AttributedMatrix { values, attributes })
+}
+
+/// new_attributed_matrix_default pairs values with a matrix of A::default()
+/// attributes of the same shape.
+pub fn new_attributed_matrix_default<T, A, I>(values: DenseMatrix<T, I>) -> crate::error::Result<AttributedMatrix<T, A, I>>
+where
+    T: 'static,
+    A: Default + 'static,
+    I: Coordinate,
+{
+    let attributes = new_default_matrix_of(values.shape())?;
+    Ok(AttributedMatrix { values, attributes })
+}
+
+/// new_labeled_matrix attaches a label to each row and column of matrix.  There
+/// must be exactly one label per row and one per column.
+pub fn new_labeled_matrix<T, I, L>(matrix: DenseMatrix<T, I>, row_labels: Vec<L>, column_labels: Vec<L>) -> crate::error::Result<LabeledMatrix<T, I, L>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let (rows, columns): (usize, usize) = match (matrix.row_count().try_into(), matrix.column_count().try_into()) {
+        (Ok(r), Ok(c)) => (r, c),
+        _ => return Err(Error::new("matrix dimensions cannot be coerced to usize".to_string())),
+    };
+    if row_labels.len() != rows || column_labels.len() != columns {
+        return Err(Error::new(format!(
+            "expected {} row and {} column labels, got {} and {}",
+            rows, columns, row_labels.len(), column_labels.len()
+        )));
+    }
+    Ok(LabeledMatrix { matrix, row_labels, column_labels })
+}
+
+/// new_matrix_history creates an empty MatrixHistory that buffers the most
+/// recent capacity generations.  A capacity of 0 keeps only fingerprints.
+pub fn new_matrix_history<T, I>(capacity: usize) -> MatrixHistory<T, I>
+where
+    I: Coordinate,
+{
+    MatrixHistory {
+        capacity,
+        recent: std::collections::VecDeque::with_capacity(capacity),
+        seen: std::collections::HashMap::new(),
+        generations: 0,
+        first_repeat: None,
+    }
+}
+
+/// new_sparse_matrix creates a rows x columns SparseMatrix in which every cell
+/// reads as default until set.  A matrix with zero rows or zero columns has no
+/// cells, and is normalized to zero rows and zero columns.
+pub fn new_sparse_matrix<T, I>(rows: I, columns: I, default: T) -> crate::error::Result<SparseMatrix<T, I>>
+where
+    I: Coordinate,
+{
+    let zero = I::default();
+    if rows < zero || columns < zero {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let (rows, columns) = if rows == zero || columns == zero {
+        (zero, zero)
+    } else {
+        (rows, columns)
+    };
+    Ok(SparseMatrix {
+        columns,
+        rows,
+        default,
+        cells: std::collections::HashMap::new(),
+    })
+}
+
+/// new_sparse_matrix_of creates a SparseMatrix of the given shape in which every
+/// cell reads as default until set.
+pub fn new_sparse_matrix_of<T, I>(shape: Shape<I>, default: T) -> crate::error::Result<SparseMatrix<T, I>>
+where
+    I: Coordinate,
+{
+    new_sparse_matrix(shape.rows, shape.columns, default)
+}
+
+/// new_fn_matrix creates a rows x columns FnMatrix whose cells are computed by f.
+/// A matrix with zero rows or zero columns has no cells, and is normalized to
+/// zero rows and zero columns.
+pub fn new_fn_matrix<T, I, F>(rows: I, columns: I, f: F) -> crate::error::Result<FnMatrix<T, I, F>>
+where
+    I: Coordinate,
+    F: Fn(MatrixAddress<I>) -> T,
+{
+    let zero = I::default();
+    if rows < zero || columns < zero {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let (rows, columns) = if rows == zero || columns == zero {
+        (zero, zero)
+    } else {
+        (rows, columns)
+    };
+    if rows.checked_multiply(columns).is_none() {
+        return Err(Error::new("matrix dimensions exceed chosen index size".to_string()));
+    }
+    Ok(FnMatrix {
+        columns,
+        rows,
+        f,
+        cache: CellCache::new(),
+    })
+}
+
+/// new_fn_matrix_of creates an FnMatrix of the given shape whose cells are
+/// computed by f.
+pub fn new_fn_matrix_of<T, I, F>(shape: Shape<I>, f: F) -> crate::error::Result<FnMatrix<T, I, F>>
+where
+    I: Coordinate,
+    F: Fn(MatrixAddress<I>) -> T,
+{
+    new_fn_matrix(shape.rows, shape.columns, f)
+}
+
+/// new_memoized_matrix creates a read-only FnMatrix over underlay, which copies
+/// each cell of underlay into its cache the first time it is read through the
+/// Matrix or Tensor traits, and serves later reads from the cache.  Wrap views
+/// whose cells are expensive to reach, such as deep overlays or chains of
+/// transforms, before running searches that read the same cells many times.
+/// The underlying matrix must not change while the view exists.
+pub fn new_memoized_matrix<'a, T, I>(underlay: &'a dyn Matrix<'a, T, I>) -> FnMatrix<T, I, impl Fn(MatrixAddress<I>) -> T + 'a>
+where
+    T: Clone + 'static,
+    I: Coordinate,
+{
+    FnMatrix {
+        columns: underlay.column_count(),
+        rows: underlay.row_count(),
+        f: move |address| underlay[address].clone(),
+        cache: CellCache::new(),
+    }
+}
+
+/// new_grid_walker creates a GridWalker on matrix at start, facing facing.
+/// start must be within the matrix.
+pub fn new_grid_walker<'a, T, I>(matrix: &'a dyn Matrix<'a, T, I>, start: MatrixAddress<I>, facing: Direction) -> crate::error::Result<GridWalker<'a, T, I>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    if !matrix.contains(start) {
+        return Err(Error::new(format!("address {} is out of bounds", start)));
+    }
+    Ok(GridWalker {
+        matrix,
+        position: start,
+        facing,
+    })
+}
+
+/// new_bit_matrix creates a rows x columns BitMatrix with every cell false.
+/// A matrix with zero rows or zero columns has no cells, and is normalized to
+/// zero rows and zero columns.
+pub fn new_bit_matrix<I>(rows: I, columns: I) -> crate::error::Result<BitMatrix<I>>
+where
+    I: Coordinate,
+{
+    let zero = I::default();
+    if rows < zero || columns < zero {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    if rows == zero || columns == zero {
+        return Ok(BitMatrix { columns: zero, rows: zero, words_per_row: 0, words: Vec::new() });
+    }
+    let (row_usize, column_usize): (usize, usize) = match (rows.try_into(), columns.try_into()) {
+        (Ok(r), Ok(c)) => (r, c),
+        _ => return Err(Error::new("matrix dimensions cannot be coerced to usize".to_string())),
+    };
+    let words_per_row = column_usize.div_ceil(WORD_BITS);
+    let len = match row_usize.checked_mul(words_per_row) {
+        Some(v) => v,
+        None => return Err(Error::new("matrix dimensions exceed usize".to_string())),
+    };
+    Ok(BitMatrix { columns, rows, words_per_row, words: vec![0; len] })
+}
+
+/// new_bit_matrix_of creates a BitMatrix of the given shape with every cell false.
+pub fn new_bit_matrix_of<I>(shape: Shape<I>) -> crate::error::Result<BitMatrix<I>>
+where
+    I: Coordinate,
+{
+    new_bit_matrix(shape.rows, shape.columns)
+}
+
+/// new_bit_matrix_from packs a boolean matrix into a BitMatrix.
+pub fn new_bit_matrix_from<'a, I>(matrix: &'a dyn Matrix<'a, bool, I>) -> crate::error::Result<BitMatrix<I>>
+where
+    I: Coordinate,
+{
+    let mut bits = new_bit_matrix(matrix.row_count(), matrix.column_count())?;
+    for (address, value) in matrix.indexed_iter() {
+        if *value {
+            bits.set(address, true);
+        }
+    }
+    Ok(bits)
+}
+
+/// new_cube creates an x_size by y_size by z_size cube from values stored layer
+/// by layer, each layer in row-major order.  A cube with any zero dimension has
+/// no cells, and is normalized to zero in every dimension.
+pub fn new_cube<T, I>(x_size: I, y_size: I, z_size: I, data: Vec<T>) -> crate::error::Result<Cube<T, I>>
+where
+    I: Coordinate,
+{
+    let zero = I::default();
+    if x_size < zero || y_size < zero || z_size < zero {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let (x_size, y_size, z_size) = if x_size == zero || y_size == zero || z_size == zero {
+        (zero, zero, zero)
+    } else {
+        (x_size, y_size, z_size)
+    };
+    let len = match (x_size.checked_multiply(y_size), z_size.try_into()) {
+        (Some(layer), Ok(layers)) => {
+            let layers: usize = layers;
+            layer.checked_mul(layers)
+        },
+        _ => None,
+    };
+    let len = match len {
+        Some(v) => v,
+        None => return Err(Error::new("cube dimensions exceed chosen index size".to_string())),
+    };
+    if data.len() != len {
+        return Err(Error::new(format!("data length {} does not match cube dimensions ({})", data.len(), len)));
+    }
+    Ok(Cube { x_size, y_size, z_size, data })
+}
+
+/// new_cube_of creates a cube from values stored layer by layer, as new_cube
+/// does, taking its size in each dimension from the named fields of shape.
+pub fn new_cube_of<T, I>(shape: CubeAddress<I>, data: Vec<T>) -> crate::error::Result<Cube<T, I>>
+where
+    I: Coordinate,
+{
+    new_cube(shape.x, shape.y, shape.z, data)
+}
+
+/// new_default_cube creates a cube of type T where all cells contain T::default().
+pub fn new_default_cube<T, I>(x_size: I, y_size: I, z_size: I) -> crate::error::Result<Cube<T, I>>
+where
+    T: Default,
+    I: Coordinate,
+{
+    let zero = I::default();
+    if x_size < zero || y_size < zero || z_size < zero {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let len = match (x_size.checked_multiply(y_size), z_size.try_into()) {
+        (Some(layer), Ok(layers)) => {
+            let layers: usize = layers;
+            layer.checked_mul(layers)
+        },
+        _ => None,
+    };
+    let mut data: Vec<T> = Vec::new();
+    match len {
+        Some(v) => data.resize_with(v, T::default),
+        None => return Err(Error::new("cube dimensions exceed chosen index size".to_string())),
+    }
+    new_cube(x_size, y_size, z_size, data)
+}
+
+/// new_default_cube_of creates a cube of type T where all cells contain
+/// T::default(), taking its size in each dimension from the named fields of
+/// shape.
+pub fn new_default_cube_of<T, I>(shape: CubeAddress<I>) -> crate::error::Result<Cube<T, I>>
+where
+    T: Default,
+    I: Coordinate,
+{
+    new_default_cube(shape.x, shape.y, shape.z)
+}
+
+/// new_hypercube creates a hypercube from values in address order, with x
+/// varying fastest and w slowest.  A hypercube with any zero dimension has no
+/// cells, and is normalized to zero in every dimension.
+pub fn new_hypercube<T, I>(x_size: I, y_size: I, z_size: I, w_size: I, data: Vec<T>) -> crate::error::Result<HyperCube<T, I>>
+where
+    I: Coordinate,
+{
+    let zero = I::default();
+    if x_size < zero || y_size < zero || z_size < zero || w_size < zero {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let (x_size, y_size, z_size, w_size) = if x_size == zero || y_size == zero || z_size == zero || w_size == zero {
+        (zero, zero, zero, zero)
+    } else {
+        (x_size, y_size, z_size, w_size)
+    };
+    let len = match (x_size.checked_multiply(y_size), z_size.checked_multiply(w_size)) {
+        (Some(a), Some(b)) => a.checked_mul(b),
+        _ => None,
+    };
+    let len = match len {
+        Some(v) => v,
+        None => return Err(Error::new("hypercube dimensions exceed chosen index size".to_string())),
+    };
+    if data.len() != len {
+        return Err(Error::new(format!("data length {} does not match hypercube dimensions ({})", data.len(), len)));
+    }
+    Ok(HyperCube { x_size, y_size, z_size, w_size, data })
+}
+
+/// new_hypercube_of creates a hypercube from values in address order, as
+/// new_hypercube does, taking its size in each dimension from the named fields
+/// of shape.
+pub fn new_hypercube_of<T, I>(shape: HyperCubeAddress<I>, data: Vec<T>) -> crate::error::Result<HyperCube<T, I>>
+where
+    I: Coordinate,
+{
+    new_hypercube(shape.x, shape.y, shape.z, shape.w, data)
+}
+
+/// new_default_hypercube creates a hypercube of type T where all cells contain
+/// T::default().  A hypercube with any zero dimension has no cells, and is
+/// normalized to zero in every dimension.
+pub fn new_default_hypercube<T, I>(x_size: I, y_size: I, z_size: I, w_size: I) -> crate::error::Result<HyperCube<T, I>>
+where
+    T: Default,
+    I: Coordinate,
+{
+    let zero = I::default();
+    if x_size < zero || y_size < zero || z_size < zero || w_size < zero {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let len = match (x_size.checked_multiply(y_size), z_size.checked_multiply(w_size)) {
+        (Some(a), Some(b)) => a.checked_mul(b),
+        _ => None,
+    };
+    let mut data: Vec<T> = Vec::new();
+    match len {
+        Some(v) => data.resize_with(v, T::default),
+        None => return Err(Error::new("hypercube dimensions exceed chosen index size".to_string())),
+    }
+    new_hypercube(x_size, y_size, z_size, w_size, data)
+}
+
+/// new_default_hypercube_of creates a hypercube of type T where all cells
+/// contain T::default(), taking its size in each dimension from the named
+/// fields of shape.
+pub fn new_default_hypercube_of<T, I>(shape: HyperCubeAddress<I>) -> crate::error::Result<HyperCube<T, I>>
+where
+    T: Default,
+    I: Coordinate,
+{
+    new_default_hypercube(shape.x, shape.y, shape.z, shape.w)
+}
+
+/// new_nd_tensor creates a tensor of the given shape from values in address
+/// order, with dimension 0 varying fastest.  A tensor with any zero dimension
+/// has no cells, and is normalized to zero in every dimension.
+pub fn new_nd_tensor<T, I, const D: usize>(shape: [I; D], data: Vec<T>) -> crate::error::Result<NdTensor<T, I, D>>
+where
+    I: Coordinate,
+{
+    let zero = I::default();
+    if shape.iter().any(|d| *d < zero) {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let shape = if shape.contains(&zero) { [zero; D] } else { shape };
+    let len = nd_cell_count(&shape);
+    let len = match len {
+        Some(v) => v,
+        None => return Err(Error::new("tensor dimensions exceed chosen index size".to_string())),
+    };
+    if data.len() != len {
+        return Err(Error::new(format!("data length {} does not match tensor shape ({})", data.len(), len)));
+    }
+    Ok(NdTensor { shape, data })
+}
+
+/// new_default_nd_tensor creates a tensor of type T where all cells contain T::default().
+pub fn new_default_nd_tensor<T, I, const D: usize>(shape: [I; D]) -> crate::error::Result<NdTensor<T, I, D>>
+where
+    T: Default,
+    I: Coordinate,
+{
+    let zero = I::default();
+    if shape.iter().any(|d| *d < zero) {
+        return Err(Error::new("negative dimensions not supported".to_string()));
+    }
+    let len = nd_cell_count(&shape);
+    let mut data: Vec<T> = Vec::new();
+    match len {
+        Some(v) => data.resize_with(v, T::default),
+        None => return Err(Error::new("tensor dimensions exceed chosen index size".to_string())),
+    }
+    new_nd_tensor(shape, data)
+}
+
+/// nd_cell_count returns the number of cells in a tensor of the given shape, or
+/// None if that overflows usize.
+fn nd_cell_count<I, const D: usize>(shape: &[I; D]) -> Option<usize>
+where
+    I: Coordinate,
+{
+    shape.iter().try_fold(if D == 0 { 0 } else { 1 }, |acc: usize, d| {
+        let extent: usize = (*d).try_into().ok()?;
+        acc.checked_mul(extent)
+    })
 }
@@ -0,0 +1,143 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::collections::{HashSet, VecDeque};
+use crate::{Coordinate, MatrixAddress, Tensor};
+use crate::dense_matrix::DenseMatrix;
+use crate::iter::MatrixForwardIterator;
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    /// flood_fill_set repaints the orthogonally connected region containing seed
+    /// with new_value, and returns the number of cells repainted.  A cell belongs
+    /// to the region when same_region(seed's original value, cell value) is true.
+    /// An out of bounds seed repaints nothing.
+    pub fn flood_fill_set(&mut self, seed: MatrixAddress<I>, new_value: T, same_region: impl Fn(&T, &T) -> bool) -> usize {
+        let region = self.region_mask(seed, same_region);
+        let mut changed = 0;
+        for (cell, _) in self.data.iter_mut().zip(region).filter(|(_, inside)| *inside) {
+            *cell = new_value.clone();
+            changed += 1;
+        }
+        changed
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    /// flood_fill returns the addresses of the orthogonally connected region
+    /// containing seed.  A cell belongs to the region when same_region(seed's
+    /// value, cell value) is true.  An out of bounds seed yields an empty set.
+    pub fn flood_fill(&self, seed: MatrixAddress<I>, same_region: impl Fn(&T, &T) -> bool) -> HashSet<MatrixAddress<I>> {
+        MatrixForwardIterator::new(MatrixAddress { row: self.rows, column: self.columns })
+            .zip(self.region_mask(seed, same_region))
+            .filter_map(|(addr, inside)| if inside { Some(addr) } else { None })
+            .collect()
+    }
+
+    /// region_mask returns a flag per cell, in storage order, that is true for
+    /// the cells of the region flood_fill describes.
+    fn region_mask(&self, seed: MatrixAddress<I>, same_region: impl Fn(&T, &T) -> bool) -> Vec<bool> {
+        let mut visited = vec![false; self.data.len()];
+        let original = match self.get(seed) {
+            Some(v) => v,
+            None => return visited,
+        };
+        visited[self.index_address(seed)] = true;
+        let mut queue = VecDeque::from([seed]);
+        while let Some(addr) = queue.pop_front() {
+            for neighbor in self.orthogonal_neighbors(addr) {
+                let index = self.index_address(neighbor);
+                if !visited[index] && same_region(original, &self.data[index]) {
+                    visited[index] = true;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        visited
+    }
+
+    /// orthogonal_neighbors returns the in-bounds addresses above, below, left
+    /// and right of address.
+    fn orthogonal_neighbors(&self, address: MatrixAddress<I>) -> Vec<MatrixAddress<I>> {
+        let ione = I::unit();
+        let izero = I::default();
+        let mut neighbors = Vec::with_capacity(4);
+        if address.row > izero {
+            neighbors.push(MatrixAddress { row: address.row - ione, column: address.column });
+        }
+        if address.column > izero {
+            neighbors.push(MatrixAddress { row: address.row, column: address.column - ione });
+        }
+        if address.column + ione < self.columns {
+            neighbors.push(MatrixAddress { row: address.row, column: address.column + ione });
+        }
+        if address.row + ione < self.rows {
+            neighbors.push(MatrixAddress { row: address.row + ione, column: address.column });
+        }
+        neighbors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    fn parse(text: &str) -> DenseMatrix<char, u8> {
+        FormatOptions::default()
+            .parse_matrix(text, |x| x.chars().next().unwrap())
+            .unwrap()
+    }
+
+    fn render(m: &DenseMatrix<char, u8>) -> String {
+        FormatOptions::default().format(m, |c| c.to_string())
+    }
+
+    #[test]
+    fn flood_fill_collects_region() {
+        let m = parse("..#..\n..#..\n###..\n.....");
+        let region = m.flood_fill(u8addr(0, 4), |a, b| a == b);
+        assert_eq!(region.len(), 11);
+        assert!(region.contains(&u8addr(3, 0)));
+        assert!(!region.contains(&u8addr(0, 0)));
+        assert!(m.flood_fill(u8addr(9, 9), |a, b| a == b).is_empty());
+    }
+
+    #[test]
+    fn flood_fill_set_repaints_region() {
+        let mut m = parse("..#..\n..#..\n###..\n.....");
+        let changed = m.flood_fill_set(u8addr(0, 0), '~', |a, b| a == b);
+        assert_eq!(changed, 4);
+        assert_eq!(render(&m), "~~#..\n~~#..\n###..\n.....");
+    }
+
+    #[test]
+    fn flood_fill_set_is_orthogonal() {
+        let mut m = parse(".#\n#.");
+        assert_eq!(m.flood_fill_set(u8addr(0, 0), 'x', |a, b| a == b), 1);
+        assert_eq!(render(&m), "x#\n#.");
+    }
+
+    #[test]
+    fn flood_fill_set_terminates_when_new_value_is_in_region() {
+        let mut m = parse("aa\nab");
+        assert_eq!(m.flood_fill_set(u8addr(0, 0), 'a', |a, b| a == b), 3);
+        assert_eq!(render(&m), "aa\nab");
+    }
+
+    #[test]
+    fn flood_fill_set_out_of_bounds_seed() {
+        let mut m = parse("aa\nab");
+        assert_eq!(m.flood_fill_set(u8addr(2, 0), 'z', |a, b| a == b), 0);
+        assert_eq!(render(&m), "aa\nab");
+    }
+}
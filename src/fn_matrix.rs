@@ -0,0 +1,309 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::cell::OnceCell;
+use std::ops::{Index, Range};
+use crate::{Coordinate, Matrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixRowsIterator, MatrixValueIterator, Tensor};
+use crate::column::Column;
+use crate::row::Row;
+
+/// FnMatrix is a read-only matrix with fixed bounds whose cells are computed
+/// from their addresses by a function, so procedurally defined grids can be
+/// searched and iterated without being materialized up front.
+///
+/// value computes a cell afresh on every call.  Access through the Matrix
+/// and Tensor traits hands out references, so those paths compute each cell
+/// at most once and keep it.  Storage for kept cells is allocated a chunk of
+/// one row at a time as cells are first read, so reading a few cells of a
+/// huge matrix does not allocate the whole grid.
+/// new_memoized_matrix uses this to cache reads of another matrix.
+/// Computed cells cannot be modified, so get_mut returns None and the matrix
+/// does not implement MatrixMut.
+pub struct FnMatrix<T, I, F>
+where
+    I: Coordinate,
+    F: Fn(MatrixAddress<I>) -> T,
+{
+    pub(crate) columns: I,
+    pub(crate) rows: I,
+    pub(crate) f: F,
+    pub(crate) cache: CellCache<T>,
+}
+
+/// CHUNK is the number of adjacent cells of a row whose storage CellCache
+/// allocates together.
+const CHUNK: usize = 64;
+
+/// CellCache keeps the cells of an FnMatrix that have been read through the
+/// Matrix or Tensor traits.  A table of rows is allocated on first read, each
+/// row's table of chunks on first read of that row, and each chunk of CHUNK
+/// cells on first read of one of its cells.
+pub(crate) struct CellCache<T> {
+    rows: OnceCell<Box<[OnceCell<CachedRow<T>>]>>,
+}
+
+type CachedChunk<T> = Box<[OnceCell<T>]>;
+type CachedRow<T> = Box<[OnceCell<CachedChunk<T>>]>;
+
+impl <T> CellCache<T> {
+    pub(crate) fn new() -> Self {
+        CellCache { rows: OnceCell::new() }
+    }
+
+    /// slot returns the cell at (row, column), allocating whatever storage
+    /// leads to it.  row and column must be within rows and columns.
+    fn slot(&self, rows: usize, columns: usize, row: usize, column: usize) -> &OnceCell<T> {
+        let table = self.rows.get_or_init(|| (0..rows).map(|_| OnceCell::new()).collect());
+        let chunks = table[row].get_or_init(|| (0..columns.div_ceil(CHUNK)).map(|_| OnceCell::new()).collect());
+        let chunk = chunks[column / CHUNK].get_or_init(|| {
+            let width = CHUNK.min(columns - column / CHUNK * CHUNK);
+            (0..width).map(|_| OnceCell::new()).collect()
+        });
+        &chunk[column % CHUNK]
+    }
+
+    /// peek returns the kept value at (row, column), if any, without
+    /// allocating.
+    fn peek(&self, row: usize, column: usize) -> Option<&T> {
+        self.rows.get()?[row].get()?[column / CHUNK].get()?[column % CHUNK].get()
+    }
+
+    fn clear(&mut self) {
+        self.rows.take();
+    }
+}
+
+impl <T, I, F> FnMatrix<T, I, F>
+where
+    I: Coordinate,
+    F: Fn(MatrixAddress<I>) -> T,
+{
+    /// value computes the cell at address without caching it.  None is
+    /// returned for out of bounds addresses.
+    pub fn value(&self, address: MatrixAddress<I>) -> Option<T> {
+        if self.contains(address) {
+            Some((self.f)(address))
+        } else {
+            None
+        }
+    }
+
+    /// is_cached reports whether the cell at address has been read through the
+    /// Matrix or Tensor traits already.
+    pub fn is_cached(&self, address: MatrixAddress<I>) -> bool {
+        self.contains(address) && self.cache.peek(to_usize(address.row), to_usize(address.column)).is_some()
+    }
+
+    /// clear discards every cached value, so cells are computed again.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+
+    /// cached_cell returns the cache slot of an in-bounds address.
+    fn cached_cell(&self, address: MatrixAddress<I>) -> &OnceCell<T> {
+        self.cache.slot(to_usize(self.rows), to_usize(self.columns), to_usize(address.row), to_usize(address.column))
+    }
+}
+
+fn to_usize<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("address overflows usize.  This should be unreachable."),
+    }
+}
+
+impl <'a, T, I, F> Matrix<'a, T, I> for FnMatrix<T, I, F>
+where
+    T: 'static,
+    I: Coordinate,
+    F: Fn(MatrixAddress<I>) -> T + 'a,
+{
+    fn row_count(&self) -> I {
+        self.rows
+    }
+
+    fn column_count(&self) -> I {
+        self.columns
+    }
+
+    fn iter(&'a self) -> MatrixValueIterator<'a, T, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        MatrixForwardIterator::new(self.range().end)
+    }
+
+    fn indexed_iter(&'a self) -> MatrixForwardIndexedIterator<'a, T, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'a self, row_num: I) -> Option<Row<'a, T, I>> {
+        if row_num < I::default() || row_num >= self.rows {
+            None
+        } else {
+            Some(Row::new(self, row_num))
+        }
+    }
+
+    fn column(&'a self, column_num: I) -> Option<Column<'a, T, I>> {
+        if column_num < I::default() || column_num >= self.columns {
+            None
+        } else {
+            Some(Column::new(self, column_num))
+        }
+    }
+
+    fn rows(&'a self) -> MatrixRowsIterator<'a, T, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'a self) -> MatrixColumnsIterator<'a, T, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+impl <T, I, F> Tensor<T, I, MatrixAddress<I>, 2> for FnMatrix<T, I, F>
+where
+    I: Coordinate,
+    F: Fn(MatrixAddress<I>) -> T,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        Range {
+            start: MatrixAddress::default(),
+            end: MatrixAddress {
+                column: self.columns,
+                row: self.rows,
+            },
+        }
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        if !self.contains(address) {
+            return None;
+        }
+        Some(self.cached_cell(address).get_or_init(|| (self.f)(address)))
+    }
+
+    /// get_mut always returns None, as computed cells are read-only.
+    fn get_mut(&mut self, _address: MatrixAddress<I>) -> Option<&mut T> {
+        None
+    }
+}
+
+impl<T, I, F> Index<MatrixAddress<I>> for FnMatrix<T, I, F>
+where
+    I: Coordinate,
+    F: Fn(MatrixAddress<I>) -> T,
+{
+    type Output = T;
+
+    fn index(&self, index: MatrixAddress<I>) -> &Self::Output {
+        match self.get(index) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use crate::error::Error;
+    use crate::factories::{new_fn_matrix, new_fn_matrix_of, new_memoized_matrix, new_rotated_cw};
+    use crate::format::FormatOptions;
+    use crate::shape::Shape;
+    use super::*;
+
+    fn u16addr(row: u16, column: u16) -> MatrixAddress<u16> {
+        MatrixAddress { row, column }
+    }
+
+    #[test]
+    fn cells_computed_on_demand() {
+        let calls = Cell::new(0);
+        let m = new_fn_matrix(2u16, 3u16, |a: MatrixAddress<u16>| {
+            calls.set(calls.get() + 1);
+            a.row * 10 + a.column
+        }).unwrap();
+        assert_eq!(m.value(u16addr(1, 2)), Some(12));
+        assert_eq!(m.value(u16addr(1, 2)), Some(12));
+        assert_eq!(calls.get(), 2);
+        assert!(m.cache.rows.get().is_none());
+        assert_eq!(m[u16addr(1, 1)], 11);
+        assert_eq!(m.get(u16addr(1, 1)), Some(&11));
+        assert_eq!(calls.get(), 3);
+        assert_eq!(m.get(u16addr(2, 0)), None);
+        assert_eq!(FormatOptions::default().format(&m, |v| format!("{:2}", v)), " 0 1 2\n101112");
+        assert_eq!(calls.get(), 8);
+        assert_eq!(u16addr(0, 0).neighbors(&m).len(), 3);
+    }
+
+    #[test]
+    fn index_exceeding_coordinate_type() {
+        let m = new_fn_matrix(20u8, 20u8, |a: MatrixAddress<u8>| u32::from(a.row) * 100 + u32::from(a.column)).unwrap();
+        assert_eq!(m.get(MatrixAddress { row: 19, column: 19 }), Some(&1919));
+        assert_eq!(m.iter().last(), Some(&1919));
+    }
+
+    #[test]
+    fn huge_matrix_allocates_only_read_chunks() {
+        let m = new_fn_matrix(100_000u32, 100_000u32, |a: MatrixAddress<u32>| u64::from(a.row) * 100_000 + u64::from(a.column)).unwrap();
+        assert_eq!(m.get(MatrixAddress { row: 99_999, column: 99_999 }), Some(&9_999_999_999));
+        assert!(m.is_cached(MatrixAddress { row: 99_999, column: 99_999 }));
+        assert!(!m.is_cached(MatrixAddress { row: 99_999, column: 0 }));
+        let table = m.cache.rows.get().unwrap();
+        assert_eq!(table.iter().filter(|row| row.get().is_some()).count(), 1);
+        let chunks = table[99_999].get().unwrap();
+        assert_eq!(chunks.iter().filter(|chunk| chunk.get().is_some()).count(), 1);
+        assert_eq!(chunks.last().unwrap().get().unwrap().len(), 100_000 % CHUNK);
+    }
+
+    #[test]
+    fn memoized_reads_are_cached() {
+        let calls = Cell::new(0);
+        let f = new_fn_matrix(2u8, 3u8, |a: MatrixAddress<u8>| {
+            calls.set(calls.get() + 1);
+            a.row * 10 + a.column
+        }).unwrap();
+        let mut m = new_memoized_matrix(&f);
+        let at = |row, column| MatrixAddress::<u8> { row, column };
+        assert!(!m.is_cached(at(1, 2)));
+        assert_eq!(m[at(1, 2)], 12);
+        assert!(m.is_cached(at(1, 2)));
+        assert_eq!(m.get(at(2, 0)), None);
+        assert!(!m.is_cached(at(2, 0)));
+        assert_eq!(FormatOptions::default().format(&m, |v| format!("{:2}", v)), " 0 1 2\n101112");
+        assert_eq!(calls.get(), 6);
+        m.clear();
+        assert!(!m.is_cached(at(1, 2)));
+    }
+
+    #[test]
+    fn memoized_views() {
+        let mut base = FormatOptions::default()
+            .parse_matrix::<char, u8>("ab\ncd", |x| x.chars().next().unwrap())
+            .unwrap();
+        let rotated = new_rotated_cw(&mut base);
+        let m = new_memoized_matrix(&rotated);
+        assert_eq!(FormatOptions::default().format(&m, |c| c.to_string()), "ca\ndb");
+        assert!(m.is_cached(MatrixAddress { row: 1, column: 1 }));
+    }
+
+    #[test]
+    fn dimension_validation() {
+        assert_eq!(
+            new_fn_matrix(-1i8, 2i8, |_| 0u8).err(),
+            Some(Error::new("negative dimensions not supported".to_string()))
+        );
+        let empty = new_fn_matrix(0u8, 5u8, |_| 0u8).unwrap();
+        assert_eq!(empty.column_count(), 0);
+        assert!(empty.addresses().next().is_none());
+    }
+
+    #[test]
+    fn shaped_factory() {
+        let m = new_fn_matrix_of(Shape { rows: 2u8, columns: 3 }, |a| a.row * 10 + a.column).unwrap();
+        assert_eq!(m.shape(), Shape { rows: 2, columns: 3 });
+        assert_eq!(m[MatrixAddress { row: 1, column: 2 }], 12);
+    }
+}
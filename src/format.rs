@@ -1,7 +1,12 @@
 use crate::error::{Error, Result};
 use crate::factories::new_matrix;
-use crate::{Coordinate, Matrix};
+use crate::factories::new_cube;
+use crate::{Coordinate, Matrix, MatrixAddress};
+use crate::cube::Cube;
 use crate::dense_matrix::DenseMatrix;
+use crate::interned_matrix::{InternedMatrix, Symbol};
+use crate::labeled_matrix::LabeledMatrix;
+use crate::shape::Shape;
 
 /// FormatOptions controls the parsing and string formatting of matrices.
 pub struct FormatOptions {
@@ -29,6 +34,34 @@ impl FormatOptions {
     pub fn parse_matrix<T, I>(&self, text_matrix: &str, parse_entry: fn(&str) -> T) -> Result<DenseMatrix<T, I>>
     where
         T: 'static,
+        I: Coordinate {
+        let (rows, entries) = self.split_entries(text_matrix)?;
+        let folded_values: Vec<T> = entries.into_iter()
+            .map(parse_entry)
+            .collect();
+        new_matrix(
+            rows,
+            folded_values)
+    }
+
+    /// parse_interned parses a matrix of text entries, storing each distinct
+    /// entry once in the symbol table of the resulting InternedMatrix.
+    pub fn parse_interned<I>(&self, text_matrix: &str) -> Result<InternedMatrix<I>>
+    where
+        I: Coordinate {
+        let (rows, entries) = self.split_entries(text_matrix)?;
+        let mut interned = InternedMatrix::default();
+        let symbols: Vec<Symbol> = entries.into_iter()
+            .map(|entry| interned.intern(entry))
+            .collect();
+        interned.matrix = new_matrix(rows, symbols)?;
+        Ok(interned)
+    }
+
+    /// split_entries divides text into rows and entries, validating that every
+    /// row has the same number of entries.  Entries are returned in row-major order.
+    fn split_entries<'t, I>(&self, text_matrix: &'t str) -> Result<(I, Vec<&'t str>)>
+    where
         I: Coordinate {
         let values: Vec<Vec<&str>> = text_matrix
             .split(self.row_delimiter.as_str())
@@ -54,13 +87,7 @@ impl FormatOptions {
                 ));
             }
         };
-        let folded_values: Vec<T> = values.into_iter()
-            .flatten()
-            .map(|v| parse_entry(v))
-            .collect();
-        new_matrix(
-            rows,
-            folded_values)
+        Ok((rows, values.into_iter().flatten().collect()))
     }
 
     /// Render a matrix to a string.
@@ -88,13 +115,170 @@ impl FormatOptions {
             })
             .fold("".to_string(), |a: String, b: String| a + &b)
     }
+
+    /// preview renders at most max_rows rows and max_columns columns from the
+    /// upper left corner of matrix, so that huge matrices can be logged safely.
+    /// Omitted columns are marked with a trailing "…" entry and omitted rows
+    /// with a final "…" row, and a footer line gives the full dimensions.
+    pub fn preview<T, I>(&self, matrix: &dyn Matrix<'_, T, I>, max_rows: usize, max_columns: usize, format_element: fn(&T) -> String) -> String
+    where
+        T: 'static,
+        I: Coordinate,
+    {
+        self.render_preview(matrix.shape(), max_rows, max_columns, |addr| match matrix.get(addr) {
+            Some(v) => format_element(v),
+            None => String::new(),
+        })
+    }
+
+    /// render_preview implements preview for a matrix of the given shape whose
+    /// cells are rendered by cell.
+    pub(crate) fn render_preview<I>(&self, shape: Shape<I>, max_rows: usize, max_columns: usize, cell: impl Fn(MatrixAddress<I>) -> String) -> String
+    where
+        I: Coordinate,
+    {
+        let rows: usize = shape.rows.try_into().unwrap_or(usize::MAX);
+        let columns: usize = shape.columns.try_into().unwrap_or(usize::MAX);
+        let mut lines: Vec<String> = Vec::new();
+        for row in 0..rows.min(max_rows) {
+            let mut entries: Vec<String> = Vec::new();
+            for column in 0..columns.min(max_columns) {
+                if let (Ok(row), Ok(column)) = (row.try_into(), column.try_into()) {
+                    entries.push(cell(MatrixAddress { row, column }));
+                }
+            }
+            if columns > max_columns {
+                entries.push("…".to_string());
+            }
+            lines.push(entries.join(self.column_delimiter.as_str()));
+        }
+        if rows > max_rows {
+            lines.push("…".to_string());
+        }
+        lines.push(format!("[{}]", shape));
+        lines.join(self.row_delimiter.as_str())
+    }
+
+    /// parse_cube takes a text representation of a cube, with each layer formatted
+    /// as a matrix and the layers separated by layer_delimiter, and returns a Cube.
+    /// The first layer is z = 0.  Every layer must have the same dimensions.
+    pub fn parse_cube<T, I>(&self, text_cube: &str, layer_delimiter: &str, parse_entry: fn(&str) -> T) -> Result<Cube<T, I>>
+    where
+        T: 'static,
+        I: Coordinate {
+        let mut size: Option<(I, I)> = None;
+        let mut layers = 0usize;
+        let mut data: Vec<T> = Vec::new();
+        for text_layer in text_cube.split(layer_delimiter).filter(|l| !l.trim().is_empty()) {
+            let layer: DenseMatrix<T, I> = self.parse_matrix(text_layer, parse_entry)?;
+            let layer_size = (layer.columns, layer.rows);
+            match size {
+                None => size = Some(layer_size),
+                Some(v) if v != layer_size => {
+                    return Err(Error::new(format!("layer {} dimensions differ from layer 0", layers)));
+                },
+                _ => {},
+            }
+            data.extend(layer);
+            layers += 1;
+        }
+        let (x_size, y_size) = match size {
+            Some(v) => v,
+            None => return Err(Error::new("empty input cannot be parsed".to_string())),
+        };
+        let z_size: I = match layers.try_into() {
+            Ok(v) => v,
+            Err(_) => {
+                return Err(Error::new(
+                    "text input layer count overflows index type".to_string(),
+                ));
+            }
+        };
+        new_cube(x_size, y_size, z_size, data)
+    }
+
+    /// Render an interned matrix to a string, writing each cell's entry text.
+    pub fn format_interned<I>(&self, matrix: &InternedMatrix<I>) -> String
+    where
+        I: Coordinate,
+    {
+        matrix.matrix()
+            .rows_chunks()
+            .map(|row| {
+                row.iter()
+                    .map(|symbol| matrix.resolve(*symbol).unwrap_or_default())
+                    .collect::<Vec<&str>>()
+                    .join(self.column_delimiter.as_str())
+            })
+            .collect::<Vec<String>>()
+            .join(self.row_delimiter.as_str())
+    }
+
+    /// Render a labeled matrix to a string, with a header row of column labels
+    /// and each row prefixed by its label.  Every column, including the label
+    /// column, is right-aligned to its widest entry, so tables line up when
+    /// column_delimiter is a space.
+    pub fn format_labeled<T, I, L>(&self, matrix: &LabeledMatrix<T, I, L>, format_label: fn(&L) -> String, format_element: fn(&T) -> String) -> String
+    where
+        T: 'static,
+        I: Coordinate,
+    {
+        let header = std::iter::once(String::new())
+            .chain(matrix.column_labels().iter().map(format_label));
+        let mut table: Vec<Vec<String>> = vec![header.collect()];
+        for (label, row) in matrix.row_labels().iter().zip(matrix.matrix().rows_chunks()) {
+            table.push(std::iter::once(format_label(label)).chain(row.iter().map(format_element)).collect());
+        }
+        let mut widths = vec![0; table[0].len()];
+        for line in table.iter() {
+            for (width, entry) in widths.iter_mut().zip(line.iter()) {
+                *width = (*width).max(entry.chars().count());
+            }
+        }
+        table.iter()
+            .map(|line| {
+                line.iter()
+                    .zip(widths.iter())
+                    .map(|(entry, width)| format!("{:>width$}", entry, width = width))
+                    .collect::<Vec<String>>()
+                    .join(self.column_delimiter.as_str())
+            })
+            .collect::<Vec<String>>()
+            .join(self.row_delimiter.as_str())
+    }
+
+    /// Render a cube to a string, as a sequence of matrices separated by layer_delimiter.
+    pub fn format_cube<T, I>(&self, cube: &Cube<T, I>, layer_delimiter: &str, format_element: fn(&T) -> String) -> String
+    where
+        I: Coordinate,
+    {
+        let columns: usize = cube.x_size().try_into().unwrap_or_default();
+        let rows: usize = cube.y_size().try_into().unwrap_or_default();
+        if columns == 0 || rows == 0 {
+            return String::new();
+        }
+        let values: Vec<String> = cube.iter().map(format_element).collect();
+        values
+            .chunks(columns * rows)
+            .map(|layer| {
+                layer
+                    .chunks(columns)
+                    .map(|row| row.join(self.column_delimiter.as_str()))
+                    .collect::<Vec<String>>()
+                    .join(self.row_delimiter.as_str())
+            })
+            .collect::<Vec<String>>()
+            .join(layer_delimiter)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::Matrix;
     use crate::format::FormatOptions;
 
     #[test]
+    #[allow(clippy::let_and_return)]
     fn parser_does_not_have_to_outlive_matrix() {
         let _ = {
             let opts = FormatOptions::default();
@@ -103,4 +287,14 @@ mod tests {
             matrix
         };
     }
+
+    #[test]
+    fn preview_truncates() {
+        let opts = FormatOptions::default();
+        let m = opts.parse_matrix::<char, u8>("ABCD\nEFGH\nIJKL", |x| x.chars().next().unwrap()).unwrap();
+        assert_eq!(m.preview(2, 3), "ABC…\nEFG…\n…\n[3x4]");
+        assert_eq!(m.preview(5, 5), "ABCD\nEFGH\nIJKL\n[3x4]");
+        let spaced = FormatOptions { column_delimiter: " ".to_string(), row_delimiter: "\n".to_string() };
+        assert_eq!(spaced.preview(&m, 1, 2, |c| c.to_lowercase().to_string()), "a b …\n…\n[3x4]");
+    }
 }
@@ -0,0 +1,174 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::Coordinate;
+use crate::bit_matrix::{BitMatrix, WORD_BITS};
+use crate::error::{Error, Result};
+
+/// Gf2Elimination is the reduced row echelon form of a BitMatrix over GF(2),
+/// where addition is xor and multiplication is and.  Build one with
+/// BitMatrix::gaussian_eliminate.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Gf2Elimination<I>
+where
+    I: Coordinate,
+{
+    pub(crate) reduced: BitMatrix<I>,
+    pub(crate) pivot_columns: Vec<usize>,
+}
+
+impl <I> Gf2Elimination<I>
+where
+    I: Coordinate,
+{
+    /// reduced returns the reduced row echelon form.  Its first rank rows hold
+    /// the pivots, and the remaining rows are clear.
+    pub fn reduced(&self) -> &BitMatrix<I> {
+        &self.reduced
+    }
+
+    /// rank returns the number of linearly independent rows.
+    pub fn rank(&self) -> usize {
+        self.pivot_columns.len()
+    }
+
+    /// pivot_columns returns the column of the leading one in each of the
+    /// first rank rows.  The other columns are free variables.
+    pub fn pivot_columns(&self) -> &[usize] {
+        &self.pivot_columns
+    }
+}
+
+impl <I> BitMatrix<I>
+where
+    I: Coordinate,
+{
+    /// gaussian_eliminate reduces the matrix to reduced row echelon form over
+    /// GF(2), xoring whole rows of words at a time.
+    pub fn gaussian_eliminate(&self) -> Gf2Elimination<I> {
+        let mut reduced = self.clone();
+        let (rows, columns) = (dimension(self.rows), dimension(self.columns));
+        let pivot_columns = eliminate(&mut reduced.words, self.words_per_row, rows, columns);
+        Gf2Elimination { reduced, pivot_columns }
+    }
+
+    /// solve_gf2 finds x such that self * x = rhs over GF(2), with one entry
+    /// of x per column and one entry of rhs per row.  Lights-out puzzles are
+    /// such systems: column j of self is the set of lights button j toggles.
+    /// When the system has several solutions, the one with every free variable
+    /// false is returned.  Ok(None) means there is no solution.
+    pub fn solve_gf2(&self, rhs: &[bool]) -> Result<Option<Vec<bool>>> {
+        let (rows, columns) = (dimension(self.rows), dimension(self.columns));
+        if rhs.len() != rows {
+            return Err(Error::new(format!(
+                "right-hand side has {} entries for {} rows",
+                rhs.len(), rows
+            )));
+        }
+        // The augmented matrix carries rhs as an extra column.
+        let width = (columns + 1).div_ceil(WORD_BITS);
+        let mut words = vec![0u64; rows * width];
+        for (row, value) in rhs.iter().enumerate() {
+            let augmented = &mut words[row * width..(row + 1) * width];
+            augmented[..self.words_per_row].copy_from_slice(&self.words[row * self.words_per_row..(row + 1) * self.words_per_row]);
+            if *value {
+                augmented[columns / WORD_BITS] |= 1 << (columns % WORD_BITS);
+            }
+        }
+        let pivot_columns = eliminate(&mut words, width, rows, columns + 1);
+        if pivot_columns.last() == Some(&columns) {
+            return Ok(None);
+        }
+        let mut solution = vec![false; columns];
+        for (row, pivot) in pivot_columns.iter().enumerate() {
+            solution[*pivot] = words[row * width + columns / WORD_BITS] >> (columns % WORD_BITS) & 1 == 1;
+        }
+        Ok(Some(solution))
+    }
+}
+
+// eliminate reduces rows of width words each to reduced row echelon form in
+// place, considering the first columns columns, and returns the pivot columns.
+fn eliminate(words: &mut [u64], width: usize, rows: usize, columns: usize) -> Vec<usize> {
+    let mut pivot_columns = Vec::new();
+    let mut pivot_row = vec![0u64; width];
+    for column in 0..columns {
+        let rank = pivot_columns.len();
+        if rank == rows {
+            break;
+        }
+        let (word, bit) = (column / WORD_BITS, 1u64 << (column % WORD_BITS));
+        let found = match (rank..rows).find(|r| words[r * width + word] & bit != 0) {
+            Some(r) => r,
+            None => continue,
+        };
+        for i in 0..width {
+            words.swap(rank * width + i, found * width + i);
+        }
+        pivot_row.copy_from_slice(&words[rank * width..(rank + 1) * width]);
+        for (row, target) in words.chunks_exact_mut(width).enumerate() {
+            if row != rank && target[word] & bit != 0 {
+                target.iter_mut().zip(pivot_row.iter()).for_each(|(t, p)| *t ^= p);
+            }
+        }
+        pivot_columns.push(column);
+    }
+    pivot_columns
+}
+
+fn dimension<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("dimension overflows usize.  This should be unreachable."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MatrixAddress;
+    use crate::factories::new_bit_matrix;
+    use super::*;
+
+    fn from_rows(rows: &[&[bool]]) -> BitMatrix<u8> {
+        let mut m = new_bit_matrix::<u8>(rows.len() as u8, rows[0].len() as u8).unwrap();
+        for (r, row) in rows.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                m.set(MatrixAddress { row: r as u8, column: c as u8 }, *value);
+            }
+        }
+        m
+    }
+
+    #[test]
+    fn eliminate_dependent_rows() {
+        let (o, x) = (false, true);
+        let m = from_rows(&[&[x, x, o], &[o, x, x], &[x, o, x]]);
+        let elimination = m.gaussian_eliminate();
+        assert_eq!(elimination.rank(), 2);
+        assert_eq!(elimination.pivot_columns(), &[0, 1]);
+        assert_eq!(elimination.reduced(), &from_rows(&[&[x, o, x], &[o, x, x], &[o, o, o]]));
+        assert_eq!(m.solve_gf2(&[x, x, o]).unwrap(), Some(vec![o, x, o]));
+        assert_eq!(m.solve_gf2(&[x, o, o]).unwrap(), None);
+        assert!(m.solve_gf2(&[x]).is_err());
+    }
+
+    #[test]
+    fn solve_lights_out() {
+        // Button j of a 3x3 board toggles light j and its orthogonal neighbors.
+        let mut toggles = new_bit_matrix::<u8>(9, 9).unwrap();
+        for j in 0..9u8 {
+            let (r, c) = (j / 3, j % 3);
+            for i in 0..9u8 {
+                let (ir, ic) = (i / 3, i % 3);
+                if r.abs_diff(ir) + c.abs_diff(ic) <= 1 {
+                    toggles.set(MatrixAddress { row: i, column: j }, true);
+                }
+            }
+        }
+        assert_eq!(toggles.gaussian_eliminate().rank(), 9);
+        let presses = toggles.solve_gf2(&[true; 9]).unwrap().unwrap();
+        for i in 0..9u8 {
+            let lit = (0..9u8).filter(|j| presses[*j as usize] && toggles.bit(MatrixAddress { row: i, column: *j }) == Some(true)).count();
+            assert_eq!(lit % 2, 1);
+        }
+    }
+}
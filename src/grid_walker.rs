@@ -0,0 +1,114 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::{Coordinate, Direction, Matrix, MatrixAddress};
+
+/// GridWalker is a cursor on a matrix with a position and a facing, for
+/// puzzles in which a guard or robot patrols a grid.  Every move is checked
+/// against the bounds of the matrix, so the walker can never leave it.
+pub struct GridWalker<'a, T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) matrix: &'a dyn Matrix<'a, T, I>,
+    pub(crate) position: MatrixAddress<I>,
+    pub(crate) facing: Direction,
+}
+
+impl <'a, T, I> GridWalker<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// position returns the address the walker occupies.
+    pub fn position(&self) -> MatrixAddress<I> {
+        self.position
+    }
+
+    /// facing returns the direction the walker faces.
+    pub fn facing(&self) -> Direction {
+        self.facing
+    }
+
+    /// current returns the value of the occupied cell.
+    pub fn current(&self) -> &'a T {
+        &self.matrix[self.position]
+    }
+
+    /// peek returns the address and value of the cell ahead, or None if the
+    /// walker faces the edge of the matrix.
+    pub fn peek(&self) -> Option<(MatrixAddress<I>, &'a T)> {
+        let ahead = self.position.step_checked(self.facing, self.matrix)?;
+        Some((ahead, self.matrix.get(ahead)?))
+    }
+
+    /// forward moves one cell ahead and returns the new position.  At the
+    /// edge of the matrix the walker stays put and None is returned.
+    pub fn forward(&mut self) -> Option<MatrixAddress<I>> {
+        let (ahead, _) = self.peek()?;
+        self.position = ahead;
+        Some(ahead)
+    }
+
+    /// turn_left turns the walker 90 degrees counter-clockwise.
+    pub fn turn_left(&mut self) {
+        self.facing = self.facing.turn_left();
+    }
+
+    /// turn_right turns the walker 90 degrees clockwise.
+    pub fn turn_right(&mut self) {
+        self.facing = self.facing.turn_right();
+    }
+
+    /// face points the walker in direction.
+    pub fn face(&mut self, direction: Direction) {
+        self.facing = direction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use crate::error::Error;
+    use crate::factories::new_grid_walker;
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    #[test]
+    fn guard_patrol() {
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>(".#..\n...#\n.^..\n....", |x| x.chars().next().unwrap())
+            .unwrap();
+        let mut guard = new_grid_walker(&m, u8addr(2, 1), Direction::North).unwrap();
+        assert_eq!(*guard.current(), '^');
+        let mut visited = HashSet::from([guard.position()]);
+        loop {
+            match guard.peek() {
+                None => break,
+                Some((_, '#')) => guard.turn_right(),
+                Some(_) => {
+                    visited.insert(guard.forward().unwrap());
+                },
+            }
+        }
+        assert_eq!(guard.position(), u8addr(3, 2));
+        assert_eq!(guard.facing(), Direction::South);
+        assert_eq!(visited.len(), 5);
+        assert_eq!(guard.forward(), None);
+        guard.turn_left();
+        assert_eq!(guard.facing(), Direction::East);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn start_must_be_in_bounds() {
+        let m = crate::factories::new_default_matrix::<u8, u8>(2, 2).unwrap();
+        assert_eq!(
+            new_grid_walker(&m, u8addr(2, 0), Direction::East).err(),
+            Some(Error::new("address (row=2,col=0) is out of bounds".to_string()))
+        );
+    }
+}
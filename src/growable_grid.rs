@@ -0,0 +1,338 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Index, IndexMut, Range};
+use crate::{CheckedAdd, CheckedSub, Coordinate, Matrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterMut, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixMut, MatrixRowsIterator, MatrixValueIterMut, MatrixValueIterator, Tensor};
+use crate::column::Column;
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+use crate::row::Row;
+
+/// GrowableGrid is a matrix of unknown extent.  Writes are made in logical
+/// coordinates, and a write that lands outside the current bounds grows the
+/// grid to include it, filling the new cells with a fill value.  Logical
+/// coordinates may be negative when I is signed.
+///
+/// The Matrix and Tensor implementations expose the occupied bounding box,
+/// re-based so that its upper left corner is (0, 0).  Use origin() or
+/// to_logical() to recover the logical address of a cell.
+pub struct GrowableGrid<T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) matrix: DenseMatrix<T, I>,
+    pub(crate) origin: MatrixAddress<I>,
+    pub(crate) fill: T,
+}
+
+impl <T, I> GrowableGrid<T, I>
+where
+    I: Coordinate,
+{
+    /// origin returns the logical address of the upper left cell of the bounding box.
+    pub fn origin(&self) -> MatrixAddress<I> {
+        self.origin
+    }
+
+    /// fill returns the value used for cells that have never been written.
+    pub fn fill(&self) -> &T {
+        &self.fill
+    }
+
+    /// to_logical converts a bounding box (Matrix) address to a logical address.
+    pub fn to_logical(&self, address: MatrixAddress<I>) -> MatrixAddress<I> {
+        self.origin + address
+    }
+
+    /// to_local converts a logical address to a bounding box (Matrix) address.
+    /// None is returned if the logical address lies outside the bounding box.
+    pub fn to_local(&self, logical: MatrixAddress<I>) -> Option<MatrixAddress<I>>
+    where
+        I: CheckedSub,
+    {
+        if logical.row < self.origin.row || logical.column < self.origin.column {
+            return None;
+        }
+        let local = MatrixAddress {
+            row: logical.row.checked_subtraction(self.origin.row)?,
+            column: logical.column.checked_subtraction(self.origin.column)?,
+        };
+        if self.matrix.contains(local) {
+            Some(local)
+        } else {
+            None
+        }
+    }
+
+    /// value_at reads a logical address.  Addresses outside the bounding box
+    /// have never been written, so they read as the fill value.
+    pub fn value_at(&self, logical: MatrixAddress<I>) -> &T
+    where
+        I: CheckedSub,
+    {
+        match self.to_local(logical) {
+            Some(local) => &self.matrix[local],
+            None => &self.fill,
+        }
+    }
+
+    /// as_matrix returns the dense storage of the bounding box.
+    pub fn as_matrix(&self) -> &DenseMatrix<T, I> {
+        &self.matrix
+    }
+
+    /// into_matrix consumes the grid, returning the dense storage of the bounding box.
+    pub fn into_matrix(self) -> DenseMatrix<T, I> {
+        self.matrix
+    }
+}
+
+impl <T, I> GrowableGrid<T, I>
+where
+    T: Clone,
+    I: Coordinate + CheckedAdd + CheckedSub,
+{
+    /// set writes a value at a logical address, growing the grid if required.
+    /// An error is returned if the grown bounds cannot be represented by I.
+    pub fn set(&mut self, logical: MatrixAddress<I>, value: T) -> Result<()> {
+        *self.cell_mut(logical)? = value;
+        Ok(())
+    }
+
+    /// cell_mut returns a mutable reference to the cell at a logical address,
+    /// growing the grid if required.
+    pub fn cell_mut(&mut self, logical: MatrixAddress<I>) -> Result<&mut T> {
+        let local = match self.to_local(logical) {
+            Some(v) => v,
+            None => {
+                self.grow_to_include(logical)?;
+                match self.to_local(logical) {
+                    Some(v) => v,
+                    None => panic!("grown grid does not contain address.  This should be unreachable."),
+                }
+            }
+        };
+        Ok(&mut self.matrix[local])
+    }
+
+    fn grow_to_include(&mut self, logical: MatrixAddress<I>) -> Result<()> {
+        let overflow = || Error::new("growable grid bounds exceed chosen index size".to_string());
+        let ione = I::unit();
+        let past_logical = MatrixAddress {
+            row: logical.row.checked_addition(ione).ok_or_else(overflow)?,
+            column: logical.column.checked_addition(ione).ok_or_else(overflow)?,
+        };
+        let empty = self.matrix.rows == I::default();
+        let (start, end) = if empty {
+            (logical, past_logical)
+        } else {
+            let end = MatrixAddress {
+                row: self.origin.row.checked_addition(self.matrix.rows).ok_or_else(overflow)?,
+                column: self.origin.column.checked_addition(self.matrix.columns).ok_or_else(overflow)?,
+            };
+            (
+                MatrixAddress {
+                    row: self.origin.row.min(logical.row),
+                    column: self.origin.column.min(logical.column),
+                },
+                MatrixAddress {
+                    row: end.row.max(past_logical.row),
+                    column: end.column.max(past_logical.column),
+                },
+            )
+        };
+        let rows = end.row.checked_subtraction(start.row).ok_or_else(overflow)?;
+        let columns = end.column.checked_subtraction(start.column).ok_or_else(overflow)?;
+        let len = rows.checked_multiply(columns).ok_or_else(overflow)?;
+        // The old bounding box is a sub-rectangle of the new one, so walking the
+        // new box in row-major order visits the old cells in their storage order.
+        let shift = if empty { MatrixAddress::default() } else { self.origin - start };
+        let old_end = shift + MatrixAddress {
+            row: self.matrix.rows,
+            column: self.matrix.columns,
+        };
+        let mut old = std::mem::take(&mut self.matrix.data).into_iter();
+        let mut data: Vec<T> = Vec::with_capacity(len);
+        for addr in MatrixForwardIterator::new(MatrixAddress { row: rows, column: columns }) {
+            let inside = addr.row >= shift.row && addr.row < old_end.row
+                && addr.column >= shift.column && addr.column < old_end.column;
+            match if inside { old.next() } else { None } {
+                Some(v) => data.push(v),
+                None => data.push(self.fill.clone()),
+            }
+        }
+        self.matrix = DenseMatrix::new(columns, rows, data);
+        self.origin = start;
+        Ok(())
+    }
+}
+
+impl <'a, T, I> Matrix<'a, T, I> for GrowableGrid<T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn row_count(&self) -> I {
+        self.matrix.row_count()
+    }
+
+    fn column_count(&self) -> I {
+        self.matrix.column_count()
+    }
+
+    fn iter(&'a self) -> MatrixValueIterator<'a, T, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        self.matrix.addresses()
+    }
+
+    fn indexed_iter(&'a self) -> MatrixForwardIndexedIterator<'a, T, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'a self, row_num: I) -> Option<Row<'a, T, I>> {
+        if row_num < I::default() || row_num >= self.row_count() {
+            None
+        } else {
+            Some(Row::new(self, row_num))
+        }
+    }
+
+    fn column(&'a self, col_num: I) -> Option<Column<'a, T, I>> {
+        if col_num < I::default() || col_num >= self.column_count() {
+            None
+        } else {
+            Some(Column::new(self, col_num))
+        }
+    }
+
+    fn rows(&'a self) -> MatrixRowsIterator<'a, T, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'a self) -> MatrixColumnsIterator<'a, T, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+impl <'a, T, I> MatrixMut<'a, T, I> for GrowableGrid<T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn iter_mut(&mut self) -> MatrixValueIterMut<'_, T, I> {
+        self.matrix.iter_mut()
+    }
+
+    fn indexed_iter_mut(&mut self) -> MatrixForwardIndexedIterMut<'_, T, I> {
+        self.matrix.indexed_iter_mut()
+    }
+}
+
+impl <T, I> Tensor<T, I, MatrixAddress<I>, 2> for GrowableGrid<T, I>
+where
+    I: Coordinate,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        self.matrix.range()
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        self.matrix.get(address)
+    }
+
+    fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        self.matrix.get_mut(address)
+    }
+}
+
+impl <T, I> Index<MatrixAddress<I>> for GrowableGrid<T, I>
+where
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, index: MatrixAddress<I>) -> &Self::Output {
+        &self.matrix[index]
+    }
+}
+
+impl <T, I> IndexMut<MatrixAddress<I>> for GrowableGrid<T, I>
+where
+    I: Coordinate,
+{
+    fn index_mut(&mut self, index: MatrixAddress<I>) -> &mut T {
+        &mut self.matrix[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_growable_grid, new_growable_grid_from, new_matrix};
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn i8addr(row: i8, column: i8) -> MatrixAddress<i8> {
+        MatrixAddress { row, column }
+    }
+
+    #[test]
+    fn empty_grid_reads_fill() {
+        let grid = new_growable_grid::<char, i8>('.');
+        assert_eq!(grid.row_count(), 0);
+        assert_eq!(grid.column_count(), 0);
+        assert_eq!(grid.value_at(i8addr(5, -3)), &'.');
+        assert!(grid.to_local(i8addr(0, 0)).is_none());
+    }
+
+    #[test]
+    fn first_write_sets_origin() {
+        let mut grid = new_growable_grid::<char, i8>('.');
+        grid.set(i8addr(-2, 7), '#').unwrap();
+        assert_eq!(grid.row_count(), 1);
+        assert_eq!(grid.column_count(), 1);
+        assert_eq!(grid.origin(), i8addr(-2, 7));
+        assert_eq!(grid[i8addr(0, 0)], '#');
+        assert_eq!(grid.value_at(i8addr(-2, 7)), &'#');
+    }
+
+    #[test]
+    fn grows_in_every_direction() {
+        let mut grid = new_growable_grid::<char, i8>('.');
+        grid.set(i8addr(0, 0), 'A').unwrap();
+        grid.set(i8addr(-1, 2), 'B').unwrap();
+        grid.set(i8addr(1, -1), 'C').unwrap();
+        assert_eq!(grid.origin(), i8addr(-1, -1));
+        assert_eq!(grid.row_count(), 3);
+        assert_eq!(grid.column_count(), 4);
+        let got = FormatOptions::default().format(&grid, |c| c.to_string());
+        assert_eq!(got, "...B\n.A..\nC...");
+        assert_eq!(grid.to_logical(i8addr(1, 1)), i8addr(0, 0));
+        assert_eq!(grid.to_local(i8addr(1, -1)), Some(i8addr(2, 0)));
+    }
+
+    #[test]
+    fn writes_inside_bounds_do_not_grow() {
+        let base = new_matrix::<char, i8>(2, vec!['a', 'b', 'c', 'd']).unwrap();
+        let mut grid = new_growable_grid_from(base, '.');
+        *grid.cell_mut(i8addr(1, 1)).unwrap() = 'z';
+        assert_eq!(grid.row_count(), 2);
+        assert_eq!(grid.column_count(), 2);
+        assert_eq!(grid.into_matrix().data, vec!['a', 'b', 'c', 'z']);
+    }
+
+    #[test]
+    fn growth_beyond_index_type_is_an_error() {
+        let mut grid = new_growable_grid::<char, i8>('.');
+        grid.set(i8addr(-100, 0), '#').unwrap();
+        let got = grid.set(i8addr(100, 0), '#');
+        assert_eq!(
+            got,
+            Err(Error::new("growable grid bounds exceed chosen index size".to_string()))
+        );
+        let max = grid.set(i8addr(i8::MAX, 0), '#');
+        assert!(max.is_err());
+        assert_eq!(grid.row_count(), 1);
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::Add;
+use crate::Coordinate;
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+
+/// MAX_HELD_KARP_NODES is the largest matrix the Hamiltonian path solvers
+/// accept.  Their tables hold 2^n * n entries.
+pub const MAX_HELD_KARP_NODES: usize = 20;
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Copy + Default + Ord + Add<Output = T>,
+    I: Coordinate,
+{
+    /// shortest_hamiltonian_path treats the matrix as a table of distances,
+    /// cell (i, j) being the cost of travelling from node i to node j, and
+    /// returns the cheapest order in which to visit every node exactly once,
+    /// starting and ending anywhere, with its total cost.  It uses the Held-Karp
+    /// dynamic program, so the matrix must be square with at most
+    /// MAX_HELD_KARP_NODES rows.
+    pub fn shortest_hamiltonian_path(&self) -> Result<(Vec<I>, T)> {
+        let n = self.held_karp_nodes()?;
+        let starts: Vec<usize> = (0..n).collect();
+        Ok(self.held_karp(n, &starts, false))
+    }
+
+    /// shortest_hamiltonian_cycle returns the cheapest tour that starts at node
+    /// 0, visits every other node exactly once and returns to node 0, with its
+    /// total cost including the final step home.  The returned order does not
+    /// repeat node 0 at the end.  The matrix must be square with at most
+    /// MAX_HELD_KARP_NODES rows.
+    pub fn shortest_hamiltonian_cycle(&self) -> Result<(Vec<I>, T)> {
+        let n = self.held_karp_nodes()?;
+        Ok(self.held_karp(n, &[0], true))
+    }
+
+    // held_karp_nodes validates the matrix and returns its node count.
+    fn held_karp_nodes(&self) -> Result<usize> {
+        if self.rows != self.columns {
+            return Err(Error::new(format!(
+                "operation requires a square matrix, got {}x{}",
+                self.rows, self.columns
+            )));
+        }
+        let n = self.data.len().isqrt();
+        if n > MAX_HELD_KARP_NODES {
+            return Err(Error::new(format!(
+                "held-karp supports at most {} nodes, got {}",
+                MAX_HELD_KARP_NODES, n
+            )));
+        }
+        Ok(n)
+    }
+
+    // held_karp finds the cheapest path through all n nodes beginning at one of
+    // starts, optionally closing the cycle back to its first node.
+    fn held_karp(&self, n: usize, starts: &[usize], cycle: bool) -> (Vec<I>, T) {
+        if n == 0 {
+            return (Vec::new(), T::default());
+        }
+        let full = (1usize << n) - 1;
+        // best[mask * n + j] is the cheapest path visiting the nodes in mask and
+        // ending at j, and previous[mask * n + j] the node before j on it.
+        let mut best: Vec<Option<T>> = vec![None; (full + 1) * n];
+        let mut previous: Vec<u8> = vec![0; (full + 1) * n];
+        for &start in starts {
+            best[(1 << start) * n + start] = Some(T::default());
+        }
+        for mask in 1..=full {
+            for last in 0..n {
+                let cost = match best[mask * n + last] {
+                    Some(cost) if mask >> last & 1 == 1 => cost,
+                    _ => continue,
+                };
+                for next in (0..n).filter(|next| mask >> next & 1 == 0) {
+                    let candidate = cost + self.data[last * n + next];
+                    let slot = (mask | 1 << next) * n + next;
+                    if best[slot].is_none_or(|b| candidate < b) {
+                        best[slot] = Some(candidate);
+                        previous[slot] = last as u8;
+                    }
+                }
+            }
+        }
+        let closing = |last: usize| if cycle { self.data[last * n] } else { T::default() };
+        let (mut last, total) = (0..n)
+            .filter_map(|last| best[full * n + last].map(|cost| (last, cost + closing(last))))
+            .min_by_key(|(_, total)| *total)
+            .unwrap_or((0, T::default()));
+        let mut order = vec![last];
+        let mut mask = full;
+        while mask.count_ones() > 1 {
+            let before = previous[mask * n + last] as usize;
+            mask &= !(1 << last);
+            last = before;
+            order.push(last);
+        }
+        order.reverse();
+        let order = order.into_iter()
+            .map(|node| match node.try_into() {
+                Ok(v) => v,
+                Err(_) => panic!("node overflows index type.  This should be unreachable."),
+            })
+            .collect();
+        (order, total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_distance_matrix, new_matrix};
+
+    #[test]
+    fn path_and_cycle_through_points() {
+        // Points on a line: the best path walks it end to end, and the best
+        // cycle goes out and back.
+        let positions = [0i64, 10, 3, 7, 1];
+        let d = new_distance_matrix::<_, i64, u8>(&positions, |a, b| (a - b).abs()).unwrap();
+        let (path, cost) = d.shortest_hamiltonian_path().unwrap();
+        assert_eq!(cost, 10);
+        assert!(path == vec![0, 4, 2, 3, 1] || path == vec![1, 3, 2, 4, 0]);
+        let (cycle, cost) = d.shortest_hamiltonian_cycle().unwrap();
+        assert_eq!(cost, 20);
+        assert_eq!(cycle[0], 0);
+        assert_eq!(cycle.len(), 5);
+    }
+
+    #[test]
+    fn asymmetric_costs_and_validation() {
+        let d = new_matrix::<u32, u8>(3, vec![
+            0, 1, 9,
+            9, 0, 1,
+            1, 9, 0,
+        ]).unwrap();
+        assert_eq!(d.shortest_hamiltonian_cycle().unwrap(), (vec![0, 1, 2], 3));
+        assert_eq!(d.shortest_hamiltonian_path().unwrap().1, 2);
+        let wide = new_matrix::<u32, u8>(1, vec![0, 1]).unwrap();
+        assert!(wide.shortest_hamiltonian_path().is_err());
+        let big = new_matrix::<u32, u8>(21, vec![0; 441]).unwrap();
+        assert!(big.shortest_hamiltonian_cycle().is_err());
+        let empty = new_matrix::<u32, u8>(0, vec![]).unwrap();
+        assert_eq!(empty.shortest_hamiltonian_path().unwrap(), (vec![], 0));
+    }
+}
@@ -0,0 +1,266 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Index, IndexMut, Range};
+use crate::error::{Error, Result};
+use crate::hypercube_address::HyperCubeAddress;
+use crate::iter::{HyperCubeForwardIndexedIterator, HyperCubeForwardIterator};
+use crate::traits::{CheckedAdd, Coordinate, Tensor};
+
+/// HyperCube is a dense four dimensional store of type T.  Storage is a
+/// sequence of cubes along w, each stored as a Cube is, so iteration visits
+/// addresses in HyperCubeAddress order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HyperCube<T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) x_size: I,
+    pub(crate) y_size: I,
+    pub(crate) z_size: I,
+    pub(crate) w_size: I,
+    pub(crate) data: Vec<T>,
+}
+
+impl <T, I> HyperCube<T, I>
+where
+    I: Coordinate,
+{
+    /// x_size returns the extent of the x dimension.
+    pub fn x_size(&self) -> I {
+        self.x_size
+    }
+
+    /// y_size returns the extent of the y dimension.
+    pub fn y_size(&self) -> I {
+        self.y_size
+    }
+
+    /// z_size returns the extent of the z dimension.
+    pub fn z_size(&self) -> I {
+        self.z_size
+    }
+
+    /// w_size returns the extent of the w dimension.
+    pub fn w_size(&self) -> I {
+        self.w_size
+    }
+
+    /// addresses returns every address in the hypercube, in address order.
+    pub fn addresses(&self) -> HyperCubeForwardIterator<I> {
+        HyperCubeForwardIterator::new(self.range().end)
+    }
+
+    /// iter returns the values of the hypercube in address order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// iter_mut returns mutable references to the values of the hypercube in address order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// indexed_iter returns (address, value) tuples in address order.
+    pub fn indexed_iter(&self) -> HyperCubeForwardIndexedIterator<'_, T, I> {
+        HyperCubeForwardIndexedIterator::new(self.addresses(), self.data.iter())
+    }
+
+    // index_address works in usize, since the cell count may not fit in I even
+    // though every coordinate does.
+    fn index_address(&self, address: HyperCubeAddress<I>) -> usize {
+        let [x, y, z, w] = [address.x, address.y, address.z, address.w].map(to_usize);
+        let [x_size, y_size, z_size] = [self.x_size, self.y_size, self.z_size].map(to_usize);
+        ((w * z_size + z) * y_size + y) * x_size + x
+    }
+}
+
+fn to_usize<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("address overflows usize.  This should be unreachable."),
+    }
+}
+
+impl <T, I> HyperCube<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    /// expand grows every dimension by margin cells on both sides, filling the new
+    /// cells with fill.  Existing cells move, so the cell formerly at address a is
+    /// found at a + (margin, margin, margin, margin) afterwards.  This suits
+    /// simulations whose active region grows by one cell per step.  An error is
+    /// returned, and the hypercube left unchanged, if the grown dimensions cannot
+    /// be represented by I.
+    pub fn expand(&mut self, margin: I, fill: T) -> Result<()>
+    where
+        I: CheckedAdd,
+    {
+        let overflow = || Error::new("hypercube dimensions exceed chosen index size".to_string());
+        if margin < I::default() {
+            return Err(Error::new("negative margin not supported".to_string()));
+        }
+        let grow = |size: I| -> Option<I> { size.checked_addition(margin)?.checked_addition(margin) };
+        let end = HyperCubeAddress {
+            x: grow(self.x_size).ok_or_else(overflow)?,
+            y: grow(self.y_size).ok_or_else(overflow)?,
+            z: grow(self.z_size).ok_or_else(overflow)?,
+            w: grow(self.w_size).ok_or_else(overflow)?,
+        };
+        let len = match (end.x.checked_multiply(end.y), end.z.checked_multiply(end.w)) {
+            (Some(a), Some(b)) => a.checked_mul(b).ok_or_else(overflow)?,
+            _ => return Err(overflow()),
+        };
+        let offset = HyperCubeAddress { x: margin, y: margin, z: margin, w: margin };
+        let old_end = offset + self.range().end;
+        // The old cells are a sub-box of the new one, so walking the new box in
+        // address order visits the old cells in their storage order.
+        let mut old = std::mem::take(&mut self.data).into_iter();
+        let mut data: Vec<T> = Vec::with_capacity(len);
+        for addr in HyperCubeForwardIterator::new(end) {
+            let inside = (0..4).all(|d| addr[d] >= offset[d] && addr[d] < old_end[d]);
+            match if inside { old.next() } else { None } {
+                Some(v) => data.push(v),
+                None => data.push(fill.clone()),
+            }
+        }
+        self.x_size = end.x;
+        self.y_size = end.y;
+        self.z_size = end.z;
+        self.w_size = end.w;
+        self.data = data;
+        Ok(())
+    }
+}
+
+impl<T, I> Tensor<T, I, HyperCubeAddress<I>, 4> for HyperCube<T, I>
+where
+    I: Coordinate,
+{
+    fn range(&self) -> Range<HyperCubeAddress<I>> {
+        Range {
+            start: HyperCubeAddress::default(),
+            end: HyperCubeAddress {
+                x: self.x_size,
+                y: self.y_size,
+                z: self.z_size,
+                w: self.w_size,
+            },
+        }
+    }
+
+    fn get(&self, address: HyperCubeAddress<I>) -> Option<&T> {
+        if !self.contains(address) {
+            None
+        } else {
+            let addr = self.index_address(address);
+            self.data.get(addr)
+        }
+    }
+
+    fn get_mut(&mut self, address: HyperCubeAddress<I>) -> Option<&mut T> {
+        if !self.contains(address) {
+            None
+        } else {
+            let addr = self.index_address(address);
+            self.data.get_mut(addr)
+        }
+    }
+}
+
+impl<T, I> Index<HyperCubeAddress<I>> for HyperCube<T, I>
+where
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, index: HyperCubeAddress<I>) -> &Self::Output {
+        match self.get(index) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl<T, I> IndexMut<HyperCubeAddress<I>> for HyperCube<T, I>
+where
+    I: Coordinate,
+{
+    fn index_mut(&mut self, index: HyperCubeAddress<I>) -> &mut T {
+        match self.get_mut(index) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_default_hypercube, new_default_hypercube_of, new_hypercube, new_hypercube_of};
+    use super::*;
+
+    fn u8addr(x: u8, y: u8, z: u8, w: u8) -> HyperCubeAddress<u8> {
+        HyperCubeAddress { x, y, z, w }
+    }
+
+    #[test]
+    fn storage_is_w_major() {
+        let mut h = new_default_hypercube::<u8, u8>(2, 2, 2, 2).unwrap();
+        h[u8addr(1, 0, 0, 0)] = 1;
+        h[u8addr(0, 0, 0, 1)] = 8;
+        *h.get_mut(u8addr(1, 1, 1, 1)).unwrap() = 15;
+        assert_eq!(h.data[1], 1);
+        assert_eq!(h.data[8], 8);
+        assert_eq!(h.data[15], 15);
+        assert_eq!(h.get(u8addr(0, 0, 0, 2)), None);
+        let last = h.indexed_iter().last().unwrap();
+        assert_eq!(last, (u8addr(1, 1, 1, 1), &15));
+        assert_eq!(h.addresses().count(), 16);
+    }
+
+    #[test]
+    fn new_from_data() {
+        let h = new_hypercube::<u32, u8>(2, 1, 3, 2, (0..12).collect()).unwrap();
+        assert_eq!(h[u8addr(1, 0, 0, 0)], 1);
+        assert_eq!(h[u8addr(0, 0, 1, 0)], 2);
+        assert_eq!(h[u8addr(1, 0, 2, 1)], 11);
+        assert_eq!(
+            new_hypercube::<u32, u8>(2, 2, 2, 2, vec![0; 15]).err(),
+            Some(Error::new("data length 15 does not match hypercube dimensions (16)".to_string()))
+        );
+        assert_eq!(new_hypercube::<u32, u8>(2, 0, 2, 2, vec![]).unwrap().x_size(), 0);
+    }
+
+    #[test]
+    fn shaped_factories() {
+        let shape = u8addr(2, 1, 3, 2);
+        let h = new_hypercube_of::<u32, u8>(shape, (0..12).collect()).unwrap();
+        assert_eq!(h, new_hypercube(2, 1, 3, 2, (0..12).collect()).unwrap());
+        let d = new_default_hypercube_of::<u32, u8>(shape).unwrap();
+        assert_eq!((d.x_size(), d.y_size(), d.z_size(), d.w_size()), (2, 1, 3, 2));
+    }
+
+    #[test]
+    fn index_exceeding_coordinate_type() {
+        let mut h = new_default_hypercube::<u8, u8>(5, 5, 5, 5).unwrap();
+        h[u8addr(4, 4, 4, 4)] = 1;
+        assert_eq!(h.get(u8addr(4, 4, 4, 4)), Some(&1));
+        assert_eq!(h.iter().position(|v| *v == 1), Some(624));
+    }
+
+    #[test]
+    fn expand_shifts_contents() {
+        let mut h = new_default_hypercube::<char, u8>(1, 1, 1, 1).unwrap();
+        h[u8addr(0, 0, 0, 0)] = '#';
+        h.expand(1, '.').unwrap();
+        assert_eq!((h.x_size(), h.y_size(), h.z_size(), h.w_size()), (3, 3, 3, 3));
+        assert_eq!(h[u8addr(1, 1, 1, 1)], '#');
+        assert_eq!(h.iter().filter(|c| **c == '#').count(), 1);
+        assert_eq!(u8addr(1, 1, 1, 1).neighbors(&h).iter().filter(|a| h[**a] == '.').count(), 80);
+        assert_eq!(
+            h.expand(200, '.'),
+            Err(Error::new("hypercube dimensions exceed chosen index size".to_string()))
+        );
+        assert_eq!(h.x_size(), 3);
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::cube_address::neighbor_span;
+use crate::traits::{Address, Coordinate, Dimension, Tensor};
+use crate::hypercube::HyperCube;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Index, Sub};
+
+/// HyperCubeAddress references a cell in a four dimensional hypercube by its
+/// x, y, z and w position.  x, y and z are laid out as in a CubeAddress, and w
+/// selects one of a sequence of cubes.  The field order gives the same w-major,
+/// then z-major, ordering that hypercube iteration uses.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct HyperCubeAddress<I>
+where
+    I: Coordinate,
+{
+    pub w: I,
+    pub z: I,
+    pub y: I,
+    pub x: I,
+}
+
+impl <I> HyperCubeAddress<I>
+where
+    I: Coordinate {
+
+    // neighbors returns the addresses of the up to 80 adjacent hypercube addresses in the
+    // given hypercube.  All returned addresses are guaranteed to be in-bounds for the given
+    // hypercube.  An address outside the hypercube has no neighbors.
+    pub fn neighbors<T>(&self, hypercube: &HyperCube<T, I>) -> Vec<HyperCubeAddress<I>> {
+        if !hypercube.contains(*self) {
+            return Vec::new();
+        }
+        let ione = I::unit();
+        let (x_low, x_high) = neighbor_span(self.x, hypercube.x_size());
+        let (y_low, y_high) = neighbor_span(self.y, hypercube.y_size());
+        let (z_low, z_high) = neighbor_span(self.z, hypercube.z_size());
+        let (w_low, w_high) = neighbor_span(self.w, hypercube.w_size());
+        let mut neighbors = Vec::new();
+        let mut w = w_low;
+        while w <= w_high {
+            let mut z = z_low;
+            while z <= z_high {
+                let mut y = y_low;
+                while y <= y_high {
+                    let mut x = x_low;
+                    while x <= x_high {
+                        let candidate = HyperCubeAddress { x, y, z, w };
+                        if candidate != *self {
+                            neighbors.push(candidate);
+                        }
+                        x = x + ione;
+                    }
+                    y = y + ione;
+                }
+                z = z + ione;
+            }
+            w = w + ione;
+        }
+        neighbors
+    }
+}
+
+impl<I> Index<Dimension> for HyperCubeAddress<I>
+where
+    I: Coordinate,
+{
+    type Output = I;
+
+    fn index(&self, index: Dimension) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("invalid dimension"),
+        }
+    }
+}
+
+impl<I> Address<I, 4usize> for HyperCubeAddress<I> where I: Coordinate {}
+
+impl<I> Display for HyperCubeAddress<I>
+where
+    I: Coordinate,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("(x={},y={},z={},w={})", self.x, self.y, self.z, self.w))
+    }
+}
+
+impl<I> From<[I; 4]> for HyperCubeAddress<I>
+where
+    I: Coordinate,
+{
+    fn from(value: [I; 4]) -> Self {
+        Self {
+            x: value[0],
+            y: value[1],
+            z: value[2],
+            w: value[3],
+        }
+    }
+}
+
+impl <I> From<HyperCubeAddress<I>> for [I; 4]
+where
+    I: Coordinate,{
+    fn from(value: HyperCubeAddress<I>) -> Self {
+        [value.x, value.y, value.z, value.w]
+    }
+}
+
+impl<I> Add for HyperCubeAddress<I>
+where
+    I: Coordinate,
+{
+    type Output = HyperCubeAddress<I>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Warning: result can be out of bounds
+        HyperCubeAddress {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl<I> Sub for HyperCubeAddress<I>
+where
+    I: Coordinate,
+{
+    type Output = HyperCubeAddress<I>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        // Warning: result can be out of bounds
+        HyperCubeAddress {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+impl<I> Default for HyperCubeAddress<I>
+where
+    I: Coordinate,
+{
+    fn default() -> Self {
+        HyperCubeAddress {
+            x: I::default(),
+            y: I::default(),
+            z: I::default(),
+            w: I::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::new_default_hypercube;
+    use super::*;
+
+    fn u8addr(x: u8, y: u8, z: u8, w: u8) -> HyperCubeAddress<u8> {
+        HyperCubeAddress { x, y, z, w }
+    }
+
+    #[test]
+    fn test_display_and_dimensions() {
+        let addr = u8addr(1, 2, 3, 4);
+        assert_eq!(addr.to_string(), "(x=1,y=2,z=3,w=4)");
+        assert_eq!(addr[3], 4);
+        let rep: [u8; 4] = addr.into();
+        assert_eq!(HyperCubeAddress::from(rep), addr);
+        assert!(u8addr(9, 9, 9, 0) < u8addr(0, 0, 0, 1));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let hypercube = new_default_hypercube::<u8, u8>(3, 3, 3, 3).unwrap();
+        let center = u8addr(1, 1, 1, 1).neighbors(&hypercube);
+        assert_eq!(center.len(), 80);
+        assert!(center.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(u8addr(0, 0, 0, 0).neighbors(&hypercube).len(), 15);
+    }
+
+    #[test]
+    fn test_out_of_bounds_neighbors() {
+        let hypercube = new_default_hypercube::<u8, u8>(3, 3, 3, 3).unwrap();
+        assert!(u8addr(255, 1, 1, 1).neighbors(&hypercube).is_empty());
+        assert!(u8addr(1, 1, 1, 3).neighbors(&hypercube).is_empty());
+        let signed = new_default_hypercube::<u8, i32>(3, 3, 3, 3).unwrap();
+        assert!(HyperCubeAddress { x: -3, y: 1, z: 1, w: 1 }.neighbors(&signed).is_empty());
+    }
+}
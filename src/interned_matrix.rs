@@ -0,0 +1,126 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::collections::HashMap;
+use crate::{Coordinate, DenseMatrix, MatrixAddress, Tensor};
+use crate::error::{Error, Result};
+
+/// Symbol is a small integer standing in for a string in an InternedMatrix.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Symbol(pub(crate) u32);
+
+impl Symbol {
+    /// index returns the position of the symbol in its string table.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// InternedMatrix is a matrix of strings that stores each distinct string once.
+/// Cells hold Symbols, which are resolved through a string table shared by the
+/// whole matrix, so grids of repeated tokens don't hold a String per cell.
+/// Use FormatOptions::parse_interned and format_interned to read and write them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InternedMatrix<I>
+where
+    I: Coordinate,
+{
+    pub(crate) matrix: DenseMatrix<Symbol, I>,
+    pub(crate) table: Vec<String>,
+    pub(crate) lookup: HashMap<String, Symbol>,
+}
+
+impl <I> Default for InternedMatrix<I>
+where
+    I: Coordinate,
+{
+    fn default() -> Self {
+        InternedMatrix {
+            matrix: DenseMatrix::new(I::default(), I::default(), Vec::new()),
+            table: Vec::new(),
+            lookup: HashMap::new(),
+        }
+    }
+}
+
+impl <I> InternedMatrix<I>
+where
+    I: Coordinate,
+{
+    /// matrix returns the matrix of symbols.
+    pub fn matrix(&self) -> &DenseMatrix<Symbol, I> {
+        &self.matrix
+    }
+
+    /// symbols returns the string table, indexed by Symbol::index.
+    pub fn symbols(&self) -> &[String] {
+        &self.table
+    }
+
+    /// resolve returns the string a symbol stands for, or None if the symbol
+    /// is not from this matrix.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        self.table.get(symbol.index()).map(|s| s.as_str())
+    }
+
+    /// symbol returns the symbol for value, or None if value has not been interned.
+    pub fn symbol(&self, value: &str) -> Option<Symbol> {
+        self.lookup.get(value).copied()
+    }
+
+    /// intern returns the symbol for value, adding it to the string table if needed.
+    pub fn intern(&mut self, value: &str) -> Symbol {
+        if let Some(symbol) = self.lookup.get(value) {
+            return *symbol;
+        }
+        let symbol = match self.table.len().try_into() {
+            Ok(v) => Symbol(v),
+            Err(_) => panic!("symbol table overflows u32"),
+        };
+        self.table.push(value.to_string());
+        self.lookup.insert(value.to_string(), symbol);
+        symbol
+    }
+
+    /// get returns the string stored at address, or None if address is out of bounds.
+    pub fn get(&self, address: MatrixAddress<I>) -> Option<&str> {
+        self.resolve(*self.matrix.get(address)?)
+    }
+
+    /// set stores value at address, interning it if needed.  An out of bounds
+    /// address is an error.
+    pub fn set(&mut self, address: MatrixAddress<I>, value: &str) -> Result<()> {
+        if !self.matrix.contains(address) {
+            return Err(Error::new(format!("address {} is out of bounds", address)));
+        }
+        let symbol = self.intern(value);
+        self.matrix[address] = symbol;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::FormatOptions;
+    use crate::Matrix;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    #[test]
+    fn repeated_tokens_share_symbols() {
+        let opts = FormatOptions { column_delimiter: " ".to_string(), row_delimiter: "\n".to_string() };
+        let mut m = opts.parse_interned::<u8>("wall floor wall\nfloor floor door").unwrap();
+        assert_eq!(m.matrix().row_count(), 2);
+        assert_eq!(m.symbols(), &["wall", "floor", "door"]);
+        assert_eq!(m.matrix()[u8addr(1, 0)], m.matrix()[u8addr(0, 1)]);
+        assert_eq!(m.get(u8addr(1, 2)), Some("door"));
+        assert_eq!(m.symbol("floor"), Some(Symbol(1)));
+        m.set(u8addr(0, 0), "lava").unwrap();
+        m.set(u8addr(0, 2), "floor").unwrap();
+        assert_eq!(m.symbols().len(), 4);
+        assert!(m.set(u8addr(2, 0), "wall").is_err());
+        assert_eq!(opts.format_interned(&m), "lava floor floor\nfloor floor door");
+    }
+}
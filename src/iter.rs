@@ -1,9 +1,14 @@
 // Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
 
 use crate::{Coordinate, Matrix};
-use crate::column::Column;
+use crate::column::{Column, ColumnMut};
+use crate::cube_address::CubeAddress;
+use crate::hypercube_address::HyperCubeAddress;
+use crate::nd_address::NdAddress;
 use crate::matrix_address::MatrixAddress;
-use crate::row::Row;
+use crate::row::{Row, RowMut};
+use crate::sub_matrix::SubMatrix;
+use std::slice::ChunksExactMut;
 
 /// MatrixForwardIterator returns the available addresses in a matrix in
 /// row-major format starting at the origin, or upper left (0, 0) address.
@@ -59,6 +64,50 @@ impl <I> Iterator for MatrixForwardIterator<I>
     }
 }
 
+/// MatrixReverseIterator returns the available addresses in a matrix in
+/// reverse row-major order, starting at the lower right address and ending
+/// at the origin.
+pub struct MatrixReverseIterator<I>
+    where I: Coordinate
+{
+    end_exclusive: MatrixAddress<I>,
+    cursor: Option<MatrixAddress<I>>
+}
+
+impl <I> MatrixReverseIterator<I>
+    where I: Coordinate {
+    pub(crate) fn new(end_exclusive: MatrixAddress<I>) -> Self {
+        let zero = I::default();
+        let cursor = if end_exclusive.row <= zero || end_exclusive.column <= zero {
+            None
+        } else {
+            Some(end_exclusive - MatrixAddress { row: I::unit(), column: I::unit() })
+        };
+        MatrixReverseIterator {
+            end_exclusive,
+            cursor,
+        }
+    }
+}
+
+impl <I> Iterator for MatrixReverseIterator<I>
+    where I: Coordinate {
+    type Item = MatrixAddress<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.cursor?;
+        let zero = I::default();
+        self.cursor = if result.column > zero {
+            Some(MatrixAddress { row: result.row, column: result.column - I::unit() })
+        } else if result.row > zero {
+            Some(MatrixAddress { row: result.row - I::unit(), column: self.end_exclusive.column - I::unit() })
+        } else {
+            None
+        };
+        Some(result)
+    }
+}
+
 /// MatrixValueIterator returns the values in a matrix
 /// in row-major order, starting at the upper left origin (0, 0).
 pub struct MatrixValueIterator<'a, T, I>
@@ -139,15 +188,136 @@ where
     }
 }
 
+/// MatrixValueIterMut returns mutable references to the values in a matrix
+/// in row-major order, starting at the upper left origin (0, 0).
+pub struct MatrixValueIterMut<'a, T, I>
+where
+    T: 'a,
+    I: Coordinate,
+{
+    values: Box<dyn Iterator<Item = &'a mut T> + 'a>,
+    phantom: std::marker::PhantomData<I>,
+}
+
+impl <'a, T, I> MatrixValueIterMut<'a, T, I>
+where
+    T: 'a,
+    I: Coordinate,
+{
+    /// values must yield the matrix cells in row-major order.
+    pub(crate) fn new(values: Box<dyn Iterator<Item = &'a mut T> + 'a>) -> Self {
+        MatrixValueIterMut{
+            values,
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl <'a, T, I> Iterator for MatrixValueIterMut<'a, T, I>
+where
+    T: 'a,
+    I: Coordinate,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next()
+    }
+}
+
+/// MatrixForwardIndexedIterMut returns (address, mutable value) tuples for
+/// a matrix in row-major order, starting at the upper left origin (0,0).
+pub struct MatrixForwardIndexedIterMut<'a, T, I>
+where
+    T: 'a,
+    I: Coordinate,
+{
+    addrs: MatrixForwardIterator<I>,
+    values: Box<dyn Iterator<Item = &'a mut T> + 'a>,
+}
+
+impl <'a, T, I> MatrixForwardIndexedIterMut<'a, T, I>
+where
+    T: 'a,
+    I: Coordinate,
+{
+    /// values must yield the matrix cells in the same row-major order as addrs.
+    pub(crate) fn new(addrs: MatrixForwardIterator<I>, values: Box<dyn Iterator<Item = &'a mut T> + 'a>) -> Self {
+        MatrixForwardIndexedIterMut{
+            addrs,
+            values,
+        }
+    }
+}
+
+impl <'a, T, I> Iterator for MatrixForwardIndexedIterMut<'a, T, I>
+where
+    T: 'a,
+    I: Coordinate,
+{
+    type Item = (MatrixAddress<I>, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.addrs.next(), self.values.next()) {
+            (Some(a), Some(v)) => Some((a, v)),
+            _ => None,
+        }
+    }
+}
+
+/// LineCursor tracks the remaining, inclusive, range of positions of a
+/// bidirectional walk along a row, a column, or the rows or columns of a
+/// matrix.  None means the walk is exhausted, so empty lines never require
+/// computing count - 1, which would underflow unsigned coordinates.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LineCursor<I>
+where
+    I: Coordinate,
+{
+    remaining: Option<(I, I)>,
+}
+
+impl <I> LineCursor<I>
+where
+    I: Coordinate,
+{
+    /// new creates a cursor over positions 0..count.
+    pub(crate) fn new(count: I) -> Self {
+        let zero = I::default();
+        LineCursor {
+            remaining: if count > zero { Some((zero, count - I::unit())) } else { None },
+        }
+    }
+
+    /// over creates a cursor over positions from..to, which must be ordered.
+    pub(crate) fn over(from: I, to: I) -> Self {
+        LineCursor {
+            remaining: if to > from { Some((from, to - I::unit())) } else { None },
+        }
+    }
+
+    /// next_front returns the lowest remaining position.
+    pub(crate) fn next_front(&mut self) -> Option<I> {
+        let (front, back) = self.remaining?;
+        self.remaining = if front == back { None } else { Some((front + I::unit(), back)) };
+        Some(front)
+    }
+
+    /// next_back returns the highest remaining position.
+    pub(crate) fn next_back(&mut self) -> Option<I> {
+        let (front, back) = self.remaining?;
+        self.remaining = if front == back { None } else { Some((front, back - I::unit())) };
+        Some(back)
+    }
+}
+
 pub struct MatrixRowIterator<'a, T, I>
 where
     T: 'static,
     I: Coordinate {
     matrix: &'a dyn Matrix<'a, T, I>,
     row: I,
-    column_cursor_forward: I,
-    column_cursor_back: I,
-    terminated: bool,
+    columns: LineCursor<I>,
 }
 
 impl <'a, T, I> MatrixRowIterator<'a, T, I>
@@ -159,9 +329,17 @@ where
         MatrixRowIterator{
             matrix,
             row,
-            column_cursor_forward: I::unit() - I::unit(),
-            column_cursor_back: matrix.column_count() - I::unit(),
-            terminated: matrix.column_count() == I::unit() - I::unit(),
+            columns: LineCursor::new(matrix.column_count()),
+        }
+    }
+
+    /// over iterates the columns from..to of row.  The range must already be
+    /// validated against the matrix.
+    pub(crate) fn over(matrix: &'a dyn Matrix<'a, T, I>, row: I, from: I, to: I) -> Self {
+        MatrixRowIterator{
+            matrix,
+            row,
+            columns: LineCursor::over(from, to),
         }
     }
 }
@@ -174,23 +352,8 @@ where
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // because some of the coordinate types can be unsigned,
-        // and because we can cause termination in reverse iteration,
-        // we can't just look for cursor_forward > cursor_back.
-        if self.terminated {
-            None
-        } else {
-            let addr = MatrixAddress{
-                row: self.row,
-                column: self.column_cursor_forward,
-            };
-            let result = Some(&self.matrix[addr]);
-            if self.column_cursor_forward == self.column_cursor_back {
-                self.terminated = true;
-            }
-            self.column_cursor_forward = self.column_cursor_forward + I::unit();
-            result
-        }
+        let column = self.columns.next_front()?;
+        Some(&self.matrix[MatrixAddress{ row: self.row, column }])
     }
 }
 
@@ -200,21 +363,8 @@ where
     I: Coordinate,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.terminated {
-            None
-        } else {
-            let addr = MatrixAddress{
-                row: self.row,
-                column: self.column_cursor_back,
-            };
-            let result = Some(&self.matrix[addr]);
-            if self.column_cursor_back == self.column_cursor_forward {
-                self.terminated = true;
-            } else {
-                self.column_cursor_back = self.column_cursor_back - I::unit();
-            }
-            result
-        }
+        let column = self.columns.next_back()?;
+        Some(&self.matrix[MatrixAddress{ row: self.row, column }])
     }
 }
 
@@ -223,9 +373,7 @@ where
     T: 'static,
     I: Coordinate {
     matrix: &'a dyn Matrix<'a, T, I>,
-    row_cursor_forward: I,
-    row_cursor_back: I,
-    terminated: bool,
+    rows: LineCursor<I>,
 }
 
 impl <'a, T, I> MatrixRowsIterator<'a, T, I>
@@ -236,9 +384,7 @@ where
     pub(crate) fn new(matrix: &'a dyn Matrix<'a, T, I>) -> Self {
         MatrixRowsIterator{
             matrix,
-            row_cursor_forward: I::unit() - I::unit(),
-            row_cursor_back: matrix.row_count() - I::unit(),
-            terminated: matrix.row_count() == I::unit() - I::unit(),
+            rows: LineCursor::new(matrix.row_count()),
         }
     }
 }
@@ -251,19 +397,7 @@ where
     type Item = Row<'a, T, I>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // because some of the coordinate types can be unsigned,
-        // and because we can cause termination in reverse iteration,
-        // we can't just look for cursor_forward > cursor_back.
-        if self.terminated {
-            None
-        } else {
-            let row : Row<T, I> = Row::new(self.matrix, self.row_cursor_forward);
-            if self.row_cursor_forward == self.row_cursor_back {
-                self.terminated = true;
-            }
-            self.row_cursor_forward = self.row_cursor_forward + I::unit();
-            Some(row)
-        }
+        Some(Row::new(self.matrix, self.rows.next_front()?))
     }
 }
 
@@ -273,20 +407,46 @@ where
     I: Coordinate,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.terminated {
-            None
-        } else {
-            let row : Row<T, I> = Row::new(self.matrix, self.row_cursor_back);
-            if self.row_cursor_forward == self.row_cursor_back {
-                self.terminated = true;
-            } else {
-                self.row_cursor_back = self.row_cursor_back - I::unit();
-            }
-            Some(row)
+        Some(Row::new(self.matrix, self.rows.next_back()?))
+    }
+}
+
+/// MatrixRowsIterMut returns a RowMut for each row of a DenseMatrix, top to bottom.
+pub struct MatrixRowsIterMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    chunks: Option<ChunksExactMut<'a, T>>,
+    row_cursor: I,
+}
+
+impl <'a, T, I> MatrixRowsIterMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// data is the row-major storage of a matrix with the given number of columns.
+    pub(crate) fn new(data: &'a mut [T], columns: usize) -> Self {
+        MatrixRowsIterMut{
+            // a matrix without columns has no rows, and chunks_exact_mut(0) panics.
+            chunks: if columns == 0 { None } else { Some(data.chunks_exact_mut(columns)) },
+            row_cursor: I::default(),
         }
     }
 }
 
+impl <'a, T, I> Iterator for MatrixRowsIterMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    type Item = RowMut<'a, T, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cells = self.chunks.as_mut()?.next()?;
+        let row = RowMut::new(cells, self.row_cursor);
+        self.row_cursor = self.row_cursor + I::unit();
+        Some(row)
+    }
+}
 
 pub struct MatrixColumnIterator<'a, T, I>
 where
@@ -294,9 +454,7 @@ where
     I: Coordinate {
     matrix: &'a dyn Matrix<'a, T, I>,
     column: I,
-    row_cursor_forward: I,
-    row_cursor_back: I,
-    terminated: bool,
+    rows: LineCursor<I>,
 }
 
 impl <'a, T, I> MatrixColumnIterator<'a, T, I>
@@ -308,9 +466,17 @@ where
         MatrixColumnIterator{
             matrix,
             column,
-            row_cursor_forward: I::unit() - I::unit(),
-            row_cursor_back: matrix.row_count() - I::unit(),
-            terminated: matrix.row_count() == I::unit() - I::unit(),
+            rows: LineCursor::new(matrix.row_count()),
+        }
+    }
+
+    /// over iterates the rows from..to of column.  The range must already be
+    /// validated against the matrix.
+    pub(crate) fn over(matrix: &'a dyn Matrix<'a, T, I>, column: I, from: I, to: I) -> Self {
+        MatrixColumnIterator{
+            matrix,
+            column,
+            rows: LineCursor::over(from, to),
         }
     }
 }
@@ -323,23 +489,8 @@ where
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // because some of the coordinate types can be unsigned,
-        // and because we can cause termination in reverse iteration,
-        // we can't just look for cursor_forward > cursor_back.
-        if self.terminated {
-            None
-        } else {
-            let addr = MatrixAddress{
-                row: self.row_cursor_forward,
-                column: self.column,
-            };
-            let result = Some(&self.matrix[addr]);
-            if self.row_cursor_forward == self.row_cursor_back {
-                self.terminated = true;
-            }
-            self.row_cursor_forward = self.row_cursor_forward + I::unit();
-            result
-        }
+        let row = self.rows.next_front()?;
+        Some(&self.matrix[MatrixAddress{ row, column: self.column }])
     }
 }
 
@@ -349,21 +500,8 @@ where
     I: Coordinate,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.terminated {
-            None
-        } else {
-            let addr = MatrixAddress{
-                row: self.row_cursor_back,
-                column: self.column,
-            };
-            let result = Some(&self.matrix[addr]);
-            if self.row_cursor_back == self.row_cursor_forward {
-                self.terminated = true;
-            } else {
-                self.row_cursor_back = self.row_cursor_back - I::unit();
-            }
-            result
-        }
+        let row = self.rows.next_back()?;
+        Some(&self.matrix[MatrixAddress{ row, column: self.column }])
     }
 }
 
@@ -373,9 +511,7 @@ where
     I: Coordinate,
 {
     matrix: &'a dyn Matrix<'a, T, I>,
-    column_cursor_forward: I,
-    column_cursor_back: I,
-    terminated: bool,
+    columns: LineCursor<I>,
 }
 
 impl <'a, T, I> MatrixColumnsIterator<'a, T, I>
@@ -386,9 +522,7 @@ where
     pub(crate) fn new(matrix: &'a dyn Matrix<'a, T, I>) -> Self {
         MatrixColumnsIterator{
             matrix,
-            column_cursor_forward: I::unit() - I::unit(),
-            column_cursor_back: matrix.column_count() - I::unit(),
-            terminated: matrix.row_count() == I::unit() - I::unit(),
+            columns: LineCursor::new(matrix.column_count()),
         }
     }
 }
@@ -401,19 +535,7 @@ where
     type Item = Column<'a, T, I>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        // because some of the coordinate types can be unsigned,
-        // and because we can cause termination in reverse iteration,
-        // we can't just look for cursor_forward > cursor_back.
-        if self.terminated {
-            None
-        } else {
-            let column : Column<T, I> = Column::new(self.matrix, self.column_cursor_forward);
-            if self.column_cursor_forward == self.column_cursor_back {
-                self.terminated = true;
-            }
-            self.column_cursor_forward = self.column_cursor_forward + I::unit();
-            Some(column)
-        }
+        Some(Column::new(self.matrix, self.columns.next_front()?))
     }
 }
 
@@ -423,22 +545,436 @@ where
     I: Coordinate,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.terminated {
+        Some(Column::new(self.matrix, self.columns.next_back()?))
+    }
+}
+
+/// MatrixColumnsIterMut returns a ColumnMut for each column of a DenseMatrix,
+/// left to right.
+pub struct MatrixColumnsIterMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    columns: std::vec::IntoIter<Vec<&'a mut T>>,
+    column_cursor: I,
+}
+
+impl <'a, T, I> MatrixColumnsIterMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// data is the row-major storage of a matrix with the given number of columns.
+    pub(crate) fn new(data: &'a mut [T], columns: usize) -> Self {
+        let mut cells: Vec<Vec<&'a mut T>> = (0..columns).map(|_| Vec::new()).collect();
+        for (i, value) in data.iter_mut().enumerate() {
+            cells[i % columns].push(value);
+        }
+        MatrixColumnsIterMut{
+            columns: cells.into_iter(),
+            column_cursor: I::default(),
+        }
+    }
+}
+
+impl <'a, T, I> Iterator for MatrixColumnsIterMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    type Item = ColumnMut<'a, T, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cells = self.columns.next()?;
+        let column = ColumnMut::new(cells, self.column_cursor);
+        self.column_cursor = self.column_cursor + I::unit();
+        Some(column)
+    }
+}
+
+/// MatrixIntoIndexedIterator consumes a matrix, returning (address, value)
+/// tuples in row-major order, starting at the upper left origin (0,0).
+pub struct MatrixIntoIndexedIterator<T, I>
+where
+    I: Coordinate,
+{
+    addrs: MatrixForwardIterator<I>,
+    values: std::vec::IntoIter<T>,
+}
+
+impl <T, I> MatrixIntoIndexedIterator<T, I>
+where
+    I: Coordinate,
+{
+    /// values must yield the matrix cells in the same row-major order as addrs.
+    pub(crate) fn new(addrs: MatrixForwardIterator<I>, values: std::vec::IntoIter<T>) -> Self {
+        MatrixIntoIndexedIterator{
+            addrs,
+            values,
+        }
+    }
+}
+
+impl <T, I> Iterator for MatrixIntoIndexedIterator<T, I>
+where
+    I: Coordinate,
+{
+    type Item = (MatrixAddress<I>, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.addrs.next(), self.values.next()) {
+            (Some(a), Some(v)) => Some((a, v)),
+            _ => None,
+        }
+    }
+}
+
+/// MatrixWindowsIterator returns a SubMatrix view of every overlapping window
+/// of a fixed size, with window origins visited in row-major order.
+pub struct MatrixWindowsIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    underlay: &'a dyn Matrix<'a, T, I>,
+    size: MatrixAddress<I>,
+    starts: MatrixForwardIterator<I>,
+}
+
+impl <'a, T, I> MatrixWindowsIterator<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// size must have positive dimensions.  A window larger than the underlay
+    /// yields no views.
+    pub(crate) fn new(underlay: &'a dyn Matrix<'a, T, I>, size: MatrixAddress<I>) -> Self {
+        let fits = size.row <= underlay.row_count() && size.column <= underlay.column_count();
+        let end = if fits {
+            underlay.range().end - size + MatrixAddress { row: I::unit(), column: I::unit() }
+        } else {
+            MatrixAddress::default()
+        };
+        MatrixWindowsIterator {
+            underlay,
+            size,
+            starts: MatrixForwardIterator::new(end),
+        }
+    }
+}
+
+impl <'a, T, I> Iterator for MatrixWindowsIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    type Item = SubMatrix<'a, T, I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.starts.next()?;
+        Some(SubMatrix {
+            underlay: self.underlay,
+            window: start..start + self.size,
+        })
+    }
+}
+
+/// AddressPairsIterator returns every unordered pair of addresses within each
+/// of its groups, each pair once with its addresses in reading order.
+pub struct AddressPairsIterator<I>
+where
+    I: Coordinate,
+{
+    groups: Vec<Vec<MatrixAddress<I>>>,
+    group: usize,
+    first: usize,
+    second: usize,
+}
+
+impl <I> AddressPairsIterator<I>
+where
+    I: Coordinate,
+{
+    /// each group must be in reading order.
+    pub(crate) fn new(groups: Vec<Vec<MatrixAddress<I>>>) -> Self {
+        AddressPairsIterator {
+            groups,
+            group: 0,
+            first: 0,
+            second: 1,
+        }
+    }
+}
+
+impl <I> Iterator for AddressPairsIterator<I>
+where
+    I: Coordinate,
+{
+    type Item = (MatrixAddress<I>, MatrixAddress<I>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let group = self.groups.get(self.group)?;
+            if self.second < group.len() {
+                let pair = (group[self.first], group[self.second]);
+                self.second += 1;
+                return Some(pair);
+            }
+            self.first += 1;
+            self.second = self.first + 1;
+            if self.second >= group.len() {
+                self.group += 1;
+                self.first = 0;
+                self.second = 1;
+            }
+        }
+    }
+}
+
+/// CubeForwardIterator returns the available addresses in a cube layer by
+/// layer, each layer in row-major order, starting at the origin (0, 0, 0).
+pub struct CubeForwardIterator<I>
+    where I: Coordinate
+{
+    end_exclusive: CubeAddress<I>,
+    cursor: Option<CubeAddress<I>>
+}
+
+impl <I> CubeForwardIterator<I>
+    where I: Coordinate {
+    pub(crate) fn new(end_exclusive: CubeAddress<I>) -> Self {
+        let zero = I::default();
+        let cursor = if end_exclusive.x == zero || end_exclusive.y == zero || end_exclusive.z == zero {
             None
         } else {
-            let column : Column<T, I> = Column::new(self.matrix, self.column_cursor_back);
-            if self.column_cursor_forward == self.column_cursor_back {
-                self.terminated = true;
-            } else {
-                self.column_cursor_back = self.column_cursor_back - I::unit();
+            Some(CubeAddress::default())
+        };
+        CubeForwardIterator{
+            end_exclusive,
+            cursor,
+        }
+    }
+}
+
+impl <I> Iterator for CubeForwardIterator<I>
+    where I: Coordinate {
+    type Item = CubeAddress<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.cursor?;
+        let mut v = result;
+        v.x = v.x + I::unit();
+        if v.x == self.end_exclusive.x {
+            v.x = I::default();
+            v.y = v.y + I::unit();
+            if v.y == self.end_exclusive.y {
+                v.y = I::default();
+                v.z = v.z + I::unit();
             }
-            Some(column)
+        }
+        self.cursor = if v.z == self.end_exclusive.z { None } else { Some(v) };
+        Some(result)
+    }
+}
+
+/// CubeForwardIndexedIterator returns (address, value) tuples from a cube in
+/// the same order as CubeForwardIterator.
+pub struct CubeForwardIndexedIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    addrs: CubeForwardIterator<I>,
+    values: std::slice::Iter<'a, T>,
+}
+
+impl <'a, T, I> CubeForwardIndexedIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// values must yield the cube cells in the same order as addrs.
+    pub(crate) fn new(addrs: CubeForwardIterator<I>, values: std::slice::Iter<'a, T>) -> Self {
+        CubeForwardIndexedIterator{
+            addrs,
+            values,
+        }
+    }
+}
+
+impl <'a, T, I> Iterator for CubeForwardIndexedIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    type Item = (CubeAddress<I>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.addrs.next(), self.values.next()) {
+            (Some(a), Some(v)) => Some((a, v)),
+            _ => None,
+        }
+    }
+}
+
+/// HyperCubeForwardIterator returns the available addresses in a hypercube
+/// in HyperCubeAddress order, starting at the origin (0, 0, 0, 0).
+pub struct HyperCubeForwardIterator<I>
+    where I: Coordinate
+{
+    end_exclusive: HyperCubeAddress<I>,
+    cursor: Option<HyperCubeAddress<I>>
+}
+
+impl <I> HyperCubeForwardIterator<I>
+    where I: Coordinate {
+    pub(crate) fn new(end_exclusive: HyperCubeAddress<I>) -> Self {
+        let zero = I::default();
+        let empty = end_exclusive.x == zero || end_exclusive.y == zero
+            || end_exclusive.z == zero || end_exclusive.w == zero;
+        HyperCubeForwardIterator{
+            end_exclusive,
+            cursor: if empty { None } else { Some(HyperCubeAddress::default()) },
+        }
+    }
+}
+
+impl <I> Iterator for HyperCubeForwardIterator<I>
+    where I: Coordinate {
+    type Item = HyperCubeAddress<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.cursor?;
+        let mut v = result;
+        v.x = v.x + I::unit();
+        if v.x == self.end_exclusive.x {
+            v.x = I::default();
+            v.y = v.y + I::unit();
+            if v.y == self.end_exclusive.y {
+                v.y = I::default();
+                v.z = v.z + I::unit();
+                if v.z == self.end_exclusive.z {
+                    v.z = I::default();
+                    v.w = v.w + I::unit();
+                }
+            }
+        }
+        self.cursor = if v.w == self.end_exclusive.w { None } else { Some(v) };
+        Some(result)
+    }
+}
+
+/// HyperCubeForwardIndexedIterator returns (address, value) tuples from a
+/// hypercube in the same order as HyperCubeForwardIterator.
+pub struct HyperCubeForwardIndexedIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    addrs: HyperCubeForwardIterator<I>,
+    values: std::slice::Iter<'a, T>,
+}
+
+impl <'a, T, I> HyperCubeForwardIndexedIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// values must yield the hypercube cells in the same order as addrs.
+    pub(crate) fn new(addrs: HyperCubeForwardIterator<I>, values: std::slice::Iter<'a, T>) -> Self {
+        HyperCubeForwardIndexedIterator{
+            addrs,
+            values,
+        }
+    }
+}
+
+impl <'a, T, I> Iterator for HyperCubeForwardIndexedIterator<'a, T, I>
+where
+    I: Coordinate,
+{
+    type Item = (HyperCubeAddress<I>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.addrs.next(), self.values.next()) {
+            (Some(a), Some(v)) => Some((a, v)),
+            _ => None,
+        }
+    }
+}
+
+/// TensorForwardIterator returns the available addresses in a D dimensional
+/// tensor in NdAddress order, with dimension 0 varying fastest, starting at
+/// the origin.
+pub struct TensorForwardIterator<I, const D: usize>
+    where I: Coordinate
+{
+    end_exclusive: NdAddress<I, D>,
+    cursor: Option<NdAddress<I, D>>
+}
+
+impl <I, const D: usize> TensorForwardIterator<I, D>
+    where I: Coordinate {
+    pub(crate) fn new(end_exclusive: NdAddress<I, D>) -> Self {
+        let empty = D == 0 || end_exclusive.coordinates.iter().any(|c| *c == I::default());
+        TensorForwardIterator{
+            end_exclusive,
+            cursor: if empty { None } else { Some(NdAddress::default()) },
+        }
+    }
+}
+
+impl <I, const D: usize> Iterator for TensorForwardIterator<I, D>
+    where I: Coordinate {
+    type Item = NdAddress<I, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.cursor?;
+        let mut v = result;
+        self.cursor = None;
+        for d in 0..D {
+            v[d] = v[d] + I::unit();
+            if v[d] != self.end_exclusive[d] {
+                self.cursor = Some(v);
+                break;
+            }
+            v[d] = I::default();
+        }
+        Some(result)
+    }
+}
+
+/// TensorForwardIndexedIterator returns (address, value) tuples from a D
+/// dimensional tensor in the same order as TensorForwardIterator.
+pub struct TensorForwardIndexedIterator<'a, T, I, const D: usize>
+where
+    I: Coordinate,
+{
+    addrs: TensorForwardIterator<I, D>,
+    values: std::slice::Iter<'a, T>,
+}
+
+impl <'a, T, I, const D: usize> TensorForwardIndexedIterator<'a, T, I, D>
+where
+    I: Coordinate,
+{
+    /// values must yield the tensor cells in the same order as addrs.
+    pub(crate) fn new(addrs: TensorForwardIterator<I, D>, values: std::slice::Iter<'a, T>) -> Self {
+        TensorForwardIndexedIterator{
+            addrs,
+            values,
+        }
+    }
+}
+
+impl <'a, T, I, const D: usize> Iterator for TensorForwardIndexedIterator<'a, T, I, D>
+where
+    I: Coordinate,
+{
+    type Item = (NdAddress<I, D>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.addrs.next(), self.values.next()) {
+            (Some(a), Some(v)) => Some((a, v)),
+            _ => None,
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    #[allow(deprecated)]
     use crate::factories::new_default_matrix;
     use crate::format::FormatOptions;
     use super::*;
@@ -468,6 +1004,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn indexed_iterator_as_expected() {
         let opts = FormatOptions{
             row_delimiter: "|".to_string(),
@@ -499,12 +1036,14 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::needless_borrow, deprecated)]
     fn empty_indexed_iterator_as_expected() {
         let matrix = new_default_matrix::<u8, u8>(0, 0).unwrap();
         let mut iter = matrix.indexed_iter();
         assert!((&mut iter).next().is_none());
     }
 
+    #[allow(clippy::extra_unused_lifetimes)]
     fn ascii_parse_opts<'a>() -> FormatOptions {
         FormatOptions{
             row_delimiter: "\n".to_string(),
@@ -656,5 +1195,58 @@ mod tests {
         assert_eq!(values3, vec!["A", "D"]);
         assert!(columns.next().is_none());
     }
-}
 
+    #[test]
+    fn cube_iterator_as_expected() {
+        let values: Vec<[u8; 3]> = CubeForwardIterator::new(CubeAddress { x: 2, y: 1, z: 2 })
+            .map(|a| a.into())
+            .collect();
+        assert_eq!(values, vec![[0, 0, 0], [1, 0, 0], [0, 0, 1], [1, 0, 1]]);
+        assert!(CubeForwardIterator::new(CubeAddress { x: 2u8, y: 0, z: 2 }).next().is_none());
+    }
+
+    #[test]
+    fn row_and_column_iterators_with_addresses() {
+        let opts = ascii_parse_opts();
+        let matrix = opts.parse_matrix::<String, u8>("ABC\nDEF", |x| x.to_string()).unwrap();
+        let row1: Vec<(MatrixAddress<u8>, &String)> = matrix.row(1).unwrap().iter_with_addresses().rev().collect();
+        assert_eq!(row1, vec![
+            (u8addr(1, 2), &"F".to_string()),
+            (u8addr(1, 1), &"E".to_string()),
+            (u8addr(1, 0), &"D".to_string()),
+        ]);
+        let column2: Vec<(MatrixAddress<u8>, &String)> = matrix.column(2).unwrap().iter_with_addresses().collect();
+        assert_eq!(column2, vec![(u8addr(0, 2), &"C".to_string()), (u8addr(1, 2), &"F".to_string())]);
+    }
+
+    #[test]
+    fn row_and_column_range_iterators() {
+        let opts = ascii_parse_opts();
+        let matrix = opts.parse_matrix::<String, u8>("ABCD\nEFGH", |x| x.to_string()).unwrap();
+        let left: Vec<&String> = matrix.row(1).unwrap().iter_range(0, 2).unwrap().rev().collect();
+        assert_eq!(left, vec!["F", "E"]);
+        assert_eq!(matrix.row(0).unwrap().iter_range(2, 2).unwrap().count(), 0);
+        assert_eq!(matrix.row(0).unwrap().iter_range(3, 4).unwrap().collect::<Vec<_>>(), vec!["D"]);
+        assert!(matrix.row(0).unwrap().iter_range(3, 5).is_err());
+        assert!(matrix.row(0).unwrap().iter_range(2, 1).is_err());
+        let below: Vec<&String> = matrix.column(3).unwrap().iter_range(1, 2).unwrap().collect();
+        assert_eq!(below, vec!["H"]);
+        assert_eq!(
+            matrix.column(0).unwrap().iter_range(0, 3).err().unwrap().to_string(),
+            "row range 0..3 is out of bounds for 2 rows"
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn empty_and_single_line_iterators() {
+        let empty = new_default_matrix::<u8, u8>(0, 0).unwrap();
+        assert!(empty.rows().next().is_none());
+        assert!(empty.columns().next_back().is_none());
+        let single = new_default_matrix::<u8, u8>(3, 1).unwrap();
+        assert_eq!(single.rows().count(), 1);
+        assert_eq!(single.columns().rev().count(), 3);
+        assert_eq!(single.column(2).unwrap().iter().rev().count(), 1);
+        assert_eq!(single.row(0).unwrap().iter().count(), 3);
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::Range;
+use crate::{Coordinate, DenseMatrix, Matrix, MatrixAddress, MatrixForwardIndexedIterMut, MatrixMut, Tensor};
+use crate::column::Column;
+use crate::error::Result;
+use crate::row::Row;
+
+/// LabeledMatrix pairs a DenseMatrix with a label for each row and column, so
+/// tables keyed by names, such as distances between locations, can be looked up
+/// by label rather than through parallel maps.  Build one with
+/// new_labeled_matrix.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LabeledMatrix<T, I, L>
+where
+    I: Coordinate,
+{
+    pub(crate) matrix: DenseMatrix<T, I>,
+    pub(crate) row_labels: Vec<L>,
+    pub(crate) column_labels: Vec<L>,
+}
+
+impl <T, I, L> LabeledMatrix<T, I, L>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// matrix returns the unlabeled matrix.
+    pub fn matrix(&self) -> &DenseMatrix<T, I> {
+        &self.matrix
+    }
+
+    /// get_mut returns the cell at address for modification, or None if it is
+    /// out of bounds.
+    pub fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        self.matrix.get_mut(address)
+    }
+
+    /// iter_mut returns each cell's address and value in row-major order, for
+    /// modification.
+    pub fn iter_mut(&mut self) -> MatrixForwardIndexedIterMut<'_, T, I> {
+        self.matrix.indexed_iter_mut()
+    }
+
+    /// row_labels returns the label of each row, in order.
+    pub fn row_labels(&self) -> &[L] {
+        &self.row_labels
+    }
+
+    /// column_labels returns the label of each column, in order.
+    pub fn column_labels(&self) -> &[L] {
+        &self.column_labels
+    }
+
+    /// into_matrix discards the labels, returning the matrix.
+    pub fn into_matrix(self) -> DenseMatrix<T, I> {
+        self.matrix
+    }
+}
+
+impl <T, I, L> LabeledMatrix<T, I, L>
+where
+    T: 'static,
+    I: Coordinate,
+    L: PartialEq,
+{
+    /// row_index returns the index of the first row labeled label.
+    pub fn row_index(&self, label: &L) -> Option<I> {
+        position(&self.row_labels, label)
+    }
+
+    /// column_index returns the index of the first column labeled label.
+    pub fn column_index(&self, label: &L) -> Option<I> {
+        position(&self.column_labels, label)
+    }
+
+    /// row_by_label returns the first row labeled label.
+    pub fn row_by_label(&self, label: &L) -> Option<Row<'_, T, I>> {
+        self.matrix.row(self.row_index(label)?)
+    }
+
+    /// column_by_label returns the first column labeled label.
+    pub fn column_by_label(&self, label: &L) -> Option<Column<'_, T, I>> {
+        self.matrix.column(self.column_index(label)?)
+    }
+
+    /// get_by_labels returns the cell at the given row and column labels.
+    pub fn get_by_labels(&self, row: &L, column: &L) -> Option<&T> {
+        self.matrix.get(MatrixAddress { row: self.row_index(row)?, column: self.column_index(column)? })
+    }
+
+    /// get_by_labels_mut returns the cell at the given row and column labels
+    /// for modification.
+    pub fn get_by_labels_mut(&mut self, row: &L, column: &L) -> Option<&mut T> {
+        let address = MatrixAddress { row: self.row_index(row)?, column: self.column_index(column)? };
+        self.matrix.get_mut(address)
+    }
+}
+
+impl <T, I, L> LabeledMatrix<T, I, L>
+where
+    T: Clone + 'static,
+    I: Coordinate,
+    L: Clone,
+{
+    /// transposed returns a copy with rows and columns swapped, along with
+    /// their labels.
+    pub fn transposed(&self) -> LabeledMatrix<T, I, L> {
+        LabeledMatrix {
+            matrix: self.matrix.transposed(),
+            row_labels: self.column_labels.clone(),
+            column_labels: self.row_labels.clone(),
+        }
+    }
+
+    /// cropped returns a copy of a non-empty, in-bounds range of cells, keeping
+    /// the labels of the rows and columns in range.
+    pub fn cropped(&self, range: Range<MatrixAddress<I>>) -> Result<LabeledMatrix<T, I, L>> {
+        self.matrix.check_window(&range)?;
+        let rows = to_usize(range.start.row)..to_usize(range.end.row);
+        let columns = to_usize(range.start.column)..to_usize(range.end.column);
+        Ok(LabeledMatrix {
+            matrix: self.matrix.copy_window(range),
+            row_labels: self.row_labels[rows].to_vec(),
+            column_labels: self.column_labels[columns].to_vec(),
+        })
+    }
+}
+
+fn position<I: Coordinate, L: PartialEq>(labels: &[L], label: &L) -> Option<I> {
+    labels.iter().position(|l| l == label)?.try_into().ok()
+}
+
+fn to_usize<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("index overflows usize.  This should be unreachable."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_labeled_matrix, new_matrix};
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn distances() -> LabeledMatrix<u32, u8, &'static str> {
+        let matrix = new_matrix::<u32, u8>(3, vec![0, 5, 9, 5, 0, 4, 9, 4, 0]).unwrap();
+        let names = vec!["home", "shop", "park"];
+        new_labeled_matrix(matrix, names.clone(), names).unwrap()
+    }
+
+    #[test]
+    fn lookup_by_label() {
+        let mut m = distances();
+        assert_eq!(m.get_by_labels(&"shop", &"park"), Some(&4));
+        assert_eq!(m.get_by_labels(&"shop", &"school"), None);
+        assert_eq!(m.row_index(&"park"), Some(2));
+        let shop: Vec<u32> = m.row_by_label(&"shop").unwrap().iter().copied().collect();
+        assert_eq!(shop, vec![5, 0, 4]);
+        let park: Vec<u32> = m.column_by_label(&"park").unwrap().iter().copied().collect();
+        assert_eq!(park, vec![9, 4, 0]);
+        *m.get_by_labels_mut(&"home", &"park").unwrap() = 8;
+        assert_eq!(m.matrix()[MatrixAddress { row: 0, column: 2 }], 8);
+        *m.get_mut(MatrixAddress { row: 2, column: 0 }).unwrap() = 7;
+        assert_eq!(m.get_by_labels(&"park", &"home"), Some(&7));
+        assert!(m.get_mut(MatrixAddress { row: 3, column: 0 }).is_none());
+        for (address, value) in m.iter_mut() {
+            if address.row == address.column {
+                *value = 1;
+            }
+        }
+        assert_eq!(m.get_by_labels(&"shop", &"shop"), Some(&1));
+        assert_eq!(m.matrix().iter().copied().collect::<Vec<u32>>(), vec![1, 5, 8, 5, 1, 4, 7, 4, 1]);
+        assert!(new_labeled_matrix(new_matrix::<u32, u8>(1, vec![1, 2]).unwrap(), vec!["a", "b"], vec!["c", "d"]).is_err());
+    }
+
+    #[test]
+    fn transpose_and_crop_keep_labels() {
+        let matrix = new_matrix::<u32, u8>(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let m = new_labeled_matrix(matrix, vec!["r0", "r1"], vec!["c0", "c1", "c2"]).unwrap();
+        let t = m.transposed();
+        assert_eq!(t.row_labels(), &["c0", "c1", "c2"]);
+        assert_eq!(t.get_by_labels(&"c2", &"r0"), Some(&3));
+        let cropped = m.cropped(MatrixAddress { row: 0, column: 1 }..MatrixAddress { row: 2, column: 3 }).unwrap();
+        assert_eq!(cropped.column_labels(), &["c1", "c2"]);
+        assert_eq!(cropped.get_by_labels(&"r1", &"c1"), Some(&5));
+        assert!(m.cropped(MatrixAddress { row: 0, column: 0 }..MatrixAddress { row: 3, column: 1 }).is_err());
+        let opts = FormatOptions { column_delimiter: " ".to_string(), row_delimiter: "\n".to_string() };
+        assert_eq!(
+            opts.format_labeled(&cropped, |l| l.to_string(), |v| v.to_string()),
+            "   c1 c2\nr0  2  3\nr1  5  6"
+        );
+    }
+}
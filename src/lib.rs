@@ -16,13 +16,80 @@ mod column;
 mod format;
 mod factories;
 mod transpose;
+mod growable_grid;
+mod flood_fill;
+mod adjacency;
+mod overlay;
+mod sparse_matrix;
+mod bit_matrix;
+mod transform;
+mod cube;
+mod cube_address;
+mod hypercube;
+mod hypercube_address;
+mod nd_address;
+mod nd_tensor;
+mod sub_matrix;
+mod shape;
+mod interned_matrix;
+mod fn_matrix;
+pub mod testing;
+mod grid_walker;
+mod compression;
+mod pathfinding;
+mod serialization;
+mod semiring;
+mod matrix_history;
+mod neighbor_count;
+mod convolution;
+mod scan;
+mod gf2;
+mod labeled_matrix;
+mod held_karp;
+mod assignment;
+mod visibility;
+mod attributed_matrix;
+mod tiles;
+#[cfg(feature = "render")]
+mod render;
 
+pub use bit_matrix::*;
+pub use adjacency::*;
+pub use attributed_matrix::*;
 pub use column::*;
+pub use compression::*;
+pub use convolution::*;
+pub use cube::*;
+pub use cube_address::*;
 pub use dense_matrix::*;
 pub use error::*;
 pub use factories::*;
+pub use fn_matrix::*;
 pub use format::*;
+pub use gf2::*;
+pub use grid_walker::*;
+pub use growable_grid::*;
+pub use held_karp::*;
+pub use hypercube::*;
+pub use hypercube_address::*;
+pub use interned_matrix::*;
 pub use iter::*;
+pub use labeled_matrix::*;
 pub use matrix_address::*;
+pub use matrix_history::*;
+pub use nd_address::*;
+pub use nd_tensor::*;
+pub use overlay::*;
+pub use pathfinding::*;
+#[cfg(feature = "render")]
+pub use render::*;
 pub use row::*;
+pub use scan::*;
+pub use semiring::*;
+pub use serialization::*;
+pub use shape::*;
+pub use sparse_matrix::*;
+pub use sub_matrix::*;
+pub use tiles::*;
+pub use transform::*;
 pub use traits::*;
@@ -1,7 +1,7 @@
 // Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
 
 use crate::LogicalDimension::{Column, Row};
-use crate::traits::{Address, Coordinate, Dimension};
+use crate::traits::{Address, CheckedAdd, CheckedSub, Coordinate, Dimension};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::{Add, Index, Sub};
 use crate::Matrix;
@@ -32,40 +32,318 @@ where
       T: 'static,
       I: Coordinate
     {
-        let ione = I::unit();
-        let izero = ione - ione;
-        let mut neighbors = Vec::new();
-        if self.column > izero {
-            if self.row > izero {
-                neighbors.push(MatrixAddress { column: self.column - ione, row: self.row - ione});
-            }
-            neighbors.push(MatrixAddress { column: self.column - ione, row: self.row });
-            if self.row < matrix.row_count() - ione {
-                neighbors.push(MatrixAddress { column: self.column - ione, row: self.row + ione});
-            }
+        self.neighbors_with(matrix, NeighborKind::All8)
+    }
+
+    /// neighbors_with returns the in-bounds neighbors of the given kind, sorted.
+    /// An address outside the matrix has no neighbors.
+    pub fn neighbors_with<'a, T>(&self, matrix: &dyn Matrix<'a, T, I>, kind: NeighborKind) -> Vec<MatrixAddress<I>>
+    where
+        T: 'static,
+    {
+        let end = matrix.range().end;
+        kind.offsets().iter()
+            .filter_map(|offset| self.offset_within(*offset, end))
+            .collect()
+    }
+
+    // transpose reverses the row and column of the address.
+    pub fn transpose(&self) -> MatrixAddress<I> {
+        MatrixAddress { row: self.column, column: self.row }
+    }
+
+    /// wrapping_neighbors returns the neighbors of the given kind on a torus:
+    /// stepping off one edge of matrix re-enters on the opposite edge, so the
+    /// left neighbor of column 0 is the last column.  Neighbors are listed in
+    /// row-major order relative to the address; in matrices narrower or shorter
+    /// than three cells the same address can appear more than once.  An address
+    /// outside the matrix has no neighbors.
+    pub fn wrapping_neighbors<'a, T>(&self, matrix: &dyn Matrix<'a, T, I>, kind: NeighborKind) -> Vec<MatrixAddress<I>>
+    where
+        T: 'static,
+    {
+        let end = matrix.range().end;
+        let izero = I::default();
+        if self.row < izero || self.column < izero || self.row >= end.row || self.column >= end.column {
+            return Vec::new();
         }
-        if self.row > izero {
-            neighbors.push(MatrixAddress { column: self.column, row: self.row - ione});
+        let wrap = |v: I, delta: i8, count: I| match delta {
+            -1 if v == izero => count - I::unit(),
+            -1 => v - I::unit(),
+            1 if v == count - I::unit() => izero,
+            1 => v + I::unit(),
+            _ => v,
+        };
+        kind.offsets().iter()
+            .map(|(row_delta, column_delta)| MatrixAddress {
+                row: wrap(self.row, *row_delta, end.row),
+                column: wrap(self.column, *column_delta, end.column),
+            })
+            .collect()
+    }
+
+    // offset_within applies a single-cell (row, column) offset, returning None
+    // if either the address or the result falls outside a matrix whose exclusive
+    // upper bound is end.
+    pub(crate) fn offset_within(&self, (row_delta, column_delta): (i8, i8), end: MatrixAddress<I>) -> Option<MatrixAddress<I>> {
+        let izero = I::default();
+        if self.row < izero || self.column < izero || self.row >= end.row || self.column >= end.column {
+            return None;
         }
-        if self.row < matrix.row_count() - ione {
-            neighbors.push(MatrixAddress { column: self.column, row: self.row + ione});
+        let step = |v: I, delta: i8, count: I| match delta {
+            -1 if v > izero => Some(v - I::unit()),
+            1 if v < count - I::unit() => Some(v + I::unit()),
+            0 => Some(v),
+            _ => None,
+        };
+        Some(MatrixAddress {
+            row: step(self.row, row_delta, end.row)?,
+            column: step(self.column, column_delta, end.column)?,
+        })
+    }
+
+    /// checked_add adds delta to the address, returning None if either component
+    /// overflows the index type.
+    pub fn checked_add(&self, delta: MatrixAddress<I>) -> Option<MatrixAddress<I>>
+    where
+        I: CheckedAdd,
+    {
+        Some(MatrixAddress {
+            row: self.row.checked_addition(delta.row)?,
+            column: self.column.checked_addition(delta.column)?,
+        })
+    }
+
+    /// checked_sub subtracts delta from the address, returning None if either
+    /// component overflows the index type, including unsigned underflow.
+    pub fn checked_sub(&self, delta: MatrixAddress<I>) -> Option<MatrixAddress<I>>
+    where
+        I: CheckedSub,
+    {
+        Some(MatrixAddress {
+            row: self.row.checked_subtraction(delta.row)?,
+            column: self.column.checked_subtraction(delta.column)?,
+        })
+    }
+
+    /// bounded_add adds delta to the address, returning None if the sum
+    /// overflows the index type or falls outside matrix.
+    pub fn bounded_add<'a, T>(&self, delta: MatrixAddress<I>, matrix: &dyn Matrix<'a, T, I>) -> Option<MatrixAddress<I>>
+    where
+        T: 'static,
+        I: CheckedAdd,
+    {
+        let sum = self.checked_add(delta)?;
+        if matrix.contains(sum) {
+            Some(sum)
+        } else {
+            None
+        }
+    }
+
+    /// manhattan_distance returns the number of orthogonal steps between the
+    /// addresses: the sum of their row and column differences.  None is
+    /// returned if the distance overflows usize.
+    pub fn manhattan_distance(&self, other: MatrixAddress<I>) -> Option<usize> {
+        abs_diff(self.row, other.row)?.checked_add(abs_diff(self.column, other.column)?)
+    }
+
+    /// chebyshev_distance returns the number of king moves between the
+    /// addresses: the larger of their row and column differences.  None is
+    /// returned if a difference overflows usize.
+    pub fn chebyshev_distance(&self, other: MatrixAddress<I>) -> Option<usize> {
+        Some(abs_diff(self.row, other.row)?.max(abs_diff(self.column, other.column)?))
+    }
+
+    /// euclidean_distance_squared returns the square of the straight line
+    /// distance between the addresses, which orders like the distance itself
+    /// without leaving the integers.  None is returned if the result overflows
+    /// usize.
+    pub fn euclidean_distance_squared(&self, other: MatrixAddress<I>) -> Option<usize> {
+        let (rows, columns) = (abs_diff(self.row, other.row)?, abs_diff(self.column, other.column)?);
+        rows.checked_mul(rows)?.checked_add(columns.checked_mul(columns)?)
+    }
+
+    /// step returns the adjacent address in direction.  Like Add, the result is
+    /// not bounds checked, and stepping below zero on an unsigned index type
+    /// overflows; use step_checked to stay within a matrix.
+    pub fn step(&self, direction: Direction) -> MatrixAddress<I> {
+        let apply = |v: I, delta: i8| match delta {
+            -1 => v - I::unit(),
+            1 => v + I::unit(),
+            _ => v,
+        };
+        let (row_delta, column_delta) = direction.offsets();
+        MatrixAddress {
+            row: apply(self.row, row_delta),
+            column: apply(self.column, column_delta),
         }
-        if self.column < matrix.column_count() - ione {
-            if self.row > izero {
-                neighbors.push(MatrixAddress { column: self.column + ione, row: self.row - ione });
-            }
-            neighbors.push(MatrixAddress { column: self.column + ione, row: self.row });
-            if self.row < matrix.row_count() - ione {
-                neighbors.push(MatrixAddress { column: self.column + ione, row: self.row + ione});
-            }
+    }
+
+    /// step_checked returns the adjacent address in direction, or None if
+    /// either this address or the result is outside matrix.
+    pub fn step_checked<'a, T>(&self, direction: Direction, matrix: &dyn Matrix<'a, T, I>) -> Option<MatrixAddress<I>>
+    where
+        T: 'static,
+    {
+        self.offset_within(direction.offsets(), matrix.range().end)
+    }
+
+    /// clamped returns the in-bounds address of matrix nearest to this one.
+    /// None is returned only if the matrix has no cells.
+    pub fn clamped<'a, T>(&self, matrix: &dyn Matrix<'a, T, I>) -> Option<MatrixAddress<I>>
+    where
+        T: 'static,
+    {
+        let izero = I::default();
+        if matrix.row_count() <= izero || matrix.column_count() <= izero {
+            return None;
         }
-        neighbors.sort();
-        neighbors
+        let clamp = |v: I, count: I| if v < izero {
+            izero
+        } else if v >= count {
+            count - I::unit()
+        } else {
+            v
+        };
+        Some(MatrixAddress {
+            row: clamp(self.row, matrix.row_count()),
+            column: clamp(self.column, matrix.column_count()),
+        })
     }
 
-    // transpose reverses the row and column of the address.
-    pub fn transpose(&self) -> MatrixAddress<I> {
-        MatrixAddress { row: self.column, column: self.row }
+    /// step_clamped moves one cell in direction, clamping the result to the
+    /// bounds of matrix, so a step off an edge leaves the address on that edge.
+    /// None is returned only if the matrix has no cells.
+    pub fn step_clamped<'a, T>(&self, direction: Direction, matrix: &dyn Matrix<'a, T, I>) -> Option<MatrixAddress<I>>
+    where
+        T: 'static,
+    {
+        let start = self.clamped(matrix)?;
+        let step = |v: I, delta: i8, count: I| match delta {
+            -1 if v > I::default() => v - I::unit(),
+            1 if v < count - I::unit() => v + I::unit(),
+            _ => v,
+        };
+        let (row_delta, column_delta) = direction.offsets();
+        Some(MatrixAddress {
+            row: step(start.row, row_delta, matrix.row_count()),
+            column: step(start.column, column_delta, matrix.column_count()),
+        })
+    }
+}
+
+/// abs_diff returns |a - b| without overflowing, even for unsigned coordinates
+/// and for signed coordinates spanning more than the positive range of I.
+fn abs_diff<I>(a: I, b: I) -> Option<usize>
+where
+    I: Coordinate,
+{
+    let (high, low) = if a >= b { (a, b) } else { (b, a) };
+    let to_usize = |v: I| -> Option<usize> { v.try_into().ok() };
+    if low >= I::default() || high < I::default() {
+        to_usize(high - low)
+    } else {
+        // Only a signed span across zero can overflow, so split it there.
+        to_usize(high)?.checked_add(to_usize(I::default() - (low + I::unit()))?)?.checked_add(1)
+    }
+}
+
+/// min_by_reading_order returns the first of addrs in reading order (top to
+/// bottom, then left to right), for deterministic tie-breaking.  None is
+/// returned if addrs is empty.
+pub fn min_by_reading_order<I>(addrs: impl IntoIterator<Item = MatrixAddress<I>>) -> Option<MatrixAddress<I>>
+where
+    I: Coordinate,
+{
+    // MatrixAddress orders by row, then column, which is reading order.
+    addrs.into_iter().min()
+}
+
+/// NeighborKind selects which adjacent cells count as neighbors: the four
+/// sharing an edge, the four sharing only a corner, or all eight.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum NeighborKind {
+    Orthogonal4,
+    Diagonal4,
+    All8,
+}
+
+impl NeighborKind {
+    // offsets returns the (row, column) offsets of the neighbors, in row-major
+    // order so that the resulting addresses are sorted.
+    pub(crate) fn offsets(self) -> &'static [(i8, i8)] {
+        match self {
+            NeighborKind::Orthogonal4 => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            NeighborKind::Diagonal4 => &[(-1, -1), (-1, 1), (1, -1), (1, 1)],
+            NeighborKind::All8 => &[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)],
+        }
+    }
+}
+
+/// Direction is a compass direction on a matrix.  North is toward row zero and
+/// West is toward column zero.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// ALL lists every direction, clockwise from North.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// CARDINAL lists the four orthogonal directions, clockwise from North.
+    pub const CARDINAL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// turn_left returns the direction 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    /// turn_right returns the direction 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// reverse returns the opposite direction.
+    pub fn reverse(self) -> Direction {
+        self.rotate(4)
+    }
+
+    // rotate turns clockwise by eighths of a full turn.
+    fn rotate(self, eighths: usize) -> Direction {
+        let position = Direction::ALL.iter().position(|d| *d == self).unwrap_or_default();
+        Direction::ALL[(position + eighths) % 8]
+    }
+
+    // offsets returns the change in (row, column) of a single step.
+    pub(crate) fn offsets(self) -> (i8, i8) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
     }
 }
 
@@ -161,6 +439,18 @@ where
     }
 }
 
+impl<I> Add<Direction> for MatrixAddress<I>
+where
+    I: Coordinate,
+{
+    type Output = MatrixAddress<I>;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        // Warning: result can be out of bounds
+        self.step(rhs)
+    }
+}
+
 impl<I> Sub for MatrixAddress<I>
 where
     I: Coordinate,
@@ -190,6 +480,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    #[allow(deprecated)]
     use crate::factories::new_default_matrix;
     use super::*;
 
@@ -279,6 +570,153 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
+    fn test_clamped() {
+        let m = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        assert_eq!(u8addr(1, 1).clamped(&m), Some(u8addr(1, 1)));
+        assert_eq!(u8addr(7, 9).clamped(&m), Some(u8addr(1, 2)));
+        let signed = new_default_matrix::<u8, i8>(3, 2).unwrap();
+        assert_eq!(MatrixAddress { row: -4, column: 5 }.clamped(&signed), Some(MatrixAddress { row: 0, column: 2 }));
+        let empty = new_default_matrix::<u8, u8>(0, 0).unwrap();
+        assert_eq!(u8addr(0, 0).clamped(&empty), None);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_step_clamped() {
+        let m = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        assert_eq!(u8addr(0, 0).step_clamped(Direction::North, &m), Some(u8addr(0, 0)));
+        assert_eq!(u8addr(0, 0).step_clamped(Direction::SouthEast, &m), Some(u8addr(1, 1)));
+        assert_eq!(u8addr(1, 2).step_clamped(Direction::NorthEast, &m), Some(u8addr(0, 2)));
+        assert_eq!(u8addr(1, 0).step_clamped(Direction::SouthWest, &m), Some(u8addr(1, 0)));
+        assert_eq!(u8addr(9, 9).step_clamped(Direction::West, &m), Some(u8addr(1, 1)));
+        let max = MatrixAddress { row: u8::MAX, column: u8::MAX };
+        assert_eq!(max.step_clamped(Direction::East, &m), Some(u8addr(1, 2)));
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_neighbors_with() {
+        let m = new_default_matrix::<u8, u8>(3, 3).unwrap();
+        assert_eq!(u8addr(0, 1).neighbors_with(&m, NeighborKind::Orthogonal4), vec![u8addr(0, 0), u8addr(0, 2), u8addr(1, 1)]);
+        assert_eq!(u8addr(1, 1).neighbors_with(&m, NeighborKind::Diagonal4), vec![u8addr(0, 0), u8addr(0, 2), u8addr(2, 0), u8addr(2, 2)]);
+        assert_eq!(u8addr(2, 2).neighbors_with(&m, NeighborKind::Diagonal4), vec![u8addr(1, 1)]);
+        assert_eq!(u8addr(1, 1).neighbors_with(&m, NeighborKind::All8).len(), 8);
+        assert!(u8addr(3, 0).neighbors_with(&m, NeighborKind::All8).is_empty());
+        let empty = new_default_matrix::<u8, u8>(0, 0).unwrap();
+        assert!(u8addr(0, 0).neighbors(&empty).is_empty());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_reading_order() {
+        let m = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        let forward: Vec<MatrixAddress<u8>> = m.reading_order().collect();
+        let mut reverse: Vec<MatrixAddress<u8>> = m.reverse_reading_order().collect();
+        assert_eq!(forward.len(), 6);
+        assert!(forward.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(reverse[0], u8addr(1, 2));
+        reverse.reverse();
+        assert_eq!(forward, reverse);
+        let empty = new_default_matrix::<u8, u8>(0, 0).unwrap();
+        assert!(empty.reverse_reading_order().next().is_none());
+        assert_eq!(min_by_reading_order(vec![u8addr(2, 0), u8addr(1, 5), u8addr(1, 3)]), Some(u8addr(1, 3)));
+        assert_eq!(min_by_reading_order(Vec::<MatrixAddress<u8>>::new()), None);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_wrapping_neighbors() {
+        let m = new_default_matrix::<u8, u8>(4, 3).unwrap();
+        assert_eq!(
+            u8addr(0, 0).wrapping_neighbors(&m, NeighborKind::Orthogonal4),
+            vec![u8addr(2, 0), u8addr(0, 3), u8addr(0, 1), u8addr(1, 0)]
+        );
+        assert_eq!(
+            u8addr(2, 3).wrapping_neighbors(&m, NeighborKind::Diagonal4),
+            vec![u8addr(1, 2), u8addr(1, 0), u8addr(0, 2), u8addr(0, 0)]
+        );
+        assert_eq!(u8addr(1, 1).wrapping_neighbors(&m, NeighborKind::All8), u8addr(1, 1).neighbors(&m));
+        let narrow = new_default_matrix::<u8, u8>(1, 3).unwrap();
+        assert_eq!(
+            u8addr(1, 0).wrapping_neighbors(&narrow, NeighborKind::Orthogonal4),
+            vec![u8addr(0, 0), u8addr(1, 0), u8addr(1, 0), u8addr(2, 0)]
+        );
+        assert!(u8addr(3, 0).wrapping_neighbors(&m, NeighborKind::All8).is_empty());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_direction_steps() {
+        let m = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        assert_eq!(u8addr(1, 1).step(Direction::NorthWest), u8addr(0, 0));
+        assert_eq!(u8addr(1, 1) + Direction::SouthEast, u8addr(2, 2));
+        assert_eq!(u8addr(0, 0).step_checked(Direction::East, &m), Some(u8addr(0, 1)));
+        assert_eq!(u8addr(0, 0).step_checked(Direction::North, &m), None);
+        assert_eq!(u8addr(1, 2).step_checked(Direction::East, &m), None);
+        assert_eq!(u8addr(1, 2).step_checked(Direction::SouthWest, &m), None);
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthEast.turn_left(), Direction::NorthWest);
+        assert_eq!(Direction::SouthWest.reverse(), Direction::NorthEast);
+        for d in Direction::ALL {
+            assert_eq!(u8addr(5, 5).step(d).step(d.reverse()), u8addr(5, 5));
+            assert_eq!(d.turn_left().turn_right(), d);
+        }
+        let path: Vec<Direction> = Direction::CARDINAL.iter().map(|d| d.turn_right()).collect();
+        assert_eq!(path, vec![Direction::East, Direction::South, Direction::West, Direction::North]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_checked_arithmetic() {
+        assert_eq!(u8addr(1, 250).checked_add(u8addr(2, 5)), Some(u8addr(3, 255)));
+        assert_eq!(u8addr(1, 251).checked_add(u8addr(2, 5)), None);
+        assert_eq!(u8addr(3, 4).checked_sub(u8addr(1, 4)), Some(u8addr(2, 0)));
+        assert_eq!(u8addr(3, 4).checked_sub(u8addr(4, 0)), None);
+        let m = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        assert_eq!(u8addr(0, 1).bounded_add(u8addr(1, 1), &m), Some(u8addr(1, 2)));
+        assert_eq!(u8addr(0, 1).bounded_add(u8addr(2, 0), &m), None);
+        assert_eq!(u8addr(0, 1).bounded_add(u8addr(0, 255), &m), None);
+        let signed = new_default_matrix::<u8, i8>(3, 2).unwrap();
+        let back = MatrixAddress { row: -1, column: 0 };
+        assert_eq!(MatrixAddress { row: 1i8, column: 1 }.bounded_add(back, &signed), Some(MatrixAddress { row: 0, column: 1 }));
+        assert_eq!(MatrixAddress { row: 0i8, column: 1 }.bounded_add(back, &signed), None);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = u8addr(1, 7);
+        let b = u8addr(4, 3);
+        assert_eq!(a.manhattan_distance(b), Some(7));
+        assert_eq!(b.manhattan_distance(a), Some(7));
+        assert_eq!(a.chebyshev_distance(b), Some(4));
+        assert_eq!(a.euclidean_distance_squared(b), Some(25));
+        assert_eq!(a.manhattan_distance(a), Some(0));
+        let far = MatrixAddress { row: i8::MIN, column: -1 };
+        let near = MatrixAddress { row: i8::MAX, column: 2 };
+        assert_eq!(far.manhattan_distance(near), Some(255 + 3));
+        assert_eq!(near.chebyshev_distance(far), Some(255));
+    }
+
+    #[test]
+    fn distances_overflowing_usize() {
+        let origin = MatrixAddress { row: 0u64, column: 0 };
+        let corner = MatrixAddress { row: u64::MAX, column: u64::MAX };
+        assert_eq!(origin.manhattan_distance(corner), None);
+        assert_eq!(origin.euclidean_distance_squared(corner), None);
+        assert_eq!(origin.chebyshev_distance(corner), usize::try_from(u64::MAX).ok());
+        let edge = MatrixAddress { row: 0u64, column: 1u64 << 32 };
+        assert_eq!(origin.manhattan_distance(edge), Some(1 << 32));
+        assert_eq!(origin.euclidean_distance_squared(edge), None);
+        let low = MatrixAddress { row: i64::MIN, column: 0 };
+        assert_eq!(low.manhattan_distance(MatrixAddress { row: i64::MAX, column: 0 }), Some(usize::MAX));
+        assert_eq!(low.manhattan_distance(MatrixAddress { row: i64::MAX, column: 1 }), None);
+        assert_eq!(low.chebyshev_distance(MatrixAddress { row: i64::MAX, column: 1 }), usize::try_from(u64::MAX).ok());
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn test_neighbors() {
         let m = new_default_matrix::<u8, u8>(3, 3).unwrap();
         let upper_left = u8addr(0, 0);
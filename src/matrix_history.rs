@@ -0,0 +1,122 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::collections::hash_map::{DefaultHasher, Entry};
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use crate::Coordinate;
+use crate::dense_matrix::DenseMatrix;
+
+/// MatrixHistory records the generations of a simulation.  It keeps the most
+/// recent capacity generations in a ring buffer, and a 64-bit fingerprint of
+/// every generation ever pushed, so the first repeated state is detected even
+/// after the original has left the buffer.  With a capacity of 0 only the
+/// fingerprints are kept.
+///
+/// Because states are compared by fingerprint, a hash collision would report
+/// a false repeat.  At puzzle scales this is vanishingly unlikely.
+#[derive(Clone, Debug)]
+pub struct MatrixHistory<T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) capacity: usize,
+    pub(crate) recent: VecDeque<DenseMatrix<T, I>>,
+    pub(crate) seen: HashMap<u64, usize>,
+    pub(crate) generations: usize,
+    pub(crate) first_repeat: Option<(usize, usize)>,
+}
+
+impl <T, I> MatrixHistory<T, I>
+where
+    T: Hash,
+    I: Coordinate,
+{
+    /// push records matrix as the next generation.  If an identical state was
+    /// pushed before, the generation number at which it was first seen is
+    /// returned.  Generations are numbered from 0.
+    pub fn push(&mut self, matrix: DenseMatrix<T, I>) -> Option<usize> {
+        let generation = self.generations;
+        self.generations += 1;
+        let earlier = match self.seen.entry(fingerprint(&matrix)) {
+            Entry::Occupied(e) => Some(*e.get()),
+            Entry::Vacant(e) => {
+                e.insert(generation);
+                None
+            },
+        };
+        if let Some(earlier) = earlier {
+            self.first_repeat.get_or_insert((earlier, generation));
+        }
+        if self.capacity > 0 {
+            if self.recent.len() == self.capacity {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(matrix);
+        }
+        earlier
+    }
+
+    /// previous returns the generation k steps before the most recent one, so
+    /// previous(0) is the latest generation.  None is returned once k reaches
+    /// past the buffered generations.
+    pub fn previous(&self, k: usize) -> Option<&DenseMatrix<T, I>> {
+        if k >= self.recent.len() {
+            return None;
+        }
+        self.recent.get(self.recent.len() - 1 - k)
+    }
+
+    /// generations returns the number of generations pushed so far.
+    pub fn generations(&self) -> usize {
+        self.generations
+    }
+
+    /// first_repeat returns the generation numbers of the first state seen
+    /// twice: where it first appeared and where it reappeared.  The cycle
+    /// length is their difference.
+    pub fn first_repeat(&self) -> Option<(usize, usize)> {
+        self.first_repeat
+    }
+}
+
+// fingerprint hashes the shape and contents of matrix.
+fn fingerprint<T, I>(matrix: &DenseMatrix<T, I>) -> u64
+where
+    T: Hash,
+    I: Coordinate,
+{
+    let mut hasher = DefaultHasher::new();
+    matrix.rows.hash(&mut hasher);
+    matrix.columns.hash(&mut hasher);
+    matrix.data.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_matrix, new_matrix_history};
+
+    #[test]
+    fn detects_first_repeat() {
+        let mut history = new_matrix_history::<u8, u8>(2);
+        let states = [vec![1, 2], vec![2, 1], vec![3, 3], vec![2, 1], vec![3, 3]];
+        let found: Vec<Option<usize>> = states.iter()
+            .map(|s| history.push(new_matrix(1, s.clone()).unwrap()))
+            .collect();
+        assert_eq!(found, vec![None, None, None, Some(1), Some(2)]);
+        assert_eq!(history.first_repeat(), Some((1, 3)));
+        assert_eq!(history.generations(), 5);
+        assert_eq!(history.previous(0), Some(&new_matrix(1, vec![3, 3]).unwrap()));
+        assert_eq!(history.previous(1), Some(&new_matrix(1, vec![2, 1]).unwrap()));
+        assert_eq!(history.previous(2), None);
+    }
+
+    #[test]
+    fn hash_only_history() {
+        let mut history = new_matrix_history::<u8, u8>(0);
+        assert_eq!(history.push(new_matrix(1, vec![1, 2]).unwrap()), None);
+        assert_eq!(history.push(new_matrix(2, vec![1, 2]).unwrap()), None);
+        assert_eq!(history.push(new_matrix(1, vec![1, 2]).unwrap()), Some(0));
+        assert_eq!(history.previous(0), None);
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::traits::{Address, Coordinate, Dimension};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, Index, IndexMut, Sub};
+
+/// NdAddress references a cell in a D dimensional tensor.  Component 0 is the
+/// fastest varying dimension, like the x of a CubeAddress, so addresses order
+/// lexicographically with the highest dimension most significant.  That is the
+/// order in which TensorForwardIterator visits them.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct NdAddress<I, const D: usize>
+where
+    I: Coordinate,
+{
+    pub coordinates: [I; D],
+}
+
+impl<I, const D: usize> Index<Dimension> for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    type Output = I;
+
+    fn index(&self, index: Dimension) -> &Self::Output {
+        match self.coordinates.get(index) {
+            Some(v) => v,
+            None => panic!("invalid dimension"),
+        }
+    }
+}
+
+impl<I, const D: usize> IndexMut<Dimension> for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    fn index_mut(&mut self, index: Dimension) -> &mut Self::Output {
+        match self.coordinates.get_mut(index) {
+            Some(v) => v,
+            None => panic!("invalid dimension"),
+        }
+    }
+}
+
+impl<I, const D: usize> Address<I, D> for NdAddress<I, D> where I: Coordinate {}
+
+impl<I, const D: usize> Ord for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.coordinates.iter().rev().cmp(other.coordinates.iter().rev())
+    }
+}
+
+impl<I, const D: usize> PartialOrd for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I, const D: usize> Display for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = self.coordinates.iter().map(|c| c.to_string()).collect();
+        f.write_str(&format!("({})", parts.join(",")))
+    }
+}
+
+impl<I, const D: usize> From<[I; D]> for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    fn from(value: [I; D]) -> Self {
+        Self { coordinates: value }
+    }
+}
+
+impl<I, const D: usize> From<NdAddress<I, D>> for [I; D]
+where
+    I: Coordinate,
+{
+    fn from(value: NdAddress<I, D>) -> Self {
+        value.coordinates
+    }
+}
+
+impl<I, const D: usize> Add for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    type Output = NdAddress<I, D>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Warning: result can be out of bounds
+        let mut coordinates = self.coordinates;
+        for (c, r) in coordinates.iter_mut().zip(rhs.coordinates) {
+            *c = *c + r;
+        }
+        NdAddress { coordinates }
+    }
+}
+
+impl<I, const D: usize> Sub for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    type Output = NdAddress<I, D>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        // Warning: result can be out of bounds
+        let mut coordinates = self.coordinates;
+        for (c, r) in coordinates.iter_mut().zip(rhs.coordinates) {
+            *c = *c - r;
+        }
+        NdAddress { coordinates }
+    }
+}
+
+impl<I, const D: usize> Default for NdAddress<I, D>
+where
+    I: Coordinate,
+{
+    fn default() -> Self {
+        NdAddress { coordinates: [I::default(); D] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_and_arithmetic() {
+        let a = NdAddress::from([1u8, 2, 3, 4, 5]);
+        assert_eq!(a.to_string(), "(1,2,3,4,5)");
+        assert_eq!(a[4], 5);
+        let b = a + NdAddress::from([1, 1, 1, 1, 1]) - NdAddress::from([2, 0, 0, 0, 0]);
+        let rep: [u8; 5] = b.into();
+        assert_eq!(rep, [0, 3, 4, 5, 6]);
+        assert_eq!(NdAddress::<u8, 5>::default().coordinates, [0; 5]);
+    }
+
+    #[test]
+    fn test_highest_dimension_is_most_significant() {
+        assert!(NdAddress::from([9u8, 0, 0]) < NdAddress::from([0, 1, 0]));
+        assert!(NdAddress::from([9u8, 9, 0]) < NdAddress::from([0, 0, 1]));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Index, IndexMut, Range};
+use crate::iter::{TensorForwardIndexedIterator, TensorForwardIterator};
+use crate::nd_address::NdAddress;
+use crate::traits::{Coordinate, Tensor};
+
+/// NdTensor is a dense store of type T with any fixed number of dimensions, D.
+/// Storage is ordered as TensorForwardIterator visits addresses, with dimension
+/// 0 varying fastest.  Cube and HyperCube are hand-written equivalents of D = 3
+/// and D = 4 with named address components.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NdTensor<T, I, const D: usize>
+where
+    I: Coordinate,
+{
+    pub(crate) shape: [I; D],
+    pub(crate) data: Vec<T>,
+}
+
+impl <T, I, const D: usize> NdTensor<T, I, D>
+where
+    I: Coordinate,
+{
+    /// shape returns the extent of each dimension.
+    pub fn shape(&self) -> [I; D] {
+        self.shape
+    }
+
+    /// addresses returns every address in the tensor, in address order.
+    pub fn addresses(&self) -> TensorForwardIterator<I, D> {
+        TensorForwardIterator::new(self.range().end)
+    }
+
+    /// iter returns the values of the tensor in address order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// iter_mut returns mutable references to the values of the tensor in address order.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// indexed_iter returns (address, value) tuples in address order.
+    pub fn indexed_iter(&self) -> TensorForwardIndexedIterator<'_, T, I, D> {
+        TensorForwardIndexedIterator::new(self.addresses(), self.data.iter())
+    }
+
+    // index_address works in usize, since the cell count may not fit in I even
+    // though every coordinate does.
+    fn index_address(&self, address: NdAddress<I, D>) -> usize {
+        (0..D).rev().fold(0, |acc, d| acc * to_usize(self.shape[d]) + to_usize(address[d]))
+    }
+}
+
+fn to_usize<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("address overflows usize.  This should be unreachable."),
+    }
+}
+
+impl<T, I, const D: usize> Tensor<T, I, NdAddress<I, D>, D> for NdTensor<T, I, D>
+where
+    I: Coordinate,
+{
+    fn range(&self) -> Range<NdAddress<I, D>> {
+        Range {
+            start: NdAddress::default(),
+            end: NdAddress::from(self.shape),
+        }
+    }
+
+    fn get(&self, address: NdAddress<I, D>) -> Option<&T> {
+        if !self.contains(address) {
+            None
+        } else {
+            let addr = self.index_address(address);
+            self.data.get(addr)
+        }
+    }
+
+    fn get_mut(&mut self, address: NdAddress<I, D>) -> Option<&mut T> {
+        if !self.contains(address) {
+            None
+        } else {
+            let addr = self.index_address(address);
+            self.data.get_mut(addr)
+        }
+    }
+}
+
+impl<T, I, const D: usize> Index<NdAddress<I, D>> for NdTensor<T, I, D>
+where
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, index: NdAddress<I, D>) -> &Self::Output {
+        match self.get(index) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl<T, I, const D: usize> IndexMut<NdAddress<I, D>> for NdTensor<T, I, D>
+where
+    I: Coordinate,
+{
+    fn index_mut(&mut self, index: NdAddress<I, D>) -> &mut T {
+        match self.get_mut(index) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::factories::{new_default_nd_tensor, new_nd_tensor};
+    use super::*;
+
+    #[test]
+    fn storage_matches_iteration_order() {
+        let t = new_nd_tensor::<u8, u8, 3>([2, 3, 2], (0..12).collect()).unwrap();
+        assert_eq!(t[NdAddress::from([1, 0, 0])], 1);
+        assert_eq!(t[NdAddress::from([0, 1, 0])], 2);
+        assert_eq!(t[NdAddress::from([0, 0, 1])], 6);
+        assert_eq!(t.get(NdAddress::from([0, 3, 0])), None);
+        for (i, (addr, value)) in t.indexed_iter().enumerate() {
+            assert_eq!(usize::from(*value), i);
+            assert_eq!(t[addr], *value);
+        }
+        let addrs: Vec<NdAddress<u8, 3>> = t.addresses().collect();
+        assert!(addrs.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn five_dimensions() {
+        let mut t = new_default_nd_tensor::<u8, u8, 5>([2, 2, 2, 2, 2]).unwrap();
+        t[NdAddress::from([1, 1, 1, 1, 1])] = 7;
+        t.iter_mut().for_each(|v| *v += 1);
+        assert_eq!(t.iter().map(|v| usize::from(*v)).sum::<usize>(), 32 + 7);
+        assert_eq!(t.data[31], 8);
+    }
+
+    #[test]
+    fn index_exceeding_coordinate_type() {
+        let mut t = new_default_nd_tensor::<u8, u8, 3>([7, 7, 7]).unwrap();
+        t[NdAddress::from([6, 6, 6])] = 1;
+        assert_eq!(t.get(NdAddress::from([6, 6, 6])), Some(&1));
+        assert_eq!(t.iter().position(|v| *v == 1), Some(342));
+    }
+
+    #[test]
+    fn dimension_validation() {
+        assert_eq!(
+            new_nd_tensor::<u8, u8, 2>([2, 2], vec![0; 3]).err(),
+            Some(Error::new("data length 3 does not match tensor shape (4)".to_string()))
+        );
+        assert_eq!(
+            new_default_nd_tensor::<u8, i8, 2>([2, -2]).err(),
+            Some(Error::new("negative dimensions not supported".to_string()))
+        );
+        let empty = new_default_nd_tensor::<u8, u8, 3>([2, 0, 2]).unwrap();
+        assert_eq!(empty.shape(), [0, 0, 0]);
+        assert!(empty.addresses().next().is_none());
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::Coordinate;
+use crate::bit_matrix::{BitMatrix, WORD_BITS};
+use crate::dense_matrix::DenseMatrix;
+use crate::matrix_address::NeighborKind;
+
+impl <I> DenseMatrix<bool, I>
+where
+    I: Coordinate,
+{
+    /// neighbor_count_map returns, for every cell, the number of its in-bounds
+    /// neighbors under connectivity that are true.  Counts are accumulated by
+    /// adding whole shifted rows, one neighbor offset at a time, rather than
+    /// visiting the neighbors of each cell.
+    pub fn neighbor_count_map(&self, connectivity: NeighborKind) -> DenseMatrix<u8, I> {
+        let (rows, columns) = (dimension(self.rows), dimension(self.columns));
+        let mut counts = vec![0u8; self.data.len()];
+        for &(dr, dc) in connectivity.offsets() {
+            for row in 0..rows {
+                let source_row = row as isize + dr as isize;
+                if source_row < 0 || source_row >= rows as isize {
+                    continue;
+                }
+                let source = &self.data[source_row as usize * columns..(source_row as usize + 1) * columns];
+                let target = &mut counts[row * columns..(row + 1) * columns];
+                let (target, source) = match dc {
+                    -1 => (&mut target[1..], &source[..columns.saturating_sub(1)]),
+                    1 => (&mut target[..columns.saturating_sub(1)], &source[1..]),
+                    _ => (target, source),
+                };
+                for (count, cell) in target.iter_mut().zip(source.iter()) {
+                    *count += *cell as u8;
+                }
+            }
+        }
+        DenseMatrix::new(self.columns, self.rows, counts)
+    }
+}
+
+impl <I> BitMatrix<I>
+where
+    I: Coordinate,
+{
+    /// neighbor_count_map returns, for every cell, the number of its in-bounds
+    /// neighbors under connectivity that are set.  Each neighbor offset is
+    /// applied to a whole row of words at once, and the shifted rows are summed
+    /// with bit-sliced adders, 64 cells per operation.
+    pub fn neighbor_count_map(&self, connectivity: NeighborKind) -> DenseMatrix<u8, I> {
+        let (rows, columns) = (dimension(self.rows), dimension(self.columns));
+        let width = self.words_per_row;
+        let mut counts = Vec::with_capacity(rows * columns);
+        let mut shifted = vec![0u64; width];
+        for row in 0..rows {
+            // planes[k] holds bit k of the running count of each cell in the row.
+            let mut planes = vec![[0u64; 4]; width];
+            for &(dr, dc) in connectivity.offsets() {
+                let source_row = row as isize + dr as isize;
+                if source_row < 0 || source_row >= rows as isize {
+                    continue;
+                }
+                let source = &self.words[source_row as usize * width..(source_row as usize + 1) * width];
+                shift_row(source, dc, &mut shifted);
+                for (word, carry) in planes.iter_mut().zip(shifted.iter()) {
+                    let mut carry = *carry;
+                    for plane in word.iter_mut() {
+                        let sum = *plane ^ carry;
+                        carry &= *plane;
+                        *plane = sum;
+                    }
+                }
+            }
+            for column in 0..columns {
+                let (word, offset) = (column / WORD_BITS, column % WORD_BITS);
+                let count = planes[word].iter().enumerate()
+                    .map(|(k, plane)| ((plane >> offset & 1) as u8) << k)
+                    .sum();
+                counts.push(count);
+            }
+        }
+        DenseMatrix::new(self.columns, self.rows, counts)
+    }
+}
+
+// shift_row writes into target the row of words whose bit c is bit c + dc of
+// source, for dc in -1..=1.  Bits shifted in from outside the row are zero.
+fn shift_row(source: &[u64], dc: i8, target: &mut [u64]) {
+    let last = source.len().saturating_sub(1);
+    for (i, word) in target.iter_mut().enumerate() {
+        *word = match dc {
+            1 => {
+                let carry = if i < last { source[i + 1] << (WORD_BITS - 1) } else { 0 };
+                source[i] >> 1 | carry
+            },
+            -1 => {
+                let carry = if i > 0 { source[i - 1] >> (WORD_BITS - 1) } else { 0 };
+                source[i] << 1 | carry
+            },
+            _ => source[i],
+        };
+    }
+}
+
+fn dimension<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("dimension overflows usize.  This should be unreachable."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, MatrixMap};
+    use crate::factories::{new_bit_matrix_from, new_matrix};
+    use super::*;
+
+    fn naive(m: &DenseMatrix<bool, u8>, kind: NeighborKind) -> DenseMatrix<u8, u8> {
+        m.map_indexed_matrix(&mut |addr, _| {
+            m.neighbors(addr, kind).filter(|(_, v)| **v).count() as u8
+        })
+    }
+
+    #[test]
+    fn matches_per_cell_counts() {
+        // 3 rows of 70 columns, so bit rows span two words.
+        let data: Vec<bool> = (0..210).map(|i: usize| (i * 7 + i / 13).is_multiple_of(3)).collect();
+        let dense = new_matrix::<bool, u8>(3, data).unwrap();
+        let bits = new_bit_matrix_from(&dense).unwrap();
+        for kind in [NeighborKind::Orthogonal4, NeighborKind::Diagonal4, NeighborKind::All8] {
+            let expected = naive(&dense, kind);
+            assert_eq!(dense.neighbor_count_map(kind), expected);
+            assert_eq!(bits.neighbor_count_map(kind), expected);
+        }
+    }
+
+    #[test]
+    fn full_grid_counts() {
+        let dense = new_matrix::<bool, u8>(3, vec![true; 9]).unwrap();
+        let counts = new_bit_matrix_from(&dense).unwrap().neighbor_count_map(NeighborKind::All8);
+        assert_eq!(counts.iter().copied().collect::<Vec<u8>>(), vec![3, 5, 3, 5, 8, 5, 3, 5, 3]);
+    }
+}
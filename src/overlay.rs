@@ -0,0 +1,324 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut, Range};
+use crate::{Coordinate, Matrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixMut, MatrixRowsIterator, MatrixValueIterator, Tensor};
+use crate::column::Column;
+use crate::error::{Error, Result};
+use crate::row::Row;
+
+/// OverlayMatrix stacks sparse overlay layers on top of a base matrix.  Reads see
+/// the topmost layer holding a value for an address, falling back to the base.
+/// Writes land in the top layer, leaving the base untouched until the layers are
+/// committed.  This allows moving entities to be rendered over a static map
+/// without mutating it.
+/// A pass over every cell would copy the whole base into the top layer, so
+/// the matrix does not implement MatrixMut; occupied_mut visits only the
+/// cells the top layer already holds.
+pub struct OverlayMatrix<'a, T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) base: &'a mut dyn MatrixMut<'a, T, I>,
+    pub(crate) layers: Vec<HashMap<MatrixAddress<I>, T>>,
+}
+
+impl <'a, T, I> OverlayMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// layer_count returns the number of overlay layers above the base.
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// push_layer adds a new, empty, top layer.
+    pub fn push_layer(&mut self) {
+        self.layers.push(HashMap::new());
+    }
+
+    /// discard removes the top layer, returning its contents.
+    /// None is returned if there are no layers.
+    pub fn discard(&mut self) -> Option<HashMap<MatrixAddress<I>, T>> {
+        self.layers.pop()
+    }
+
+    /// discard_all removes every layer, restoring the view of the base.
+    pub fn discard_all(&mut self) {
+        self.layers.clear();
+    }
+
+    /// set writes value at address in the top layer, creating a layer if there
+    /// are none.  Out of bounds addresses are an error.
+    pub fn set(&mut self, address: MatrixAddress<I>, value: T) -> Result<()> {
+        if !self.base.contains(address) {
+            return Err(Error::new(format!("overlay address {} is out of bounds", address)));
+        }
+        if self.layers.is_empty() {
+            self.push_layer();
+        }
+        if let Some(top) = self.layers.last_mut() {
+            top.insert(address, value);
+        }
+        Ok(())
+    }
+
+    /// occupied_mut returns the (address, value) pairs held by the top layer in
+    /// row-major order, for modification.  Cells that only the base or lower
+    /// layers hold are not visited.
+    pub fn occupied_mut(&mut self) -> std::vec::IntoIter<(MatrixAddress<I>, &mut T)> {
+        let mut cells: Vec<(MatrixAddress<I>, &mut T)> = match self.layers.last_mut() {
+            Some(top) => top.iter_mut().map(|(addr, value)| (*addr, value)).collect(),
+            None => Vec::new(),
+        };
+        cells.sort_by_key(|(addr, _)| *addr);
+        cells.into_iter()
+    }
+
+    /// overlay_value returns the value the overlays hold for an address, ignoring
+    /// the base.  None is returned if no layer holds a value for the address.
+    pub fn overlay_value(&self, address: MatrixAddress<I>) -> Option<&T> {
+        self.layers.iter().rev().find_map(|layer| layer.get(&address))
+    }
+
+    /// commit merges the top layer into the layer below it, or into the base
+    /// if it is the only layer.
+    pub fn commit(&mut self) {
+        let top = match self.layers.pop() {
+            Some(v) => v,
+            None => return,
+        };
+        match self.layers.last_mut() {
+            Some(below) => below.extend(top),
+            None => {
+                for (address, value) in top {
+                    self.base[address] = value;
+                }
+            }
+        }
+    }
+
+    /// commit_all writes every layer into the base, bottom layer first.
+    pub fn commit_all(&mut self) {
+        while !self.layers.is_empty() {
+            self.commit();
+        }
+    }
+}
+
+impl <'a, T, I> OverlayMatrix<'a, T, I>
+where
+    T: 'static + Clone,
+    I: Coordinate,
+{
+    /// copy_up ensures the top layer holds address, copying the visible value
+    /// into it if necessary, so that it can be mutated without touching the
+    /// base or lower layers.
+    fn copy_up(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        if !self.base.contains(address) {
+            return None;
+        }
+        if self.layers.is_empty() {
+            self.push_layer();
+        }
+        if !self.layers.last()?.contains_key(&address) {
+            let visible = self.get(address)?.clone();
+            self.layers.last_mut()?.insert(address, visible);
+        }
+        self.layers.last_mut()?.get_mut(&address)
+    }
+}
+
+impl <'a, T, I> Tensor<T, I, MatrixAddress<I>, 2> for OverlayMatrix<'a, T, I>
+where
+    T: 'static + Clone,
+    I: Coordinate,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        self.base.range()
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        match self.overlay_value(address) {
+            Some(v) if self.base.contains(address) => Some(v),
+            _ => self.base.get(address),
+        }
+    }
+
+    /// get_mut copies the visible value into the top layer before returning it,
+    /// so mutation never reaches the base until commit.
+    fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        self.copy_up(address)
+    }
+}
+
+impl<'a, T, I> Index<MatrixAddress<I>> for OverlayMatrix<'a, T, I>
+where
+    T: 'static + Clone,
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, address: MatrixAddress<I>) -> &Self::Output {
+        match self.get(address) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl<'a, T, I> IndexMut<MatrixAddress<I>> for OverlayMatrix<'a, T, I>
+where
+    T: 'static + Clone,
+    I: Coordinate,
+{
+    fn index_mut(&mut self, address: MatrixAddress<I>) -> &mut Self::Output {
+        match self.get_mut(address) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl <'a: 'c, 'c, T, I> Matrix<'c, T, I> for OverlayMatrix<'a, T, I>
+where
+    T: 'static + Clone,
+    I: Coordinate,
+{
+    fn row_count(&self) -> I {
+        self.base.row_count()
+    }
+
+    fn column_count(&self) -> I {
+        self.base.column_count()
+    }
+
+    fn iter(&'c self) -> MatrixValueIterator<'c, T, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        self.base.addresses()
+    }
+
+    fn indexed_iter(&'c self) -> MatrixForwardIndexedIterator<'c, T, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'c self, row_num: I) -> Option<Row<'c, T, I>> {
+        if row_num >= I::default() && row_num < self.row_count() {
+            Some(Row::new(self, row_num))
+        } else {
+            None
+        }
+    }
+
+    fn column(&'c self, col_num: I) -> Option<Column<'c, T, I>> {
+        if col_num >= I::default() && col_num < self.column_count() {
+            Some(Column::new(self, col_num))
+        } else {
+            None
+        }
+    }
+
+    fn rows(&'c self) -> MatrixRowsIterator<'c, T, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'c self) -> MatrixColumnsIterator<'c, T, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::new_overlay_matrix;
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    fn render(m: &OverlayMatrix<char, u8>) -> String {
+        FormatOptions::default().format(m, |c| c.to_string())
+    }
+
+    fn render_base(m: &crate::DenseMatrix<char, u8>) -> String {
+        FormatOptions::default().format(m, |c| c.to_string())
+    }
+
+    fn map() -> crate::DenseMatrix<char, u8> {
+        FormatOptions::default()
+            .parse_matrix("...\n.#.\n...", |x| x.chars().next().unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_see_topmost_value() {
+        let mut base = map();
+        let mut overlay = new_overlay_matrix(&mut base);
+        overlay.set(u8addr(0, 0), '@').unwrap();
+        overlay.push_layer();
+        overlay.set(u8addr(0, 0), 'E').unwrap();
+        overlay.set(u8addr(2, 2), 'G').unwrap();
+        assert_eq!(overlay.layer_count(), 2);
+        assert_eq!(overlay[u8addr(0, 0)], 'E');
+        assert_eq!(overlay.overlay_value(u8addr(1, 1)), None);
+        assert_eq!(render(&overlay), "E..\n.#.\n..G");
+        overlay.discard();
+        assert_eq!(render(&overlay), "@..\n.#.\n...");
+        overlay.discard_all();
+        assert_eq!(render_base(&base), "...\n.#.\n...");
+    }
+
+    #[test]
+    fn commit_merges_downwards() {
+        let mut base = map();
+        let mut overlay = new_overlay_matrix(&mut base);
+        overlay.set(u8addr(0, 1), 'a').unwrap();
+        overlay.push_layer();
+        overlay.set(u8addr(0, 2), 'b').unwrap();
+        overlay.commit();
+        assert_eq!(overlay.layer_count(), 1);
+        assert_eq!(overlay.overlay_value(u8addr(0, 2)), Some(&'b'));
+        overlay.commit_all();
+        assert_eq!(overlay.layer_count(), 0);
+        assert_eq!(render_base(&base), ".ab\n.#.\n...");
+    }
+
+    #[test]
+    fn mutation_copies_up() {
+        let mut base = map();
+        let mut overlay = new_overlay_matrix(&mut base);
+        overlay[u8addr(1, 1)] = '*';
+        *overlay.get_mut(u8addr(1, 0)).unwrap() = '<';
+        assert!(overlay.get_mut(u8addr(3, 0)).is_none());
+        assert!(overlay.set(u8addr(0, 3), 'x').is_err());
+        assert_eq!(render(&overlay), "...\n<*.\n...");
+        overlay.discard_all();
+        assert_eq!(render_base(&base), "...\n.#.\n...");
+    }
+
+    #[test]
+    fn occupied_mut_writes_top_layer() {
+        let mut base = map();
+        let mut overlay = new_overlay_matrix(&mut base);
+        overlay.set(u8addr(2, 0), 'b').unwrap();
+        overlay.push_layer();
+        overlay.set(u8addr(2, 2), 'c').unwrap();
+        overlay.set(u8addr(0, 1), 'a').unwrap();
+        let visited: Vec<MatrixAddress<u8>> = overlay.occupied_mut()
+            .map(|(addr, value)| {
+                *value = value.to_ascii_uppercase();
+                addr
+            })
+            .collect();
+        assert_eq!(visited, vec![u8addr(0, 1), u8addr(2, 2)]);
+        assert_eq!(render(&overlay), ".A.\n.#.\nb.C");
+        overlay.discard_all();
+        assert_eq!(overlay.occupied_mut().count(), 0);
+        assert_eq!(render_base(&base), "...\n.#.\n...");
+    }
+}
@@ -0,0 +1,630 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use crate::{Coordinate, DenseMatrix, Matrix, MatrixAddress, NeighborKind, Tensor};
+
+/// Path is a route through a matrix, listing every address visited from the
+/// start to the goal inclusive, and the cost of each step between them.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Path<I>
+where
+    I: Coordinate,
+{
+    pub(crate) addresses: Vec<MatrixAddress<I>>,
+    pub(crate) step_costs: Vec<u64>,
+}
+
+impl <I> Path<I>
+where
+    I: Coordinate,
+{
+    /// addresses returns the addresses on the path, from start to goal.
+    pub fn addresses(&self) -> &[MatrixAddress<I>] {
+        &self.addresses
+    }
+
+    /// step_costs returns the cost of each step, so step_costs()[i] is the cost
+    /// of moving from addresses()[i] to addresses()[i + 1].
+    pub fn step_costs(&self) -> &[u64] {
+        &self.step_costs
+    }
+
+    /// cost returns the total cost of the path's steps.
+    pub fn cost(&self) -> u64 {
+        self.step_costs.iter().sum()
+    }
+
+    /// contains reports whether the path visits address.
+    pub fn contains(&self, address: MatrixAddress<I>) -> bool {
+        self.addresses.contains(&address)
+    }
+
+    /// to_mask returns a matrix shaped like matrix that is true exactly at the
+    /// addresses on the path.
+    pub fn to_mask<'a, T>(&self, matrix: &dyn Matrix<'a, T, I>) -> DenseMatrix<bool, I>
+    where
+        T: 'static,
+    {
+        let mut data: Vec<bool> = matrix.addresses().map(|_| false).collect();
+        for index in self.addresses.iter().filter_map(|addr| matrix.address_to_index(*addr)) {
+            data[index] = true;
+        }
+        if data.is_empty() {
+            return DenseMatrix::new(I::default(), I::default(), data);
+        }
+        DenseMatrix::new(matrix.column_count(), matrix.row_count(), data)
+    }
+
+    /// len returns the number of steps on the path, one fewer than the number
+    /// of addresses.
+    pub fn len(&self) -> usize {
+        self.addresses.len().saturating_sub(1)
+    }
+
+    /// is_empty reports whether the path takes no steps, because it starts at
+    /// its goal.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// start returns the first address of the path.
+    pub fn start(&self) -> MatrixAddress<I> {
+        self.addresses[0]
+    }
+
+    /// goal returns the last address of the path.
+    pub fn goal(&self) -> MatrixAddress<I> {
+        self.addresses[self.addresses.len() - 1]
+    }
+}
+
+/// ContractedGraph is the junction graph of the passable cells of a matrix,
+/// built by contract_corridors.  Its nodes are the passable cells that do not
+/// have exactly two passable neighbors (junctions and dead ends), and each
+/// corridor of degree-2 cells between two nodes becomes a single weighted edge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractedGraph<I>
+where
+    I: Coordinate,
+{
+    pub(crate) nodes: Vec<MatrixAddress<I>>,
+    pub(crate) node_ids: HashMap<MatrixAddress<I>, usize>,
+    pub(crate) corridors: Vec<Corridor<I>>,
+    pub(crate) adjacency: Vec<Vec<usize>>,
+}
+
+/// Corridor is an edge of a ContractedGraph: the run of cells joining two
+/// nodes, listed from one node to the other inclusive.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Corridor<I>
+where
+    I: Coordinate,
+{
+    pub(crate) from: usize,
+    pub(crate) to: usize,
+    pub(crate) cells: Vec<MatrixAddress<I>>,
+}
+
+impl <I> Corridor<I>
+where
+    I: Coordinate,
+{
+    /// from returns the node the corridor's cells start at.
+    pub fn from(&self) -> usize {
+        self.from
+    }
+
+    /// to returns the node the corridor's cells end at.
+    pub fn to(&self) -> usize {
+        self.to
+    }
+
+    /// cells returns the addresses along the corridor, including both nodes.
+    pub fn cells(&self) -> &[MatrixAddress<I>] {
+        &self.cells
+    }
+
+    /// len returns the number of steps along the corridor.
+    pub fn len(&self) -> u64 {
+        self.cells.len().saturating_sub(1) as u64
+    }
+
+    /// is_empty reports whether the corridor takes no steps.  Corridors always
+    /// join two cells, so this is only true of a default-constructed one.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl <I> ContractedGraph<I>
+where
+    I: Coordinate,
+{
+    /// nodes returns the address of each node, indexed by node number.
+    pub fn nodes(&self) -> &[MatrixAddress<I>] {
+        &self.nodes
+    }
+
+    /// node returns the number of the node at address, if it is one.
+    pub fn node(&self, address: MatrixAddress<I>) -> Option<usize> {
+        self.node_ids.get(&address).copied()
+    }
+
+    /// corridors returns every edge of the graph, each listed once.
+    pub fn corridors(&self) -> &[Corridor<I>] {
+        &self.corridors
+    }
+
+    /// edges returns the nodes adjacent to node and the length of the
+    /// corridor to each.  A corridor that loops back to node is listed twice,
+    /// once per direction.
+    pub fn edges(&self, node: usize) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.adjacency.get(node).into_iter().flatten().map(move |&c| {
+            let corridor = &self.corridors[c];
+            let other = if corridor.from == node { corridor.to } else { corridor.from };
+            (other, corridor.len())
+        })
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// contract_corridors collapses the cells for which passable is true into a
+    /// ContractedGraph, moving between neighbors of the given kind.  Longest
+    /// path searches that are hopeless cell by cell become tractable on the
+    /// handful of junctions that remain.  A closed loop of degree-2 cells with
+    /// no junction on it has no nodes, and is omitted.
+    pub fn contract_corridors(&self, passable: impl Fn(&T) -> bool, kind: NeighborKind) -> ContractedGraph<I> {
+        let open = |addr: MatrixAddress<I>| self.get(addr).is_some_and(&passable);
+        let exits = |addr: MatrixAddress<I>| -> Vec<MatrixAddress<I>> {
+            addr.neighbors_with(self, kind).into_iter().filter(|n| open(*n)).collect()
+        };
+        let nodes: Vec<MatrixAddress<I>> = self.addresses()
+            .filter(|addr| open(*addr) && exits(*addr).len() != 2)
+            .collect();
+        let node_ids: HashMap<MatrixAddress<I>, usize> = nodes.iter().enumerate().map(|(i, addr)| (*addr, i)).collect();
+        let mut corridors = Vec::new();
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        // Each corridor is walked from both ends, so keep the walk whose first
+        // step is unseen, or for corridors without interior cells, the walk
+        // from the lower numbered node.
+        let mut walked: HashSet<MatrixAddress<I>> = HashSet::new();
+        for (from, start) in nodes.iter().enumerate() {
+            for first in exits(*start) {
+                if let Some(&to) = node_ids.get(&first) {
+                    if from < to {
+                        adjacency[from].push(corridors.len());
+                        adjacency[to].push(corridors.len());
+                        corridors.push(Corridor { from, to, cells: vec![*start, first] });
+                    }
+                    continue;
+                }
+                if !walked.insert(first) {
+                    continue;
+                }
+                let mut cells = vec![*start, first];
+                let (mut previous, mut current) = (*start, first);
+                while !node_ids.contains_key(&current) {
+                    let next = match exits(current).into_iter().find(|n| *n != previous) {
+                        Some(next) => next,
+                        None => break,
+                    };
+                    walked.insert(next);
+                    cells.push(next);
+                    (previous, current) = (current, next);
+                }
+                let to = node_ids[&current];
+                walked.insert(cells[cells.len() - 2]);
+                adjacency[from].push(corridors.len());
+                adjacency[to].push(corridors.len());
+                corridors.push(Corridor { from, to, cells });
+            }
+        }
+        ContractedGraph { nodes, node_ids, corridors, adjacency }
+    }
+}
+
+/// SearchPath is a route found by search, listing every state from the initial
+/// state to the goal inclusive, and the cost of each step between them.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SearchPath<S> {
+    pub(crate) states: Vec<S>,
+    pub(crate) step_costs: Vec<u64>,
+}
+
+impl <S> SearchPath<S> {
+    /// states returns the states on the path, from the initial state to the
+    /// goal.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// step_costs returns the cost of each step, so step_costs()[i] is the cost
+    /// of moving from states()[i] to states()[i + 1].
+    pub fn step_costs(&self) -> &[u64] {
+        &self.step_costs
+    }
+
+    /// cost returns the total cost of the path's steps.
+    pub fn cost(&self) -> u64 {
+        self.step_costs.iter().sum()
+    }
+
+    /// goal returns the last state of the path.
+    pub fn goal(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+/// search finds a cheapest sequence of states from initial to any state for
+/// which goal is true, by Dijkstra's algorithm over the graph that successors
+/// describes: successors(s) lists each state reachable from s in one step with
+/// the cost of that step.  States typically pair a MatrixAddress with whatever
+/// else the puzzle tracks, such as the direction of travel, keys held or the
+/// number of consecutive steps taken.  When several cheapest paths exist, the
+/// one found first is returned.  None is returned if no goal is reachable.
+pub fn search<S>(
+    initial: S,
+    successors: impl Fn(&S) -> Vec<(S, u64)>,
+    goal: impl Fn(&S) -> bool,
+) -> Option<SearchPath<S>>
+where
+    S: Clone + Eq + Hash,
+{
+    // States are numbered in the order they are discovered, so the queue and
+    // parent links need only Ord on the number, not on S.
+    let mut states = vec![initial.clone()];
+    let mut ids = HashMap::from([(initial, 0usize)]);
+    let mut best: Vec<u64> = vec![0];
+    let mut parents: Vec<Option<(usize, u64)>> = vec![None];
+    let mut done: Vec<bool> = vec![false];
+    let mut queue = BinaryHeap::from([Reverse((0u64, 0usize))]);
+    while let Some(Reverse((cost, id))) = queue.pop() {
+        if done[id] {
+            continue;
+        }
+        done[id] = true;
+        if goal(&states[id]) {
+            return Some(trace_search(&states, &parents, id));
+        }
+        for (next, step) in successors(&states[id]) {
+            let next_cost = cost.saturating_add(step);
+            let next_id = match ids.get(&next) {
+                Some(&next_id) => next_id,
+                None => {
+                    ids.insert(next.clone(), states.len());
+                    states.push(next);
+                    best.push(u64::MAX);
+                    parents.push(None);
+                    done.push(false);
+                    states.len() - 1
+                },
+            };
+            if !done[next_id] && next_cost < best[next_id] {
+                best[next_id] = next_cost;
+                parents[next_id] = Some((id, step));
+                queue.push(Reverse((next_cost, next_id)));
+            }
+        }
+    }
+    None
+}
+
+/// bfs finds a shortest path from start to goal by breadth-first search,
+/// moving between neighbors of the given kind and only entering cells for
+/// which passable is true.  Every step costs 1.  The start cell itself is not
+/// tested.  When several shortest paths exist, the one found first in neighbor
+/// order is returned.  None is returned if the goal is unreachable or either
+/// address is out of bounds.
+pub fn bfs<'a, T, I>(
+    matrix: &'a dyn Matrix<'a, T, I>,
+    start: MatrixAddress<I>,
+    goal: MatrixAddress<I>,
+    passable: impl Fn(&T) -> bool,
+    kind: NeighborKind,
+) -> Option<Path<I>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let start_index = matrix.address_to_index(start)?;
+    matrix.address_to_index(goal)?;
+    let mut parents: Vec<Option<usize>> = matrix.addresses().map(|_| None).collect();
+    parents[start_index] = Some(start_index);
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        if current == goal {
+            return Some(trace_path(matrix, &parents, goal));
+        }
+        let current_index = matrix.address_to_index(current)?;
+        for next in current.neighbors_with(matrix, kind) {
+            let next_index = matrix.address_to_index(next)?;
+            if parents[next_index].is_none() && matrix.get(next).is_some_and(&passable) {
+                parents[next_index] = Some(current_index);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// count_shortest_paths returns the number of distinct shortest paths from
+/// start to goal, moving as bfs does.  The count saturates at u128::MAX.  None
+/// is returned if the goal is unreachable or either address is out of bounds.
+pub fn count_shortest_paths<'a, T, I>(
+    matrix: &'a dyn Matrix<'a, T, I>,
+    start: MatrixAddress<I>,
+    goal: MatrixAddress<I>,
+    passable: impl Fn(&T) -> bool,
+    kind: NeighborKind,
+) -> Option<u128>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let distances = bfs_distances(matrix, start, passable, kind)?;
+    let goal_index = matrix.address_to_index(goal)?;
+    distances[goal_index]?;
+    // Visit reached cells nearest first, so each count is final before it is
+    // passed on to the next layer.
+    let mut order: Vec<MatrixAddress<I>> = matrix.addresses()
+        .filter(|a| matrix.address_to_index(*a).is_some_and(|i| distances[i].is_some()))
+        .collect();
+    order.sort_by_key(|a| matrix.address_to_index(*a).and_then(|i| distances[i]));
+    let mut counts: Vec<u128> = vec![0; distances.len()];
+    counts[matrix.address_to_index(start)?] = 1;
+    for current in order {
+        let current_index = matrix.address_to_index(current)?;
+        for next in current.neighbors_with(matrix, kind) {
+            let next_index = matrix.address_to_index(next)?;
+            if distances[next_index].is_some_and(|d| Some(d) == distances[current_index].map(|c| c + 1)) {
+                counts[next_index] = counts[next_index].saturating_add(counts[current_index]);
+            }
+        }
+    }
+    Some(counts[goal_index])
+}
+
+/// cells_on_any_shortest_path returns every address that lies on at least one
+/// shortest path from start to goal, moving as bfs does, including start and
+/// goal.  None is returned if the goal is unreachable or either address is out
+/// of bounds.
+pub fn cells_on_any_shortest_path<'a, T, I>(
+    matrix: &'a dyn Matrix<'a, T, I>,
+    start: MatrixAddress<I>,
+    goal: MatrixAddress<I>,
+    passable: impl Fn(&T) -> bool,
+    kind: NeighborKind,
+) -> Option<HashSet<MatrixAddress<I>>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let distances = bfs_distances(matrix, start, passable, kind)?;
+    distances[matrix.address_to_index(goal)?]?;
+    // Walk back from the goal, one layer at a time, through neighbors exactly
+    // one step closer to the start.  The neighbor relation is symmetric.
+    let mut cells = HashSet::from([goal]);
+    let mut queue = VecDeque::from([goal]);
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[matrix.address_to_index(current)?]?;
+        for previous in current.neighbors_with(matrix, kind) {
+            let on_path = distances[matrix.address_to_index(previous)?].is_some_and(|d| d + 1 == distance);
+            if on_path && cells.insert(previous) {
+                queue.push_back(previous);
+            }
+        }
+    }
+    Some(cells)
+}
+
+// bfs_distances runs a breadth-first search from start, returning the distance
+// to each cell, indexed by address_to_index.  None is returned if start is out
+// of bounds.
+fn bfs_distances<'a, T, I>(
+    matrix: &'a dyn Matrix<'a, T, I>,
+    start: MatrixAddress<I>,
+    passable: impl Fn(&T) -> bool,
+    kind: NeighborKind,
+) -> Option<Vec<Option<usize>>>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let mut distances: Vec<Option<usize>> = matrix.addresses().map(|_| None).collect();
+    distances[matrix.address_to_index(start)?] = Some(0);
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[matrix.address_to_index(current)?]?;
+        for next in current.neighbors_with(matrix, kind) {
+            let next_index = matrix.address_to_index(next)?;
+            if distances[next_index].is_none() && matrix.get(next).is_some_and(&passable) {
+                distances[next_index] = Some(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    Some(distances)
+}
+
+// trace_search follows parent links back from the state numbered goal to the
+// initial state, which has no parent.
+fn trace_search<S>(states: &[S], parents: &[Option<(usize, u64)>], goal: usize) -> SearchPath<S>
+where
+    S: Clone,
+{
+    let mut path = vec![states[goal].clone()];
+    let mut step_costs = Vec::new();
+    let mut id = goal;
+    while let Some((parent, step)) = parents[id] {
+        path.push(states[parent].clone());
+        step_costs.push(step);
+        id = parent;
+    }
+    path.reverse();
+    step_costs.reverse();
+    SearchPath { states: path, step_costs }
+}
+
+/// trace_path follows parent links back from goal to the start, whose parent
+/// is itself.
+fn trace_path<'a, T, I>(matrix: &'a dyn Matrix<'a, T, I>, parents: &[Option<usize>], goal: MatrixAddress<I>) -> Path<I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    let mut addresses = vec![goal];
+    let mut index = matrix.address_to_index(goal).unwrap_or_default();
+    while let Some(parent) = parents[index] {
+        if parent == index {
+            break;
+        }
+        index = parent;
+        addresses.extend(matrix.index_to_address(index));
+    }
+    addresses.reverse();
+    let step_costs = vec![1; addresses.len() - 1];
+    Path { addresses, step_costs }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::new_sparse_matrix;
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    fn maze() -> crate::DenseMatrix<char, u8> {
+        FormatOptions::default()
+            .parse_matrix("S.#.\n.##.\n...E", |x| x.chars().next().unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn shortest_path_through_maze() {
+        let m = maze();
+        let path = bfs(&m, u8addr(0, 0), u8addr(2, 3), |c| *c != '#', NeighborKind::Orthogonal4).unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.start(), u8addr(0, 0));
+        assert_eq!(path.goal(), u8addr(2, 3));
+        assert_eq!(path.addresses(), &[
+            u8addr(0, 0), u8addr(1, 0), u8addr(2, 0), u8addr(2, 1), u8addr(2, 2), u8addr(2, 3),
+        ]);
+        let diagonal = bfs(&m, u8addr(0, 0), u8addr(2, 3), |c| *c != '#', NeighborKind::All8).unwrap();
+        assert_eq!(diagonal.len(), 4);
+    }
+
+    #[test]
+    fn path_cost_membership_and_mask() {
+        let mut m = new_sparse_matrix::<char, u8>(3, 4, '.').unwrap();
+        for wall in [u8addr(0, 2), u8addr(1, 1), u8addr(1, 2)] {
+            m.set(wall, '#');
+        }
+        let path = bfs(&m, u8addr(0, 0), u8addr(2, 3), |c| *c != '#', NeighborKind::Orthogonal4).unwrap();
+        assert_eq!(path.cost(), 5);
+        assert_eq!(path.step_costs(), &[1; 5]);
+        assert!(path.contains(u8addr(2, 1)));
+        assert!(!path.contains(u8addr(0, 1)));
+        assert!(!path.contains(u8addr(3, 0)));
+        let mask = path.to_mask(&m);
+        assert_eq!(mask.shape(), m.shape());
+        assert_eq!(FormatOptions::default().format(&mask, |b| if *b { "*" } else { "." }.to_string()), "*...\n*...\n****");
+        let standing = bfs(&m, u8addr(0, 0), u8addr(0, 0), |c| *c != '#', NeighborKind::Orthogonal4).unwrap();
+        assert_eq!((standing.cost(), standing.step_costs().len()), (0, 0));
+        assert_eq!(standing.to_mask(&m).iter().filter(|b| **b).count(), 1);
+    }
+
+    #[test]
+    fn all_shortest_paths() {
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>("S...\n.#..\n...E", |x| x.chars().next().unwrap())
+            .unwrap();
+        let passable = |c: &char| *c != '#';
+        assert_eq!(count_shortest_paths(&m, u8addr(0, 0), u8addr(2, 3), passable, NeighborKind::Orthogonal4), Some(4));
+        let cells = cells_on_any_shortest_path(&m, u8addr(0, 0), u8addr(2, 3), passable, NeighborKind::Orthogonal4).unwrap();
+        assert_eq!(cells.len(), 11);
+        assert!(!cells.contains(&u8addr(1, 1)));
+        assert_eq!(count_shortest_paths(&m, u8addr(0, 0), u8addr(1, 1), passable, NeighborKind::Orthogonal4), None);
+        assert_eq!(count_shortest_paths(&m, u8addr(0, 0), u8addr(0, 0), passable, NeighborKind::All8), Some(1));
+        assert_eq!(cells_on_any_shortest_path(&m, u8addr(0, 0), u8addr(0, 0), passable, NeighborKind::All8).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn search_with_keys_and_turns() {
+        // The door D opens only once the key k has been picked up.
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>("S.D.E\n.####\n...k.", |x| x.chars().next().unwrap())
+            .unwrap();
+        let successors = |&(addr, key): &(MatrixAddress<u8>, bool)| {
+            addr.neighbors_with(&m, NeighborKind::Orthogonal4)
+                .into_iter()
+                .filter_map(|next| match m.get(next) {
+                    Some('#') => None,
+                    Some('D') if !key => None,
+                    Some(c) => Some(((next, key || *c == 'k'), 1)),
+                    None => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        let path = search((u8addr(0, 0), false), successors, |(addr, _)| m.get(*addr) == Some(&'E')).unwrap();
+        assert_eq!(path.cost(), 14);
+        assert_eq!(path.states().len(), 15);
+        assert!(path.states().contains(&(u8addr(2, 3), true)));
+        assert_eq!(path.goal(), &(u8addr(0, 4), true));
+        assert!(search((u8addr(0, 0), false), successors, |(addr, _)| *addr == u8addr(1, 1)).is_none());
+    }
+
+    #[test]
+    fn search_prefers_cheaper_steps() {
+        // Walking east along row 0 costs 1 per step, but turning costs 10.
+        let successors = |&(column, turned): &(u8, bool)| {
+            let mut next = vec![];
+            if column < 4 {
+                next.push(((column + 1, turned), if turned { 1 } else { 5 }));
+            }
+            if !turned {
+                next.push(((column, true), 3));
+            }
+            next
+        };
+        let path = search((0u8, false), successors, |(column, _)| *column == 4).unwrap();
+        assert_eq!(path.cost(), 7);
+        assert_eq!(path.step_costs(), &[3, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn contract_maze_corridors() {
+        let m = FormatOptions::default()
+            .parse_matrix::<char, u8>("#S#####\n#.....#\n#.###.#\n#.....#\n#####E#", |x| x.chars().next().unwrap())
+            .unwrap();
+        let graph = m.contract_corridors(|c| *c != '#', NeighborKind::Orthogonal4);
+        assert_eq!(graph.nodes(), &[u8addr(0, 1), u8addr(1, 1), u8addr(3, 5), u8addr(4, 5)]);
+        assert_eq!(graph.corridors().len(), 4);
+        assert_eq!(graph.corridors().iter().map(|c| c.len()).sum::<u64>(), 14);
+        let junction = graph.node(u8addr(1, 1)).unwrap();
+        let mut edges: Vec<(usize, u64)> = graph.edges(junction).collect();
+        edges.sort();
+        assert_eq!(edges, vec![(0, 1), (2, 6), (2, 6)]);
+        assert_eq!(graph.node(u8addr(1, 2)), None);
+        let corridor = &graph.corridors()[graph.corridors().len() - 1];
+        assert_eq!(corridor.cells(), &[u8addr(3, 5), u8addr(4, 5)]);
+    }
+
+    #[test]
+    fn unreachable_and_trivial() {
+        let m = maze();
+        assert_eq!(bfs(&m, u8addr(0, 0), u8addr(0, 3), |c| *c != '#', NeighborKind::Diagonal4), None);
+        assert_eq!(bfs(&m, u8addr(0, 0), u8addr(3, 0), |_| true, NeighborKind::All8), None);
+        let here = bfs(&m, u8addr(1, 3), u8addr(1, 3), |_| false, NeighborKind::All8).unwrap();
+        assert!(here.is_empty());
+        assert_eq!(here.addresses(), &[u8addr(1, 3)]);
+    }
+}
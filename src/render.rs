@@ -0,0 +1,28 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+/// RgbaImage is a raw image with four bytes (red, green, blue, alpha) per
+/// pixel, rows top to bottom.  The layout matches the data of a browser
+/// ImageData, so a WASM build can copy it into a canvas in one call.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RgbaImage {
+    pub width: u32,
+    pub height: u32,
+    pub data: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Matrix;
+    use crate::factories::new_matrix;
+    use super::*;
+
+    #[test]
+    fn render_rgba() {
+        let m = new_matrix::<bool, u8>(2, vec![true, false, false, true, true, true]).unwrap();
+        let image = m.render(|on| if *on { [255, 255, 255, 255] } else { [0, 0, 0, 255] });
+        assert_eq!((image.width, image.height), (3, 2));
+        assert_eq!(image.data.len(), 24);
+        assert_eq!(&image.data[0..8], &[255, 255, 255, 255, 0, 0, 0, 255]);
+        assert_eq!(image, RgbaImage { width: 3, height: 2, data: image.data.clone() });
+    }
+}
@@ -1,4 +1,6 @@
 use crate::{Coordinate, Matrix, MatrixAddress, MatrixRowIterator};
+use std::slice::{Iter, IterMut};
+use crate::error::{Error, Result};
 
 /// Row is a quality-of-life assistant to ease processing matrices
 /// in a row-major fashion.
@@ -31,8 +33,92 @@ where
         MatrixRowIterator::new(self.matrix, self.row)
     }
 
+    /// iter_range returns a bidirectional iterator over the columns
+    /// from_col..to_col of the row.  An error is returned unless
+    /// 0 <= from_col <= to_col <= column count.
+    pub fn iter_range(&self, from_col: I, to_col: I) -> Result<MatrixRowIterator<'a, T, I>> {
+        if from_col < I::default() || from_col > to_col || to_col > self.matrix.column_count() {
+            return Err(Error::new(format!(
+                "column range {}..{} is out of bounds for {} columns",
+                from_col, to_col, self.matrix.column_count()
+            )));
+        }
+        Ok(MatrixRowIterator::over(self.matrix, self.row, from_col, to_col))
+    }
+
     /// get retrieves a specified column's cell entry from this row.
     pub fn get(&self, column: I) -> Option<&'a T> {
         self.matrix.get(MatrixAddress{row: self.row, column})
     }
-}
\ No newline at end of file
+
+    /// iter_with_addresses returns a bidirectional iterator over the row that
+    /// pairs each cell with its address.
+    pub fn iter_with_addresses(&self) -> impl DoubleEndedIterator<Item = (MatrixAddress<I>, &'a T)> + 'a
+    where
+        T: 'static,
+        I: 'a,
+    {
+        let matrix = self.matrix;
+        let row = self.row;
+        let columns: usize = matrix.column_count().try_into().unwrap_or_default();
+        (0..columns).filter_map(move |c| {
+            let addr = MatrixAddress { row, column: c.try_into().ok()? };
+            Some((addr, matrix.get(addr)?))
+        })
+    }
+}
+
+/// RowMut is a mutable handle on a single row of a DenseMatrix, for
+/// rewriting a row in place without address bookkeeping.
+pub struct RowMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    cells: &'a mut [T],
+    row: I,
+}
+
+impl <'a, T, I> RowMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) fn new(cells: &'a mut [T], row: I) -> Self {
+        RowMut{
+            cells,
+            row,
+        }
+    }
+
+    /// row returns the row number this RowMut represents, 0-based.
+    pub fn row(&self) -> I {
+        self.row
+    }
+
+    /// iter returns an iterator over the row, from the left.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    /// iter_mut returns a mutable iterator over the row, from the left.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// get retrieves a specified column's cell entry from this row.
+    pub fn get(&self, column: I) -> Option<&T> {
+        let index: usize = column.try_into().ok()?;
+        self.cells.get(index)
+    }
+
+    /// get_mut retrieves a mutable reference to a specified column's cell entry.
+    pub fn get_mut(&mut self, column: I) -> Option<&mut T> {
+        let index: usize = column.try_into().ok()?;
+        self.cells.get_mut(index)
+    }
+
+    /// as_mut_slice exposes the row's cells, left to right, so whole-row
+    /// operations such as rotate_left, reverse or copy_from_slice can be used.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.cells
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::Coordinate;
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+use crate::matrix_address::Direction;
+
+/// ScanStrategy chooses how scan_lines and tilt reach the columns of a matrix,
+/// whose cells are strided in row-major storage.  Rows are always processed in
+/// place.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ScanStrategy {
+    /// Auto currently picks Transposed for column scans.
+    #[default]
+    Auto,
+    /// Strided copies each column into a buffer, runs the kernel, and copies
+    /// it back.  It needs only one column of extra memory.
+    Strided,
+    /// Transposed transposes the whole matrix once, runs the kernel over the
+    /// now contiguous rows, and transposes back.  It needs a second copy of
+    /// the matrix, but is much faster on large grids.
+    Transposed,
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    /// scan_lines calls kernel with each line of cells running in direction,
+    /// as a mutable slice that starts at the edge the direction points
+    /// towards: for North, slice[0] is the top cell of a column; for East it is
+    /// the rightmost cell of a row.  Changes to the slice are written back to
+    /// the matrix.  Only the four cardinal directions are supported.
+    pub fn scan_lines(&mut self, direction: Direction, strategy: ScanStrategy, mut kernel: impl FnMut(&mut [T])) -> Result<()> {
+        let columns: usize = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        if columns == 0 {
+            return match direction {
+                Direction::North | Direction::South | Direction::East | Direction::West => Ok(()),
+                _ => Err(Error::new("scan direction must be cardinal".to_string())),
+            };
+        }
+        let rows = self.data.len() / columns;
+        match (direction, strategy) {
+            (Direction::West, _) => self.data.chunks_exact_mut(columns).for_each(kernel),
+            (Direction::East, _) => self.data.chunks_exact_mut(columns).for_each(|line| reversed(line, &mut kernel)),
+            (Direction::North | Direction::South, ScanStrategy::Strided) => {
+                let mut line: Vec<T> = Vec::with_capacity(rows);
+                for column in 0..columns {
+                    line.clear();
+                    line.extend((0..rows).map(|row| self.data[row * columns + column].clone()));
+                    if direction == Direction::North {
+                        kernel(&mut line);
+                    } else {
+                        reversed(&mut line, &mut kernel);
+                    }
+                    for (row, value) in line.drain(..).enumerate() {
+                        self.data[row * columns + column] = value;
+                    }
+                }
+            },
+            (Direction::North | Direction::South, ScanStrategy::Auto | ScanStrategy::Transposed) => {
+                let mut transposed = self.transposed();
+                let line_direction = if direction == Direction::North { Direction::West } else { Direction::East };
+                transposed.scan_lines(line_direction, strategy, kernel)?;
+                *self = transposed.transposed();
+            },
+            _ => return Err(Error::new("scan direction must be cardinal".to_string())),
+        }
+        Ok(())
+    }
+
+    /// tilt slides every cell for which is_mobile is true as far as it will go
+    /// in direction, like rocks on a tilted platform.  A mobile cell moves by
+    /// swapping with the empty cells (per is_empty) ahead of it, and stops at
+    /// the edge, at a cell that is neither mobile nor empty, or behind another
+    /// mobile cell.  Only the four cardinal directions are supported.
+    pub fn tilt(&mut self, direction: Direction, strategy: ScanStrategy, is_mobile: impl Fn(&T) -> bool, is_empty: impl Fn(&T) -> bool) -> Result<()> {
+        self.scan_lines(direction, strategy, |line| {
+            let mut free = 0;
+            for i in 0..line.len() {
+                if is_mobile(&line[i]) {
+                    line.swap(free, i);
+                    free += 1;
+                } else if !is_empty(&line[i]) {
+                    free = i + 1;
+                }
+            }
+        })
+    }
+}
+
+// reversed runs kernel over line with its order reversed.
+fn reversed<T>(line: &mut [T], kernel: &mut impl FnMut(&mut [T])) {
+    line.reverse();
+    kernel(line);
+    line.reverse();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn parse(text: &str) -> DenseMatrix<char, u8> {
+        FormatOptions::default().parse_matrix(text, |x| x.chars().next().unwrap()).unwrap()
+    }
+
+    fn render(m: &DenseMatrix<char, u8>) -> String {
+        FormatOptions::default().format(m, |c| c.to_string())
+    }
+
+    #[test]
+    fn tilt_in_each_direction() {
+        let start = "O.#.\n.O.O\nO#..\n..O.";
+        let expected = [
+            (Direction::North, "OO#O\nO.O.\n.#..\n...."),
+            (Direction::South, "..#.\n.O..\nO#..\nO.OO"),
+            (Direction::East, ".O#.\n..OO\nO#..\n...O"),
+            (Direction::West, "O.#.\nOO..\nO#..\nO..."),
+        ];
+        for (direction, want) in expected {
+            for strategy in [ScanStrategy::Auto, ScanStrategy::Strided, ScanStrategy::Transposed] {
+                let mut m = parse(start);
+                m.tilt(direction, strategy, |c| *c == 'O', |c| *c == '.').unwrap();
+                assert_eq!(render(&m), want, "{:?} {:?}", direction, strategy);
+            }
+        }
+        let mut m = parse(start);
+        assert!(m.tilt(Direction::NorthEast, ScanStrategy::Auto, |c| *c == 'O', |c| *c == '.').is_err());
+    }
+
+    #[test]
+    fn scan_lines_orients_slices() {
+        let mut m = parse("ab\ncd");
+        let mut seen = Vec::new();
+        m.scan_lines(Direction::South, ScanStrategy::Strided, |line| seen.push(line.iter().collect::<String>())).unwrap();
+        assert_eq!(seen, vec!["ca", "db"]);
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::marker::PhantomData;
+use std::ops::Add;
+use crate::Coordinate;
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+
+/// Semiring supplies the addition and multiplication that matmul, pow and
+/// closure use to combine elements.  Implementations are marker types, so the
+/// same element type can be multiplied under different semirings, e.g.
+/// m.matmul::<Boolean>(&other) or m.pow::<MinPlus<u64>>(k).
+pub trait Semiring {
+    type Element: Clone;
+
+    /// zero is the additive identity, which also annihilates under mul.
+    fn zero() -> Self::Element;
+    /// one is the multiplicative identity.
+    fn one() -> Self::Element;
+    fn add(a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn mul(a: &Self::Element, b: &Self::Element) -> Self::Element;
+}
+
+/// Arithmetic is the ordinary (+, *) semiring over a numeric type.  Counting
+/// paths of length k is Arithmetic::pow of the adjacency matrix.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Arithmetic<T> {
+    marker: PhantomData<T>,
+}
+
+macro_rules! arithmetic_semiring {
+    ($($t:ty, $zero:expr, $one:expr);* $(;)?) => {
+        $(
+        impl Semiring for Arithmetic<$t> {
+            type Element = $t;
+            fn zero() -> $t { $zero }
+            fn one() -> $t { $one }
+            fn add(a: &$t, b: &$t) -> $t { a + b }
+            fn mul(a: &$t, b: &$t) -> $t { a * b }
+        }
+        )*
+    };
+}
+
+arithmetic_semiring!(
+    u8, 0, 1; u16, 0, 1; u32, 0, 1; u64, 0, 1; u128, 0, 1; usize, 0, 1;
+    i8, 0, 1; i16, 0, 1; i32, 0, 1; i64, 0, 1; i128, 0, 1; isize, 0, 1;
+    f32, 0.0, 1.0; f64, 0.0, 1.0;
+);
+
+/// Modular is the (+, *) semiring over u64 reduced modulo M, for path counts
+/// that would otherwise overflow.  M must be positive.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Modular<const M: u64>;
+
+impl<const M: u64> Semiring for Modular<M> {
+    type Element = u64;
+
+    fn zero() -> u64 {
+        0
+    }
+
+    fn one() -> u64 {
+        1 % M
+    }
+
+    fn add(a: &u64, b: &u64) -> u64 {
+        ((*a as u128 + *b as u128) % M as u128) as u64
+    }
+
+    fn mul(a: &u64, b: &u64) -> u64 {
+        ((*a as u128 * *b as u128) % M as u128) as u64
+    }
+}
+
+/// Boolean is the (or, and) semiring.  Its closure is reachability.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Boolean;
+
+impl Semiring for Boolean {
+    type Element = bool;
+
+    fn zero() -> bool {
+        false
+    }
+
+    fn one() -> bool {
+        true
+    }
+
+    fn add(a: &bool, b: &bool) -> bool {
+        *a || *b
+    }
+
+    fn mul(a: &bool, b: &bool) -> bool {
+        *a && *b
+    }
+}
+
+/// MinPlus is the tropical (min, +) semiring.  None stands for an infinite
+/// distance (no edge), and Some(0) is the multiplicative identity.  Its closure
+/// is all-pairs shortest paths, provided there are no negative cycles.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct MinPlus<T> {
+    marker: PhantomData<T>,
+}
+
+impl<T> Semiring for MinPlus<T>
+where
+    T: Copy + Default + Ord + Add<Output = T>,
+{
+    type Element = Option<T>;
+
+    fn zero() -> Option<T> {
+        None
+    }
+
+    fn one() -> Option<T> {
+        Some(T::default())
+    }
+
+    fn add(a: &Option<T>, b: &Option<T>) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(*a.min(b)),
+            (Some(a), None) => Some(*a),
+            (None, b) => *b,
+        }
+    }
+
+    fn mul(a: &Option<T>, b: &Option<T>) -> Option<T> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some(*a + *b),
+            _ => None,
+        }
+    }
+}
+
+impl<T, I> DenseMatrix<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    /// matmul returns the product of self and other under the semiring S.  The
+    /// column count of self must equal the row count of other.
+    pub fn matmul<S>(&self, other: &DenseMatrix<T, I>) -> Result<DenseMatrix<T, I>>
+    where
+        S: Semiring<Element = T>,
+    {
+        if self.columns != other.rows {
+            return Err(Error::new(format!(
+                "cannot multiply {}x{} by {}x{}",
+                self.rows, self.columns, other.rows, other.columns
+            )));
+        }
+        let (rows, inner, columns) = (to_usize(self.rows), to_usize(self.columns), to_usize(other.columns));
+        let mut data = Vec::with_capacity(rows * columns);
+        for r in 0..rows {
+            for c in 0..columns {
+                let mut sum = S::zero();
+                for k in 0..inner {
+                    sum = S::add(&sum, &S::mul(&self.data[r * inner + k], &other.data[k * columns + c]));
+                }
+                data.push(sum);
+            }
+        }
+        DenseMatrix::from_raw_parts(self.rows, other.columns, data)
+    }
+
+    /// pow raises a square matrix to the power exponent under the semiring S by
+    /// repeated squaring.  The zeroth power is the identity matrix.
+    pub fn pow<S>(&self, exponent: u64) -> Result<DenseMatrix<T, I>>
+    where
+        S: Semiring<Element = T>,
+    {
+        let mut result = self.identity::<S>()?;
+        let mut base = DenseMatrix::new(self.columns, self.rows, self.data.clone());
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.matmul::<S>(&base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.matmul::<S>(&base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// closure returns the reflexive transitive closure (the Kleene star
+    /// I + A + A^2 + ...) of a square matrix under the semiring S, using the
+    /// Floyd-Warshall recurrence.  The result is only meaningful when addition in
+    /// S is idempotent and the star of every element is one, as for Boolean
+    /// (reachability) and MinPlus without negative cycles (shortest paths).
+    pub fn closure<S>(&self) -> Result<DenseMatrix<T, I>>
+    where
+        S: Semiring<Element = T>,
+    {
+        let mut result = self.identity::<S>()?;
+        let n = to_usize(self.rows);
+        for (cell, value) in result.data.iter_mut().zip(self.data.iter()) {
+            *cell = S::add(cell, value);
+        }
+        for k in 0..n {
+            for i in 0..n {
+                let via = result.data[i * n + k].clone();
+                for j in 0..n {
+                    let candidate = S::mul(&via, &result.data[k * n + j]);
+                    result.data[i * n + j] = S::add(&result.data[i * n + j], &candidate);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    // identity returns the identity matrix under S with the shape of self, which
+    // must be square.
+    fn identity<S>(&self) -> Result<DenseMatrix<T, I>>
+    where
+        S: Semiring<Element = T>,
+    {
+        if self.rows != self.columns {
+            return Err(Error::new(format!(
+                "operation requires a square matrix, got {}x{}",
+                self.rows, self.columns
+            )));
+        }
+        let n = to_usize(self.rows);
+        let mut data = vec![S::zero(); n * n];
+        for i in 0..n {
+            data[i * n + i] = S::one();
+        }
+        Ok(DenseMatrix::new(self.columns, self.rows, data))
+    }
+}
+
+fn to_usize<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("dimension overflows usize.  This should be unreachable."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::new_matrix;
+    use super::*;
+
+    #[test]
+    fn arithmetic_matmul_and_pow() {
+        let a = new_matrix::<u64, u8>(2, vec![1, 1, 1, 0]).unwrap();
+        let fib = a.pow::<Arithmetic<u64>>(10).unwrap();
+        assert_eq!(fib.data, vec![89, 55, 55, 34]);
+        assert_eq!(a.pow::<Arithmetic<u64>>(0).unwrap().data, vec![1, 0, 0, 1]);
+        let column = new_matrix::<u64, u8>(2, vec![2, 3]).unwrap();
+        assert_eq!(a.matmul::<Arithmetic<u64>>(&column).unwrap().data, vec![5, 2]);
+        assert_eq!(
+            column.matmul::<Arithmetic<u64>>(&a),
+            Err(Error::new("cannot multiply 2x1 by 2x2".to_string()))
+        );
+        assert!(column.pow::<Arithmetic<u64>>(2).is_err());
+    }
+
+    #[test]
+    fn modular_pow() {
+        let a = new_matrix::<u64, u8>(2, vec![1, 1, 1, 0]).unwrap();
+        assert_eq!(a.pow::<Modular<10>>(10).unwrap().data, vec![9, 5, 5, 4]);
+    }
+
+    #[test]
+    fn boolean_closure_is_reachability() {
+        // 0 -> 1 -> 2, 3 isolated.
+        let mut edges = vec![false; 16];
+        edges[1] = true;
+        edges[4 + 2] = true;
+        let a = new_matrix::<bool, u8>(4, edges).unwrap();
+        let reach = a.closure::<Boolean>().unwrap();
+        assert!(reach.data[2]);
+        assert!(reach.data[3 * 4 + 3]);
+        assert!(!reach.data[2 * 4]);
+        assert!(!reach.data[3]);
+    }
+
+    #[test]
+    fn min_plus_closure_is_shortest_paths() {
+        let a = new_matrix::<Option<u32>, u8>(3, vec![
+            None, Some(4), Some(10),
+            None, None, Some(3),
+            Some(1), None, None,
+        ]).unwrap();
+        let d = a.closure::<MinPlus<u32>>().unwrap();
+        assert_eq!(d.data, vec![
+            Some(0), Some(4), Some(7),
+            Some(4), Some(0), Some(3),
+            Some(1), Some(5), Some(0),
+        ]);
+        assert_eq!(a.pow::<MinPlus<u32>>(2).unwrap().data[2], Some(7));
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::io::{Read, Write};
+use crate::error::{Error, Result};
+
+/// HEADER_MAGIC opens every serialized matrix.
+const HEADER_MAGIC: &[u8; 4] = b"RAMX";
+
+/// HEADER_VERSION is the header layout written by write_header.
+pub const HEADER_VERSION: u16 = 1;
+
+/// MatrixHeader describes a serialized matrix: a tag naming the element type,
+/// the dimensions, and free-form key/value metadata.  It is written ahead of
+/// the payload so tools can inspect a file with peek_header without reading
+/// the cells.
+///
+/// The encoding is the magic bytes "RAMX", the version as a little-endian u16,
+/// the element type, rows and columns as little-endian u64s, a little-endian
+/// u32 metadata entry count, then each key and value.  Strings are a
+/// little-endian u32 byte length followed by UTF-8 bytes.  The default header
+/// has version HEADER_VERSION.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatrixHeader {
+    pub version: u16,
+    pub element_type: String,
+    pub rows: u64,
+    pub columns: u64,
+    pub metadata: Vec<(String, String)>,
+}
+
+impl Default for MatrixHeader {
+    fn default() -> Self {
+        MatrixHeader {
+            version: HEADER_VERSION,
+            element_type: String::new(),
+            rows: 0,
+            columns: 0,
+            metadata: Vec::new(),
+        }
+    }
+}
+
+impl MatrixHeader {
+    /// write_header encodes the header to writer.  Only HEADER_VERSION can be
+    /// written, so it is an error if the version field holds anything else.
+    pub fn write_header(&self, writer: &mut impl Write) -> Result<()> {
+        if self.version != HEADER_VERSION {
+            return Err(Error::new(format!("unsupported header version {}", self.version)));
+        }
+        let count: u32 = match self.metadata.len().try_into() {
+            Ok(v) => v,
+            Err(_) => return Err(Error::new("too many metadata entries".to_string())),
+        };
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(HEADER_MAGIC);
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        push_string(&mut bytes, &self.element_type)?;
+        bytes.extend_from_slice(&self.rows.to_le_bytes());
+        bytes.extend_from_slice(&self.columns.to_le_bytes());
+        bytes.extend_from_slice(&count.to_le_bytes());
+        for (key, value) in &self.metadata {
+            push_string(&mut bytes, key)?;
+            push_string(&mut bytes, value)?;
+        }
+        writer.write_all(&bytes).map_err(io_error)
+    }
+
+    /// metadata_value returns the value of the first metadata entry named key.
+    pub fn metadata_value(&self, key: &str) -> Option<&str> {
+        self.metadata.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+}
+
+/// peek_header reads a header from reader, consuming only the header bytes so
+/// the payload that follows is left unread.
+pub fn peek_header(reader: &mut impl Read) -> Result<MatrixHeader> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic).map_err(io_error)?;
+    if &magic != HEADER_MAGIC {
+        return Err(Error::new("not a serialized matrix".to_string()));
+    }
+    let version = u16::from_le_bytes(read_array(reader)?);
+    if version != HEADER_VERSION {
+        return Err(Error::new(format!("unsupported header version {}", version)));
+    }
+    let element_type = read_string(reader)?;
+    let rows = u64::from_le_bytes(read_array(reader)?);
+    let columns = u64::from_le_bytes(read_array(reader)?);
+    let count = u32::from_le_bytes(read_array(reader)?);
+    let mut metadata = Vec::new();
+    for _ in 0..count {
+        let key = read_string(reader)?;
+        let value = read_string(reader)?;
+        metadata.push((key, value));
+    }
+    Ok(MatrixHeader { version, element_type, rows, columns, metadata })
+}
+
+fn io_error(e: std::io::Error) -> Error {
+    Error::new(format!("matrix header i/o failed: {}", e))
+}
+
+fn push_string(bytes: &mut Vec<u8>, s: &str) -> Result<()> {
+    let len: u32 = match s.len().try_into() {
+        Ok(v) => v,
+        Err(_) => return Err(Error::new("header string too long".to_string())),
+    };
+    bytes.extend_from_slice(&len.to_le_bytes());
+    bytes.extend_from_slice(s.as_bytes());
+    Ok(())
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> Result<[u8; N]> {
+    let mut buf = [0u8; N];
+    reader.read_exact(&mut buf).map_err(io_error)?;
+    Ok(buf)
+}
+
+fn read_string(reader: &mut impl Read) -> Result<String> {
+    let len = u32::from_le_bytes(read_array(reader)?) as usize;
+    let mut buf = Vec::new();
+    reader.take(len as u64).read_to_end(&mut buf).map_err(io_error)?;
+    if buf.len() != len {
+        return Err(Error::new("matrix header is truncated".to_string()));
+    }
+    String::from_utf8(buf).map_err(|_| Error::new("matrix header string is not UTF-8".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_round_trip() {
+        let header = MatrixHeader {
+            version: HEADER_VERSION,
+            element_type: "u8".to_string(),
+            rows: 140,
+            columns: 141,
+            metadata: vec![("puzzle".to_string(), "2024-12-10".to_string())],
+        };
+        let mut bytes = Vec::new();
+        header.write_header(&mut bytes).unwrap();
+        bytes.extend_from_slice(b"payload");
+        let mut reader = bytes.as_slice();
+        let got = peek_header(&mut reader).unwrap();
+        assert_eq!(got, header);
+        assert_eq!(got.metadata_value("puzzle"), Some("2024-12-10"));
+        assert_eq!(reader, b"payload");
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        assert_eq!(
+            peek_header(&mut &b"XXXX"[..]).err(),
+            Some(Error::new("not a serialized matrix".to_string()))
+        );
+        assert_eq!(
+            peek_header(&mut &b"RAMX\x02\x00"[..]).err(),
+            Some(Error::new("unsupported header version 2".to_string()))
+        );
+        let mut bytes = Vec::new();
+        let future = MatrixHeader { version: HEADER_VERSION + 1, ..MatrixHeader::default() };
+        assert_eq!(
+            future.write_header(&mut bytes).err(),
+            Some(Error::new("unsupported header version 2".to_string()))
+        );
+        assert!(bytes.is_empty());
+        MatrixHeader::default().write_header(&mut bytes).unwrap();
+        bytes.truncate(bytes.len() - 1);
+        assert!(peek_header(&mut bytes.as_slice()).is_err());
+    }
+}
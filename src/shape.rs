@@ -0,0 +1,63 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::fmt::{Display, Formatter};
+use crate::{Coordinate, Matrix};
+use crate::error::{Error, Result};
+
+/// Shape is the extent of a matrix: its number of rows and columns.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Shape<I>
+where
+    I: Coordinate,
+{
+    pub rows: I,
+    pub columns: I,
+}
+
+impl<I> Display for Shape<I>
+where
+    I: Coordinate,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&format!("{}x{}", self.rows, self.columns))
+    }
+}
+
+/// assert_same_shape returns an error naming both shapes if a and b differ in
+/// shape.  Binary operations over pairs of matrices use it so that dimension
+/// mismatches are reported consistently.
+pub fn assert_same_shape<'a, 'b, A, B, I>(a: &dyn Matrix<'a, A, I>, b: &dyn Matrix<'b, B, I>) -> Result<()>
+where
+    A: 'static,
+    B: 'static,
+    I: Coordinate,
+{
+    let (a, b) = (a.shape(), b.shape());
+    if a == b {
+        Ok(())
+    } else {
+        Err(Error::new(format!("matrix shapes differ: {} vs {}", a, b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[allow(deprecated)]
+    use crate::factories::{new_default_matrix, new_transposed_matrix};
+    use super::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn shapes_compare() {
+        let a = new_default_matrix::<u8, u8>(3, 2).unwrap();
+        let mut b = new_default_matrix::<char, u8>(3, 2).unwrap();
+        assert_eq!(a.shape(), Shape { rows: 2, columns: 3 });
+        assert_eq!(a.shape().to_string(), "2x3");
+        assert!(assert_same_shape(&a, &b).is_ok());
+        let t = new_transposed_matrix(&mut b);
+        assert_eq!(
+            assert_same_shape(&a, &t),
+            Err(Error::new("matrix shapes differ: 2x3 vs 3x2".to_string()))
+        );
+    }
+}
@@ -0,0 +1,392 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut, Range};
+use crate::{Coordinate, Matrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixRowsIterator, MatrixValueIterator, Tensor};
+use crate::column::Column;
+use crate::row::Row;
+
+/// SparseMatrix stores only the cells that differ from a configured default
+/// value, which every other cell reads as.  It suits grids that are huge but
+/// mostly empty.
+#[derive(Clone, Debug)]
+pub struct SparseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) columns: I,
+    pub(crate) rows: I,
+    pub(crate) default: T,
+    pub(crate) cells: HashMap<MatrixAddress<I>, T>,
+}
+
+impl <T, I> SparseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    /// default_value returns the value of every cell that has not been set.
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// occupied_count returns the number of cells held in storage.
+    pub fn occupied_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// occupied returns the stored (address, value) pairs in row-major order.
+    pub fn occupied(&self) -> std::vec::IntoIter<(MatrixAddress<I>, &T)> {
+        let mut cells: Vec<(MatrixAddress<I>, &T)> = self.cells.iter()
+            .map(|(addr, value)| (*addr, value))
+            .collect();
+        cells.sort_by_key(|(addr, _)| *addr);
+        cells.into_iter()
+    }
+
+    /// occupied_mut returns the stored (address, value) pairs in row-major
+    /// order, for modification.  Unset cells are not visited, so a pass over
+    /// the matrix never stores the default value.
+    pub fn occupied_mut(&mut self) -> std::vec::IntoIter<(MatrixAddress<I>, &mut T)> {
+        let mut cells: Vec<(MatrixAddress<I>, &mut T)> = self.cells.iter_mut()
+            .map(|(addr, value)| (*addr, value))
+            .collect();
+        cells.sort_by_key(|(addr, _)| *addr);
+        cells.into_iter()
+    }
+
+    /// clear resets every cell to the default value.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+}
+
+impl <T, I> SparseMatrix<T, I>
+where
+    T: Clone + PartialEq,
+    I: Coordinate,
+{
+    /// set writes value at address, releasing the storage for the cell if the
+    /// value is the default.  Returns false for out of bounds addresses.
+    pub fn set(&mut self, address: MatrixAddress<I>, value: T) -> bool {
+        if !self.contains(address) {
+            return false;
+        }
+        if value == self.default {
+            self.cells.remove(&address);
+        } else {
+            self.cells.insert(address, value);
+        }
+        true
+    }
+
+    /// iter_non_default returns the stored (address, value) pairs whose value
+    /// differs from the default value, in row-major order.
+    pub fn iter_non_default(&self) -> std::vec::IntoIter<(MatrixAddress<I>, &T)> {
+        let default = &self.default;
+        let mut cells: Vec<(MatrixAddress<I>, &T)> = self.cells.iter()
+            .filter(|(_, value)| *value != default)
+            .map(|(addr, value)| (*addr, value))
+            .collect();
+        cells.sort_by_key(|(addr, _)| *addr);
+        cells.into_iter()
+    }
+
+    /// compact releases the storage of cells that hold the default value, as
+    /// can happen after mutation through get_mut or occupied_mut.
+    pub fn compact(&mut self) {
+        let default = &self.default;
+        self.cells.retain(|_, value| value != default);
+    }
+}
+
+impl <T, I> SparseMatrix<T, I>
+where
+    T: Default + PartialEq,
+    I: Coordinate,
+{
+    /// row_nonzero_counts returns, for each row, the number of cells that differ
+    /// from T::default().  Only stored cells are visited.
+    pub fn row_nonzero_counts(&self) -> Vec<usize> {
+        self.nonzero_counts(self.rows, self.columns, |addr| addr.row)
+    }
+
+    /// column_nonzero_counts returns, for each column, the number of cells that
+    /// differ from T::default().  Only stored cells are visited.
+    pub fn column_nonzero_counts(&self) -> Vec<usize> {
+        self.nonzero_counts(self.columns, self.rows, |addr| addr.column)
+    }
+
+    /// degree returns the number of cells in row node that differ from
+    /// T::default(), the out-degree of node when the matrix is an adjacency
+    /// matrix.  Out of range nodes have degree 0.
+    pub fn degree(&self, node: I) -> usize {
+        if node < I::default() || node >= self.rows {
+            return 0;
+        }
+        let zero = T::default();
+        let stored = self.cells.iter().filter(|(addr, _)| addr.row == node);
+        if self.default == zero {
+            stored.filter(|(_, value)| **value != zero).count()
+        } else {
+            let columns: usize = match self.columns.try_into() {
+                Ok(v) => v,
+                Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+            };
+            columns - stored.filter(|(_, value)| **value == zero).count()
+        }
+    }
+
+    // nonzero_counts tallies the cells differing from T::default() in each of
+    // lines lines of length line_length, where line picks a cell's line.  When
+    // the default value is not T::default(), every unstored cell counts.
+    fn nonzero_counts(&self, lines: I, line_length: I, line: impl Fn(&MatrixAddress<I>) -> I) -> Vec<usize> {
+        let to_usize = |v: I| -> usize {
+            match v.try_into() {
+                Ok(v) => v,
+                Err(_) => panic!("dimension overflows usize.  This should be unreachable."),
+            }
+        };
+        let zero = T::default();
+        let default_is_zero = self.default == zero;
+        let mut counts = vec![if default_is_zero { 0 } else { to_usize(line_length) }; to_usize(lines)];
+        for (addr, value) in self.cells.iter() {
+            let index = to_usize(line(addr));
+            match (default_is_zero, *value == zero) {
+                (true, false) => counts[index] += 1,
+                (false, true) => counts[index] -= 1,
+                _ => {},
+            }
+        }
+        counts
+    }
+}
+
+impl <'a, T, I> Matrix<'a, T, I> for SparseMatrix<T, I>
+where
+    T: 'static + Clone,
+    I: Coordinate,
+{
+    fn row_count(&self) -> I {
+        self.rows
+    }
+
+    fn column_count(&self) -> I {
+        self.columns
+    }
+
+    fn iter(&'a self) -> MatrixValueIterator<'a, T, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        MatrixForwardIterator::new(MatrixAddress {
+            column: self.columns,
+            row: self.rows,
+        })
+    }
+
+    fn indexed_iter(&'a self) -> MatrixForwardIndexedIterator<'a, T, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'a self, row_num: I) -> Option<Row<'a, T, I>> {
+        if row_num < I::default() || row_num >= self.rows {
+            None
+        } else {
+            Some(Row::new(self, row_num))
+        }
+    }
+
+    fn column(&'a self, column_num: I) -> Option<Column<'a, T, I>> {
+        if column_num < I::default() || column_num >= self.columns {
+            None
+        } else {
+            Some(Column::new(self, column_num))
+        }
+    }
+
+    fn rows(&'a self) -> MatrixRowsIterator<'a, T, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'a self) -> MatrixColumnsIterator<'a, T, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+impl <T, I> Tensor<T, I, MatrixAddress<I>, 2> for SparseMatrix<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        Range {
+            start: MatrixAddress::default(),
+            end: MatrixAddress {
+                column: self.columns,
+                row: self.rows,
+            },
+        }
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        if !self.contains(address) {
+            None
+        } else {
+            Some(self.cells.get(&address).unwrap_or(&self.default))
+        }
+    }
+
+    /// get_mut stores a copy of the default value for unset cells, so that it
+    /// can be mutated.
+    fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        if !self.contains(address) {
+            None
+        } else {
+            Some(self.cells.entry(address).or_insert_with(|| self.default.clone()))
+        }
+    }
+}
+
+impl<T, I> Index<MatrixAddress<I>> for SparseMatrix<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, index: MatrixAddress<I>) -> &Self::Output {
+        match self.get(index) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl<T, I> IndexMut<MatrixAddress<I>> for SparseMatrix<T, I>
+where
+    T: Clone,
+    I: Coordinate,
+{
+    fn index_mut(&mut self, index: MatrixAddress<I>) -> &mut T {
+        match self.get_mut(index) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::factories::{new_sparse_matrix, new_sparse_matrix_of};
+    use crate::format::FormatOptions;
+    use crate::shape::Shape;
+    use super::*;
+
+    fn u32addr(row: u32, column: u32) -> MatrixAddress<u32> {
+        MatrixAddress { row, column }
+    }
+
+    #[test]
+    fn nonzero_counts_and_degree() {
+        let mut m = new_sparse_matrix::<u8, u32>(3, 4, 0).unwrap();
+        m.set(u32addr(0, 1), 5);
+        m.set(u32addr(0, 3), 2);
+        m.set(u32addr(2, 1), 1);
+        assert_eq!(m.row_nonzero_counts(), vec![2, 0, 1]);
+        assert_eq!(m.column_nonzero_counts(), vec![0, 2, 0, 1]);
+        assert_eq!(m.degree(0), 2);
+        assert_eq!(m.degree(3), 0);
+        let mut ones = new_sparse_matrix::<u8, u32>(2, 2, 1).unwrap();
+        ones.set(u32addr(1, 0), 0);
+        assert_eq!(ones.row_nonzero_counts(), vec![2, 1]);
+        assert_eq!(ones.column_nonzero_counts(), vec![1, 2]);
+        assert_eq!(ones.degree(1), 1);
+        assert_eq!(ones.to_dense().row_nonzero_counts(), vec![2, 1]);
+    }
+
+    #[test]
+    fn unset_cells_read_default() {
+        let m = new_sparse_matrix::<char, u32>(1_000_000, 1_000_000, '.').unwrap();
+        assert_eq!(m.row_count(), 1_000_000);
+        assert_eq!(m[u32addr(999_999, 5)], '.');
+        assert_eq!(m.get(u32addr(1_000_000, 0)), None);
+        assert_eq!(m.occupied_count(), 0);
+    }
+
+    #[test]
+    fn set_stores_only_non_default() {
+        let mut m = new_sparse_matrix::<char, u32>(3, 4, '.').unwrap();
+        assert!(m.set(u32addr(2, 1), '#'));
+        assert!(m.set(u32addr(0, 3), '#'));
+        assert!(m.set(u32addr(1, 1), '.'));
+        assert!(!m.set(u32addr(3, 0), '#'));
+        assert_eq!(m.occupied_count(), 2);
+        let occupied: Vec<(MatrixAddress<u32>, &char)> = m.occupied().collect();
+        assert_eq!(occupied, vec![(u32addr(0, 3), &'#'), (u32addr(2, 1), &'#')]);
+        assert!(m.set(u32addr(2, 1), '.'));
+        assert_eq!(m.occupied_count(), 1);
+        let got = FormatOptions::default().format(&m, |c| c.to_string());
+        assert_eq!(got, "...#\n....\n....");
+    }
+
+    #[test]
+    fn to_dense_fills_defaults() {
+        let mut m = new_sparse_matrix::<char, u32>(3, 4, '.').unwrap();
+        m.set(u32addr(0, 3), '#');
+        let dense = m.to_dense();
+        assert_eq!(dense.shape(), m.shape());
+        assert_eq!(dense.iter().filter(|c| **c == '#').count(), 1);
+        assert_eq!(dense[u32addr(0, 3)], '#');
+        assert_eq!(dense[u32addr(2, 1)], '.');
+    }
+
+    #[test]
+    fn mutation_and_compact() {
+        let mut m = new_sparse_matrix::<u8, u8>(2, 2, 0).unwrap();
+        m[MatrixAddress { row: 1, column: 0 }] = 7;
+        *m.get_mut(MatrixAddress { row: 0, column: 0 }).unwrap() += 0;
+        assert_eq!(m.occupied_count(), 2);
+        assert_eq!(m.iter_non_default().count(), 1);
+        m.compact();
+        assert_eq!(m.occupied_count(), 1);
+        m.clear();
+        assert_eq!(m.occupied_count(), 0);
+    }
+
+    #[test]
+    fn occupied_mut_visits_stored_cells() {
+        let mut m = new_sparse_matrix::<u8, u8>(3, 3, 0).unwrap();
+        m.set(MatrixAddress { row: 2, column: 1 }, 4);
+        m.set(MatrixAddress { row: 0, column: 2 }, 1);
+        let mut visited = Vec::new();
+        for (addr, value) in m.occupied_mut() {
+            visited.push(addr);
+            *value -= 1;
+        }
+        assert_eq!(visited, vec![MatrixAddress { row: 0, column: 2 }, MatrixAddress { row: 2, column: 1 }]);
+        assert_eq!(m.occupied_count(), 2);
+        assert_eq!(m.iter().copied().collect::<Vec<u8>>(), vec![0, 0, 0, 0, 0, 0, 0, 3, 0]);
+        m.compact();
+        assert_eq!(m.occupied_count(), 1);
+    }
+
+    #[test]
+    fn dimension_validation() {
+        assert_eq!(
+            new_sparse_matrix::<u8, i8>(-1, 2, 0).err(),
+            Some(Error::new("negative dimensions not supported".to_string()))
+        );
+        let empty = new_sparse_matrix::<u8, u8>(0, 5, 0).unwrap();
+        assert_eq!(empty.column_count(), 0);
+        assert!(empty.addresses().next().is_none());
+    }
+
+    #[test]
+    fn shaped_factory() {
+        let m = new_sparse_matrix_of::<char, u8>(Shape { rows: 2, columns: 3 }, '.').unwrap();
+        assert_eq!(m.shape(), Shape { rows: 2, columns: 3 });
+        assert_eq!(m[MatrixAddress { row: 1, column: 2 }], '.');
+    }
+}
@@ -0,0 +1,478 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Index, IndexMut, Range};
+use crate::{Coordinate, DenseMatrix, Matrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterMut, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixMut, MatrixRowsIterator, MatrixValueIterMut, MatrixValueIterator, Tensor};
+use crate::column::Column;
+use crate::error::{Error, Result};
+use crate::iter::MatrixWindowsIterator;
+use crate::row::Row;
+
+/// SubMatrix is a read-only view of a rectangular window of another matrix.
+/// Addresses are re-based so that the upper left corner of the window is
+/// (0, 0), which lets the usual iterators and formatters run over a region
+/// without copying it.
+///
+/// The view cannot mutate the underlying matrix, so get_mut returns None and
+/// the view does not implement MatrixMut.  Use SubMatrixMut to modify a region.
+pub struct SubMatrix<'a, T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) underlay: &'a dyn Matrix<'a, T, I>,
+    pub(crate) window: Range<MatrixAddress<I>>,
+}
+
+impl <'a, T, I> SubMatrix<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// window returns the range of underlying addresses covered by the view.
+    pub fn window(&self) -> Range<MatrixAddress<I>> {
+        self.window.clone()
+    }
+
+    /// to_underlay converts a view address into an address of the underlying
+    /// matrix.  None is returned for addresses outside the view.
+    pub fn to_underlay(&self, address: MatrixAddress<I>) -> Option<MatrixAddress<I>> {
+        to_underlay(&self.window, address)
+    }
+}
+
+/// to_underlay translates a re-based window address into an underlying address.
+/// The address is checked against the window's size before the window's start
+/// is added, so the sum cannot overflow.
+pub(crate) fn to_underlay<I>(window: &Range<MatrixAddress<I>>, address: MatrixAddress<I>) -> Option<MatrixAddress<I>>
+where
+    I: Coordinate,
+{
+    let zero = I::default();
+    if address.row < zero || address.column < zero {
+        return None;
+    }
+    let size = window.end - window.start;
+    if address.row < size.row && address.column < size.column {
+        Some(window.start + address)
+    } else {
+        None
+    }
+}
+
+impl <'a, T, I> Tensor<T, I, MatrixAddress<I>, 2> for SubMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        Range {
+            start: MatrixAddress::default(),
+            end: self.window.end - self.window.start,
+        }
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        self.underlay.get(self.to_underlay(address)?)
+    }
+
+    /// get_mut always returns None, as the view is read-only.
+    fn get_mut(&mut self, _address: MatrixAddress<I>) -> Option<&mut T> {
+        None
+    }
+}
+
+impl<'a, T, I> Index<MatrixAddress<I>> for SubMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, address: MatrixAddress<I>) -> &Self::Output {
+        match self.get(address) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl <'a: 'c, 'c, T, I> Matrix<'c, T, I> for SubMatrix<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn row_count(&self) -> I {
+        self.window.end.row - self.window.start.row
+    }
+
+    fn column_count(&self) -> I {
+        self.window.end.column - self.window.start.column
+    }
+
+    fn iter(&'c self) -> MatrixValueIterator<'c, T, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        MatrixForwardIterator::new(self.range().end)
+    }
+
+    fn indexed_iter(&'c self) -> MatrixForwardIndexedIterator<'c, T, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'c self, row_num: I) -> Option<Row<'c, T, I>> {
+        if row_num >= I::default() && row_num < self.row_count() {
+            Some(Row::new(self, row_num))
+        } else {
+            None
+        }
+    }
+
+    fn column(&'c self, col_num: I) -> Option<Column<'c, T, I>> {
+        if col_num >= I::default() && col_num < self.column_count() {
+            Some(Column::new(self, col_num))
+        } else {
+            None
+        }
+    }
+
+    fn rows(&'c self) -> MatrixRowsIterator<'c, T, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'c self) -> MatrixColumnsIterator<'c, T, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+/// SubMatrixMut is a mutable view of a rectangular window of another matrix,
+/// re-based in the same way as SubMatrix.  Writes through the view land in the
+/// underlying matrix, so a region of a board can be handed to code that
+/// mutates it in place.
+pub struct SubMatrixMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) underlay: &'a mut dyn MatrixMut<'a, T, I>,
+    pub(crate) window: Range<MatrixAddress<I>>,
+}
+
+impl <'a, T, I> SubMatrixMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// window returns the range of underlying addresses covered by the view.
+    pub fn window(&self) -> Range<MatrixAddress<I>> {
+        self.window.clone()
+    }
+
+    /// to_underlay converts a view address into an address of the underlying
+    /// matrix.  None is returned for addresses outside the view.
+    pub fn to_underlay(&self, address: MatrixAddress<I>) -> Option<MatrixAddress<I>> {
+        to_underlay(&self.window, address)
+    }
+}
+
+impl <'a, T, I> SubMatrixMut<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// window_values_mut collects the underlay's cells that fall in the window,
+    /// in the view's row-major order.
+    fn window_values_mut(&mut self) -> Box<dyn Iterator<Item = &mut T> + '_> {
+        let window = self.window.clone();
+        Box::new(self.underlay.indexed_iter_mut()
+            .filter(move |(addr, _)| window.start.row <= addr.row && addr.row < window.end.row
+                && window.start.column <= addr.column && addr.column < window.end.column)
+            .map(|(_, value)| value))
+    }
+}
+
+impl <'a, T, I> Tensor<T, I, MatrixAddress<I>, 2> for SubMatrixMut<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        Range {
+            start: MatrixAddress::default(),
+            end: self.window.end - self.window.start,
+        }
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        self.underlay.get(self.to_underlay(address)?)
+    }
+
+    fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        let underlay = self.to_underlay(address)?;
+        self.underlay.get_mut(underlay)
+    }
+}
+
+impl<'a, T, I> Index<MatrixAddress<I>> for SubMatrixMut<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, address: MatrixAddress<I>) -> &Self::Output {
+        match self.get(address) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl<'a, T, I> IndexMut<MatrixAddress<I>> for SubMatrixMut<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn index_mut(&mut self, address: MatrixAddress<I>) -> &mut Self::Output {
+        match self.get_mut(address) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl <'a: 'c, 'c, T, I> Matrix<'c, T, I> for SubMatrixMut<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn row_count(&self) -> I {
+        self.window.end.row - self.window.start.row
+    }
+
+    fn column_count(&self) -> I {
+        self.window.end.column - self.window.start.column
+    }
+
+    fn iter(&'c self) -> MatrixValueIterator<'c, T, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        MatrixForwardIterator::new(self.range().end)
+    }
+
+    fn indexed_iter(&'c self) -> MatrixForwardIndexedIterator<'c, T, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'c self, row_num: I) -> Option<Row<'c, T, I>> {
+        if row_num >= I::default() && row_num < self.row_count() {
+            Some(Row::new(self, row_num))
+        } else {
+            None
+        }
+    }
+
+    fn column(&'c self, col_num: I) -> Option<Column<'c, T, I>> {
+        if col_num >= I::default() && col_num < self.column_count() {
+            Some(Column::new(self, col_num))
+        } else {
+            None
+        }
+    }
+
+    fn rows(&'c self) -> MatrixRowsIterator<'c, T, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'c self) -> MatrixColumnsIterator<'c, T, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+impl <'a: 'c, 'c, T, I> MatrixMut<'c, T, I> for SubMatrixMut<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn iter_mut(&mut self) -> MatrixValueIterMut<'_, T, I> {
+        MatrixValueIterMut::new(self.window_values_mut())
+    }
+
+    fn indexed_iter_mut(&mut self) -> MatrixForwardIndexedIterMut<'_, T, I> {
+        let addrs = self.addresses();
+        MatrixForwardIndexedIterMut::new(addrs, self.window_values_mut())
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// windows returns a view of every overlapping window_rows x window_columns
+    /// window of the matrix, like slice::windows in two dimensions.  Windows are
+    /// ordered by their upper left corner in row-major order.  A window larger
+    /// than the matrix yields no views.
+    pub fn windows(&self, window_rows: I, window_columns: I) -> Result<MatrixWindowsIterator<'_, T, I>> {
+        let zero = I::default();
+        if window_rows <= zero || window_columns <= zero {
+            return Err(Error::new("window must have positive dimensions".to_string()));
+        }
+        Ok(MatrixWindowsIterator::new(self, MatrixAddress { row: window_rows, column: window_columns }))
+    }
+
+    /// pool reduces each non-overlapping window_rows x window_columns window of
+    /// the matrix to a single value with f, returning the matrix of reductions.
+    /// This gives max, min or sum pooling, or any custom downsampling.  The
+    /// matrix dimensions must be multiples of the window dimensions.
+    pub fn pool<U>(&self, window_rows: I, window_columns: I, f: impl Fn(SubMatrix<'_, T, I>) -> U) -> Result<DenseMatrix<U, I>> {
+        let zero = I::default();
+        if window_rows <= zero || window_columns <= zero {
+            return Err(Error::new("pool window must have positive dimensions".to_string()));
+        }
+        let to_usize = |v: I| -> usize {
+            match v.try_into() {
+                Ok(v) => v,
+                Err(_) => panic!("coordinate overflows usize.  This should be unreachable."),
+            }
+        };
+        let (rows, columns) = (to_usize(self.rows), to_usize(self.columns));
+        let (wr, wc) = (to_usize(window_rows), to_usize(window_columns));
+        if rows % wr != 0 || columns % wc != 0 {
+            return Err(Error::new(format!(
+                "matrix dimensions ({}x{}) are not multiples of the pool window ({}x{})",
+                self.rows, self.columns, window_rows, window_columns)));
+        }
+        let to_coordinate = |v: usize| -> I {
+            match v.try_into() {
+                Ok(v) => v,
+                Err(_) => panic!("pooled coordinate overflows index type.  This should be unreachable."),
+            }
+        };
+        let (out_rows, out_columns) = (to_coordinate(rows / wr), to_coordinate(columns / wc));
+        let window = MatrixAddress { row: window_rows, column: window_columns };
+        let data = MatrixForwardIterator::new(MatrixAddress { row: out_rows, column: out_columns })
+            .map(|cell| {
+                let start = MatrixAddress { row: cell.row * window_rows, column: cell.column * window_columns };
+                f(SubMatrix { underlay: self, window: start..start + window })
+            })
+            .collect();
+        Ok(DenseMatrix::new(out_columns, out_rows, data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+    use crate::factories::{new_default_matrix_of, new_sub_matrix, new_sub_matrix_mut};
+    use crate::format::FormatOptions;
+    use crate::shape::Shape;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    fn base() -> crate::DenseMatrix<char, u8> {
+        FormatOptions::default()
+            .parse_matrix("abcd\nefgh\nijkl", |x| x.chars().next().unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn window_is_rebased() {
+        let m = base();
+        let sub = new_sub_matrix(&m, u8addr(1, 1)..u8addr(3, 3)).unwrap();
+        assert_eq!(sub.row_count(), 2);
+        assert_eq!(sub.column_count(), 2);
+        assert_eq!(sub[u8addr(0, 0)], 'f');
+        assert_eq!(sub.get(u8addr(2, 0)), None);
+        assert_eq!(sub.to_underlay(u8addr(1, 1)), Some(u8addr(2, 2)));
+        assert_eq!(FormatOptions::default().format(&sub, |c| c.to_string()), "fg\njk");
+        let column: Vec<char> = sub.column(1).unwrap().iter().copied().collect();
+        assert_eq!(column, vec!['g', 'k']);
+    }
+
+    #[test]
+    fn addresses_beyond_window_do_not_overflow() {
+        let mut m = new_default_matrix_of::<u8, u8>(Shape { rows: 255, columns: 1 }).unwrap();
+        let window = u8addr(200, 0)..u8addr(255, 1);
+        let sub = new_sub_matrix(&m, window.clone()).unwrap();
+        assert_eq!(sub.get(u8addr(100, 0)), None);
+        assert_eq!(sub.get(u8addr(54, 0)), Some(&0));
+        let mut sub = new_sub_matrix_mut(&mut m, window).unwrap();
+        assert_eq!(sub.get_mut(u8addr(100, 0)), None);
+    }
+
+    #[test]
+    fn window_validation() {
+        let m = base();
+        assert_eq!(
+            new_sub_matrix(&m, u8addr(1, 1)..u8addr(4, 2)).err(),
+            Some(Error::new("sub matrix window exceeds the underlying matrix".to_string()))
+        );
+        let empty = new_sub_matrix(&m, u8addr(1, 2)..u8addr(3, 2)).unwrap();
+        assert_eq!(empty.row_count(), 0);
+        assert!(empty.addresses().next().is_none());
+    }
+
+    #[test]
+    fn sliding_windows() {
+        let m = base();
+        let got: Vec<String> = m.windows(2, 3).unwrap()
+            .map(|w| FormatOptions::default().format(&w, |c| c.to_string()))
+            .collect();
+        assert_eq!(got, vec!["abc\nefg", "bcd\nfgh", "efg\nijk", "fgh\njkl"]);
+        assert_eq!(m.windows(1, 1).unwrap().count(), 12);
+        assert_eq!(m.windows(3, 4).unwrap().count(), 1);
+        assert_eq!(m.windows(4, 1).unwrap().count(), 0);
+        assert!(m.windows(2, 0).is_err());
+        let windows = m.windows(2, 2).unwrap();
+        let origins: Vec<MatrixAddress<u8>> = windows.map(|w| w.window().start).collect();
+        assert_eq!(origins[3], u8addr(1, 0));
+    }
+
+    #[test]
+    fn max_pooling() {
+        let m = crate::factories::new_matrix::<u8, u8>(4, (0..24).collect()).unwrap();
+        let pooled = m.pool(2, 3, |w| *w.iter().max().unwrap()).unwrap();
+        assert_eq!(FormatOptions::default().format(&pooled, |v| format!("{:2}", v)), " 811\n2023");
+        let sums = m.pool(4, 1, |w| w.iter().map(|v| u32::from(*v)).sum::<u32>()).unwrap();
+        assert_eq!(sums.row_count(), 1);
+        assert_eq!(sums[u8addr(0, 5)], 5 + 11 + 17 + 23);
+        assert_eq!(
+            m.pool(3, 3, |_| 0).err(),
+            Some(Error::new("matrix dimensions (4x6) are not multiples of the pool window (3x3)".to_string()))
+        );
+        assert!(m.pool(0, 3, |_| 0).is_err());
+    }
+
+    #[test]
+    fn cells_cannot_be_borrowed_mutably() {
+        let m = base();
+        let mut sub = new_sub_matrix(&m, u8addr(0, 0)..u8addr(1, 1)).unwrap();
+        assert!(sub.get_mut(u8addr(0, 0)).is_none());
+    }
+
+    fn uppercase(view: &mut SubMatrixMut<char, u8>) {
+        for value in view.iter_mut() {
+            *value = value.to_ascii_uppercase();
+        }
+    }
+
+    #[test]
+    fn mutation_through_window() {
+        let mut m = base();
+        let mut sub = new_sub_matrix_mut(&mut m, u8addr(1, 1)..u8addr(3, 4)).unwrap();
+        assert_eq!(sub.row_count(), 2);
+        assert_eq!(sub.column_count(), 3);
+        sub[u8addr(0, 2)] = '#';
+        assert!(sub.get_mut(u8addr(2, 0)).is_none());
+        uppercase(&mut sub);
+        let indexed: Vec<(MatrixAddress<u8>, char)> = sub.indexed_iter_mut().map(|(a, v)| (a, *v)).take(4).collect();
+        assert_eq!(indexed, vec![
+            (u8addr(0, 0), 'F'), (u8addr(0, 1), 'G'), (u8addr(0, 2), '#'), (u8addr(1, 0), 'J'),
+        ]);
+        let got = FormatOptions::default().format(&m, |c| c.to_string());
+        assert_eq!(got, "abcd\neFG#\niJKL");
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+//! Assertions and constructors for writing tests against matrices.
+
+use std::fmt::Display;
+use crate::{Coordinate, DenseMatrix, Matrix, MatrixAddress};
+use crate::error::{Error, Result};
+use crate::factories::new_matrix;
+
+/// matrix_from_rows builds a matrix from a vector of rows, each of which must
+/// have the same length.  It backs the matrix! macro.
+pub fn matrix_from_rows<T, I>(rows: Vec<Vec<T>>) -> Result<DenseMatrix<T, I>>
+where
+    I: Coordinate,
+{
+    let columns = rows.first().map(|r| r.len()).unwrap_or_default();
+    if let Some(row) = rows.iter().position(|r| r.len() != columns) {
+        return Err(Error::new(format!("row {} length differs from row 0", row)));
+    }
+    let row_count: I = match rows.len().try_into() {
+        Ok(v) => v,
+        Err(_) => return Err(Error::new("row count overflows index type".to_string())),
+    };
+    new_matrix(row_count, rows.into_iter().flatten().collect())
+}
+
+/// describe_matrix_mismatch returns None if the matrices have the same shape
+/// and contents, and otherwise a report showing both matrices and the first
+/// differing cells.  It backs the assert_matrix_eq! macro.
+pub fn describe_matrix_mismatch<'a, 'b, T, I>(left: &'a dyn Matrix<'a, T, I>, right: &'b dyn Matrix<'b, T, I>) -> Option<String>
+where
+    T: 'static + PartialEq + Display,
+    I: Coordinate,
+{
+    let mut problems = Vec::new();
+    if left.shape() != right.shape() {
+        problems.push(format!("shapes differ: {} vs {}", left.shape(), right.shape()));
+    } else {
+        let differing: Vec<MatrixAddress<I>> = left.addresses()
+            .filter(|a| left.get(*a) != right.get(*a))
+            .collect();
+        if differing.is_empty() {
+            return None;
+        }
+        problems.push(format!("{} cells differ", differing.len()));
+        for addr in differing.iter().take(10) {
+            problems.push(format!("  at {}: {} != {}", addr, left[*addr], right[*addr]));
+        }
+    }
+    Some(format!("{}\nleft:\n{}\nright:\n{}", problems.join("\n"), render(left), render(right)))
+}
+
+/// render writes a matrix with its columns separated by spaces.
+fn render<'a, T, I>(matrix: &'a dyn Matrix<'a, T, I>) -> String
+where
+    T: 'static + Display,
+    I: Coordinate,
+{
+    let mut text = String::new();
+    for addr in matrix.addresses() {
+        if addr.column > I::default() {
+            text.push(' ');
+        } else if addr.row > I::default() {
+            text.push('\n');
+        }
+        text.push_str(&matrix[addr].to_string());
+    }
+    text
+}
+
+/// matrix! builds a DenseMatrix from rows of values written as nested
+/// brackets, panicking if the rows differ in length.  The index type is
+/// inferred, so annotate the binding if nothing else fixes it.
+///
+/// ```
+/// use rust_advent_matrix::{matrix, DenseMatrix, Matrix};
+/// let m: DenseMatrix<u8, u8> = matrix![[1, 2, 3], [4, 5, 6]];
+/// assert_eq!(m.row_count(), 2);
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($([$($value:expr),* $(,)?]),* $(,)?) => {
+        $crate::testing::matrix_from_rows(vec![$(vec![$($value),*]),*]).unwrap()
+    };
+}
+
+/// assert_matrix_eq! asserts that two matrices have the same shape and
+/// contents, printing both matrices and the differing cells on failure.
+#[macro_export]
+macro_rules! assert_matrix_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        if let Some(report) = $crate::testing::describe_matrix_mismatch(&$left, &$right) {
+            panic!("assertion `left == right` failed for matrices\n{}", report);
+        }
+    };
+}
+
+/// assert_shape! asserts that a matrix has the given row and column counts.
+#[macro_export]
+macro_rules! assert_shape {
+    ($matrix:expr, $rows:expr, $columns:expr $(,)?) => {
+        assert_eq!(
+            $crate::Matrix::shape(&$matrix),
+            $crate::Shape { rows: $rows, columns: $columns },
+            "matrix shape mismatch"
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::FormatOptions;
+    use super::*;
+
+    #[test]
+    fn literal_matrices() {
+        let m: DenseMatrix<char, u8> = matrix![['a', 'b'], ['c', 'd'], ['e', 'f']];
+        assert_shape!(m, 3, 2);
+        let parsed = FormatOptions::default()
+            .parse_matrix::<char, u8>("ab\ncd\nef", |x| x.chars().next().unwrap())
+            .unwrap();
+        assert_matrix_eq!(m, parsed);
+        assert_eq!(
+            matrix_from_rows::<u8, u8>(vec![vec![1, 2], vec![3]]).err(),
+            Some(Error::new("row 1 length differs from row 0".to_string()))
+        );
+    }
+
+    #[test]
+    fn mismatch_report() {
+        let a: DenseMatrix<u8, u8> = matrix![[1, 2], [3, 4]];
+        let b: DenseMatrix<u8, u8> = matrix![[1, 2], [3, 5]];
+        let report = describe_matrix_mismatch(&a, &b).unwrap();
+        assert_eq!(report, "1 cells differ\n  at (row=1,col=1): 4 != 5\nleft:\n1 2\n3 4\nright:\n1 2\n3 5");
+        let c: DenseMatrix<u8, u8> = matrix![[1, 2]];
+        assert!(describe_matrix_mismatch(&a, &c).unwrap().starts_with("shapes differ: 2x2 vs 1x2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "failed for matrices")]
+    fn assert_matrix_eq_panics() {
+        let a: DenseMatrix<u8, u8> = matrix![[1, 2]];
+        let b: DenseMatrix<u8, u8> = matrix![[2, 1]];
+        assert_matrix_eq!(a, b);
+    }
+}
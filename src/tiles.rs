@@ -0,0 +1,188 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::Coordinate;
+use crate::dense_matrix::DenseMatrix;
+use crate::error::{Error, Result};
+use crate::matrix_address::MatrixAddress;
+
+/// TileMut is a mutable view of one rectangular tile of a DenseMatrix, made by
+/// tiles_mut or par_chunks_mut.  It holds a borrowed slice of each row of the
+/// tile, so tiles of the same matrix never overlap and may be modified on
+/// different threads at once.  Addresses passed to get and get_mut are
+/// relative to the tile's origin.
+#[derive(Debug)]
+pub struct TileMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) origin: MatrixAddress<I>,
+    pub(crate) rows: Vec<&'a mut [T]>,
+}
+
+impl <'a, T, I> TileMut<'a, T, I>
+where
+    I: Coordinate,
+{
+    /// origin returns the address in the whole matrix of the tile's upper left
+    /// cell.
+    pub fn origin(&self) -> MatrixAddress<I> {
+        self.origin
+    }
+
+    /// row_count returns the number of rows in the tile.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// column_count returns the number of columns in the tile.
+    pub fn column_count(&self) -> usize {
+        self.rows.first().map_or(0, |row| row.len())
+    }
+
+    /// get returns the cell at address, relative to the tile's origin.  None is
+    /// returned for addresses outside the tile.
+    pub fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        let (row, column) = offsets(address)?;
+        self.rows.get(row)?.get(column)
+    }
+
+    /// get_mut returns the cell at address, relative to the tile's origin, for
+    /// modification.
+    pub fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        let (row, column) = offsets(address)?;
+        self.rows.get_mut(row)?.get_mut(column)
+    }
+
+    /// rows_mut returns the tile's rows as mutable slices.
+    pub fn rows_mut(&mut self) -> &mut [&'a mut [T]] {
+        &mut self.rows
+    }
+}
+
+impl <T, I> DenseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    /// tiles_mut splits the matrix into tiles of tile_rows by tile_columns cells,
+    /// in reading order, with smaller tiles along the bottom and right edges
+    /// when the dimensions are not multiples of the tile size.  The tiles are
+    /// carved out by splitting the storage slice, so the borrow checker
+    /// guarantees they are disjoint.
+    pub fn tiles_mut(&mut self, tile_rows: I, tile_columns: I) -> Result<Vec<TileMut<'_, T, I>>> {
+        let (tile_rows, tile_columns): (usize, usize) = match (tile_rows.try_into(), tile_columns.try_into()) {
+            (Ok(r), Ok(c)) if r > 0 && c > 0 => (r, c),
+            _ => return Err(Error::new(format!("tile dimensions {}x{} must be positive", tile_rows, tile_columns))),
+        };
+        let columns: usize = match self.columns.try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("column count overflows usize.  This should be unreachable."),
+        };
+        let mut tiles = Vec::new();
+        if columns == 0 {
+            return Ok(tiles);
+        }
+        // Tiles larger than the matrix cover it in one piece; clamping keeps
+        // the band length below from overflowing.
+        let rows = self.data.len() / columns;
+        let (tile_rows, tile_columns) = (tile_rows.min(rows), tile_columns.min(columns));
+        let across = columns.div_ceil(tile_columns);
+        for (band, rows) in self.data.chunks_mut(tile_rows * columns).enumerate() {
+            let first = tiles.len();
+            for index in 0..across {
+                tiles.push(TileMut { origin: address(band * tile_rows, index * tile_columns), rows: Vec::new() });
+            }
+            for row in rows.chunks_exact_mut(columns) {
+                for (tile, piece) in tiles[first..].iter_mut().zip(row.chunks_mut(tile_columns)) {
+                    tile.rows.push(piece);
+                }
+            }
+        }
+        Ok(tiles)
+    }
+
+    /// par_chunks_mut is tiles_mut as a rayon parallel iterator, so each tile
+    /// can be processed on its own thread.
+    #[cfg(feature = "rayon")]
+    pub fn par_chunks_mut(&mut self, tile_rows: I, tile_columns: I) -> Result<rayon::vec::IntoIter<TileMut<'_, T, I>>>
+    where
+        T: Send,
+        I: Send,
+    {
+        use rayon::iter::IntoParallelIterator;
+        Ok(self.tiles_mut(tile_rows, tile_columns)?.into_par_iter())
+    }
+}
+
+fn offsets<I: Coordinate>(address: MatrixAddress<I>) -> Option<(usize, usize)> {
+    Some((address.row.try_into().ok()?, address.column.try_into().ok()?))
+}
+
+fn address<I: Coordinate>(row: usize, column: usize) -> MatrixAddress<I> {
+    match (row.try_into(), column.try_into()) {
+        (Ok(row), Ok(column)) => MatrixAddress { row, column },
+        _ => panic!("address overflows index type.  This should be unreachable."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::new_matrix;
+    use super::*;
+
+    #[test]
+    fn tiles_cover_matrix_once() {
+        let mut m = new_matrix::<u32, u8>(5, vec![0; 35]).unwrap();
+        let tiles = m.tiles_mut(2, 3).unwrap();
+        assert_eq!(tiles.len(), 9);
+        assert_eq!(tiles[8].origin(), MatrixAddress { row: 4, column: 6 });
+        assert_eq!((tiles[8].row_count(), tiles[8].column_count()), (1, 1));
+        std::thread::scope(|scope| {
+            for (n, mut tile) in tiles.into_iter().enumerate() {
+                scope.spawn(move || {
+                    for row in tile.rows_mut() {
+                        row.iter_mut().for_each(|cell| *cell += n as u32 + 1);
+                    }
+                });
+            }
+        });
+        assert_eq!(&m.data[..7], &[1, 1, 1, 2, 2, 2, 3]);
+        assert_eq!(m.data[34], 9);
+        assert!(m.tiles_mut(0, 1).is_err());
+    }
+
+    #[test]
+    fn tile_addresses_are_relative() {
+        let mut m = new_matrix::<u32, u8>(5, vec![0; 35]).unwrap();
+        let mut tiles = m.tiles_mut(2, 3).unwrap();
+        assert_eq!(tiles[4].origin(), MatrixAddress { row: 2, column: 3 });
+        *tiles[4].get_mut(MatrixAddress { row: 1, column: 2 }).unwrap() = 7;
+        assert_eq!(tiles[4].get(MatrixAddress { row: 1, column: 2 }), Some(&7));
+        assert_eq!(tiles[4].get(MatrixAddress { row: 2, column: 0 }), None);
+        assert_eq!(m.data[3 * 7 + 5], 7);
+    }
+
+    #[test]
+    fn oversized_tiles_cover_matrix() {
+        let mut m = new_matrix::<u32, u64>(2, vec![1, 2, 3, 4]).unwrap();
+        let tiles = m.tiles_mut(u64::MAX, 1).unwrap();
+        assert_eq!(tiles.len(), 2);
+        assert_eq!((tiles[1].row_count(), tiles[1].column_count()), (2, 1));
+        assert_eq!(tiles[1].get(MatrixAddress { row: 1, column: 0 }), Some(&4));
+        let tiles = m.tiles_mut(u64::MAX, u64::MAX).unwrap();
+        assert_eq!(tiles.len(), 1);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_chunks_mut_visits_every_cell() {
+        use rayon::iter::ParallelIterator;
+        let mut m = new_matrix::<u32, u16>(100, vec![1; 10_000]).unwrap();
+        m.par_chunks_mut(7, 9).unwrap().for_each(|mut tile| {
+            let origin = tile.origin();
+            for row in tile.rows_mut() {
+                row.iter_mut().for_each(|cell| *cell += origin.row as u32);
+            }
+        });
+        assert_eq!(m.data.iter().map(|v| *v as u64).sum::<u64>(), 10_000 + (0..100).map(|r| r / 7 * 7 * 100).sum::<u64>());
+    }
+}
@@ -1,12 +1,16 @@
 // Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
 
+use std::cmp::{Ordering, Reverse};
 use std::fmt::{Debug, Display};
+use std::collections::{BinaryHeap, HashMap};
 use std::hash::Hash;
 use std::ops::{Add, Index, IndexMut, Mul, Range, Sub};
-use crate::{DenseMatrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixRowsIterator, MatrixValueIterator};
+use crate::{AddressPairsIterator, AddressTransform, DenseMatrix, Direction, MatrixAddress, NeighborKind, MatrixColumnsIterator, MatrixForwardIndexedIterMut, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixReverseIterator, MatrixRowsIterator, MatrixValueIterMut, MatrixValueIterator};
 use crate::column::Column;
-use crate::factories::new_matrix;
+use crate::factories::{new_matrix, new_matrix_from_iter};
+use crate::format::FormatOptions;
 use crate::row::Row;
+use crate::shape::{assert_same_shape, Shape};
 
 /// Dimension is an axis of the storage.  In a vector there's a single Dimension (0)
 /// and it's the horizontal position within the vector.  For a matrix, there are two
@@ -35,6 +39,22 @@ pub trait CheckedMul where Self: Sized {
     fn checked_multiply(&self, rhs: Self) -> Option<usize>;
 }
 
+/// CheckedAdd captures the built-in checked_add behavior provided for all
+/// intrinsic integer types in Rust, so generic coordinate arithmetic can
+/// detect overflow rather than panicking or wrapping.
+pub trait CheckedAdd where Self: Sized {
+    // returns None on overflow.
+    fn checked_addition(&self, rhs: Self) -> Option<Self>;
+}
+
+/// CheckedSub captures the built-in checked_sub behavior provided for all
+/// intrinsic integer types in Rust.  For unsigned types, this also detects
+/// underflow below zero.
+pub trait CheckedSub where Self: Sized {
+    // returns None on overflow or underflow.
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self>;
+}
+
 /// Coordinate is the traits required to act as a dimensional index in
 /// a Matrix.  All the built-in integral types should satisfy these.
 pub trait Coordinate:
@@ -70,12 +90,31 @@ where
     /// column_count returns the number of vertical columns stored in the Matrix.
     fn column_count(&self) -> I;
 
+    /// shape returns the row and column counts of the Matrix.
+    fn shape(&self) -> Shape<I> {
+        Shape { rows: self.row_count(), columns: self.column_count() }
+    }
+
     /// iter iterates over the values in a matrix in row-major order.
     fn iter(&'a self) -> MatrixValueIterator<'a, T, I>;
 
     /// addresses iterates over the addresses in a Matrix in row-major order.
     fn addresses(&self) -> MatrixForwardIterator<I>;
 
+    /// reading_order iterates over the addresses in a Matrix in reading order:
+    /// left to right within a row, rows from top to bottom.  This order is
+    /// guaranteed, matches the Ord of MatrixAddress, and is the order used by
+    /// addresses and the other row-major iterators.
+    fn reading_order(&self) -> MatrixForwardIterator<I> {
+        MatrixForwardIterator::new(self.range().end)
+    }
+
+    /// reverse_reading_order iterates over the addresses in a Matrix in the
+    /// exact reverse of reading_order, starting at the lower right corner.
+    fn reverse_reading_order(&self) -> MatrixReverseIterator<I> {
+        MatrixReverseIterator::new(self.range().end)
+    }
+
     /// indexed_iter returns addresses and their cell's contents as an iterator.
     fn indexed_iter(&'a self) -> MatrixForwardIndexedIterator<'a, T, I>;
 
@@ -90,6 +129,580 @@ where
 
     /// columns returns an iterator over the columns of the matrix.
     fn columns(&'a self) -> MatrixColumnsIterator<'a, T, I>;
+
+    /// neighbors returns the in-bounds neighbors of address of the given kind,
+    /// paired with their values, sorted by address.  An address outside the
+    /// matrix has no neighbors.
+    fn neighbors(&'a self, address: MatrixAddress<I>, kind: NeighborKind) -> std::vec::IntoIter<(MatrixAddress<I>, &'a T)> {
+        let end = self.range().end;
+        kind.offsets().iter()
+            .filter_map(|offset| address.offset_within(*offset, end))
+            .filter_map(|n| Some((n, self.get(n)?)))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// ray returns the cells on the straight line from start in direction,
+    /// beginning with start itself and ending at the edge of the matrix.  Use
+    /// skip(1) to see only the cells beyond start.  An out of bounds start
+    /// yields nothing.
+    fn ray(&'a self, start: MatrixAddress<I>, direction: Direction) -> std::vec::IntoIter<(MatrixAddress<I>, &'a T)> {
+        let end = self.range().end;
+        let mut cells = Vec::new();
+        let mut cursor = if self.contains(start) { Some(start) } else { None };
+        while let Some(addr) = cursor {
+            if let Some(v) = self.get(addr) {
+                cells.push((addr, v));
+            }
+            cursor = addr.offset_within(direction.offsets(), end);
+        }
+        cells.into_iter()
+    }
+
+    /// read_line returns exactly k cells on the straight line from start in
+    /// direction, beginning with start itself.  None is returned if any of them
+    /// would lie outside the matrix.  A k of 0 yields an empty line.
+    fn read_line(&'a self, start: MatrixAddress<I>, direction: Direction, k: usize) -> Option<Vec<&'a T>> {
+        let end = self.range().end;
+        let mut cells = Vec::with_capacity(k);
+        let mut cursor = start;
+        for i in 0..k {
+            if i > 0 {
+                cursor = cursor.offset_within(direction.offsets(), end)?;
+            }
+            cells.push(self.get(cursor)?);
+        }
+        Some(cells)
+    }
+
+    /// value_at_tiled treats the matrix as tiled infinitely in every direction
+    /// and returns the value at (row, column) of that plane, where the original
+    /// matrix occupies rows and columns 0 onward.  Negative coordinates wrap, so
+    /// (-1, -1) is the lower right cell.  Panics if the matrix is empty.
+    fn value_at_tiled(&'a self, row: i64, column: i64) -> &'a T {
+        let (address, _) = tiled_address(self.range().end, row, column);
+        &self[address]
+    }
+
+    /// value_at_tiled_with is value_at_tiled for tiles that differ from the
+    /// original: f receives the underlying value and the (row, column) number of
+    /// the tile holding the coordinates, with the original matrix as tile (0, 0).
+    fn value_at_tiled_with<U>(&'a self, row: i64, column: i64, f: impl Fn(&'a T, (i64, i64)) -> U) -> U
+    where
+        Self: Sized,
+    {
+        let (address, tile) = tiled_address(self.range().end, row, column);
+        f(&self[address], tile)
+    }
+
+    /// min_by_address returns the smallest value in the matrix with its
+    /// address.  Ties go to the earliest cell in reading order.  None is
+    /// returned for an empty matrix.
+    fn min_by_address(&'a self) -> Option<(MatrixAddress<I>, &'a T)>
+    where
+        T: Ord,
+    {
+        extreme_by(self.addresses().filter_map(|addr| Some((addr, self.get(addr)?))), T::cmp, Ordering::Less)
+    }
+
+    /// max_by_address returns the largest value in the matrix with its
+    /// address.  Ties go to the earliest cell in reading order.  None is
+    /// returned for an empty matrix.
+    fn max_by_address(&'a self) -> Option<(MatrixAddress<I>, &'a T)>
+    where
+        T: Ord,
+    {
+        extreme_by(self.addresses().filter_map(|addr| Some((addr, self.get(addr)?))), T::cmp, Ordering::Greater)
+    }
+
+    /// k_smallest returns the k smallest values in the matrix with their
+    /// addresses, smallest first, with ties going to the earlier cell in
+    /// reading order.  Only k cells are held at a time, in a bounded heap, so
+    /// the matrix is never sorted.
+    fn k_smallest(&'a self, k: usize) -> Vec<(MatrixAddress<I>, &'a T)>
+    where
+        T: Ord,
+    {
+        k_best(self.addresses().filter_map(|addr| Some((addr, self.get(addr)?))), k, |v| v)
+    }
+
+    /// k_largest returns the k largest values in the matrix with their
+    /// addresses, largest first, with ties going to the earlier cell in reading
+    /// order.  Only k cells are held at a time, in a bounded heap.
+    fn k_largest(&'a self, k: usize) -> Vec<(MatrixAddress<I>, &'a T)>
+    where
+        T: Ord,
+    {
+        k_best(self.addresses().filter_map(|addr| Some((addr, self.get(addr)?))), k, Reverse)
+    }
+
+    /// min_by_address_by_key is min_by_address comparing cells by key(value).
+    fn min_by_address_by_key<K: Ord>(&'a self, key: impl Fn(&T) -> K) -> Option<(MatrixAddress<I>, &'a T)>
+    where
+        Self: Sized,
+    {
+        extreme_by(self.addresses().filter_map(|addr| Some((addr, self.get(addr)?))), |a, b| key(a).cmp(&key(b)), Ordering::Less)
+    }
+
+    /// max_by_address_by_key is max_by_address comparing cells by key(value).
+    fn max_by_address_by_key<K: Ord>(&'a self, key: impl Fn(&T) -> K) -> Option<(MatrixAddress<I>, &'a T)>
+    where
+        Self: Sized,
+    {
+        extreme_by(self.addresses().filter_map(|addr| Some((addr, self.get(addr)?))), |a, b| key(a).cmp(&key(b)), Ordering::Greater)
+    }
+
+    /// diagonal returns a bidirectional iterator over the main diagonal, the cells
+    /// where row == column, from the upper left.  On a non-square matrix the
+    /// diagonal stops at the end of the shorter dimension.
+    fn diagonal(&'a self) -> std::vec::IntoIter<&'a T> {
+        let length = self.row_count().min(self.column_count());
+        let mut cells = Vec::new();
+        let mut i = I::default();
+        while i < length {
+            if let Some(v) = self.get(MatrixAddress { row: i, column: i }) {
+                cells.push(v);
+            }
+            i = i + I::unit();
+        }
+        cells.into_iter()
+    }
+
+    /// anti_diagonal returns a bidirectional iterator over the anti-diagonal, the
+    /// main diagonal mirrored left to right, from the upper right.  On a
+    /// non-square matrix it stops at the end of the shorter dimension.
+    fn anti_diagonal(&'a self) -> std::vec::IntoIter<&'a T> {
+        let columns = self.column_count();
+        let length = self.row_count().min(columns);
+        let mut cells = Vec::new();
+        let mut i = I::default();
+        while i < length {
+            let column = columns - I::unit() - i;
+            if let Some(v) = self.get(MatrixAddress { row: i, column }) {
+                cells.push(v);
+            }
+            i = i + I::unit();
+        }
+        cells.into_iter()
+    }
+
+    /// preview renders the upper left max_rows x max_columns corner of the
+    /// matrix with the default FormatOptions, marking omitted rows and columns
+    /// with "…" and ending with a footer giving the full dimensions.  Unlike
+    /// FormatOptions::format, its output is bounded however large the matrix.
+    fn preview(&self, max_rows: usize, max_columns: usize) -> String
+    where
+        T: Display,
+    {
+        FormatOptions::default().render_preview(self.shape(), max_rows, max_columns, |addr| match self.get(addr) {
+            Some(v) => v.to_string(),
+            None => String::new(),
+        })
+    }
+
+    /// render converts the matrix to an RgbaImage with one pixel per cell,
+    /// colored by cell_to_rgba.  It is available with the "render" feature.
+    #[cfg(feature = "render")]
+    fn render(&self, cell_to_rgba: impl Fn(&T) -> [u8; 4]) -> crate::render::RgbaImage
+    where
+        Self: Sized,
+    {
+        let dimension = |v: I| -> u32 {
+            let v: usize = match v.try_into() {
+                Ok(v) => v,
+                Err(_) => panic!("matrix dimensions overflow usize.  This should be unreachable."),
+            };
+            match v.try_into() {
+                Ok(v) => v,
+                Err(_) => panic!("matrix dimensions exceed u32 image dimensions"),
+            }
+        };
+        let data: Vec<u8> = self.addresses()
+            .filter_map(|addr| self.get(addr))
+            .flat_map(cell_to_rgba)
+            .collect();
+        crate::render::RgbaImage { width: dimension(self.column_count()), height: dimension(self.row_count()), data }
+    }
+
+    /// to_dense copies the matrix, whatever its implementation, into a new
+    /// DenseMatrix of the same shape.
+    fn to_dense(&self) -> DenseMatrix<T, I>
+    where
+        T: Clone,
+    {
+        let data: Vec<T> = self.addresses()
+            .filter_map(|addr| self.get(addr).cloned())
+            .collect();
+        if data.is_empty() {
+            return DenseMatrix::new(I::default(), I::default(), data);
+        }
+        DenseMatrix::new(self.column_count(), self.row_count(), data)
+    }
+
+    /// group_addresses_by_value partitions the addresses of the matrix by the
+    /// value of their cells.  Each group lists its addresses in reading order.
+    fn group_addresses_by_value(&self) -> HashMap<T, Vec<MatrixAddress<I>>>
+    where
+        T: Clone + Eq + Hash,
+    {
+        let mut groups: HashMap<T, Vec<MatrixAddress<I>>> = HashMap::new();
+        for addr in self.addresses() {
+            if let Some(value) = self.get(addr) {
+                match groups.get_mut(value) {
+                    Some(group) => group.push(addr),
+                    None => { groups.insert(value.clone(), vec![addr]); },
+                }
+            }
+        }
+        groups
+    }
+
+    /// count_where returns the number of cells whose value satisfies pred.
+    fn count_where(&self, pred: impl Fn(&T) -> bool) -> usize
+    where
+        Self: Sized,
+    {
+        self.addresses().filter(|addr| self.get(*addr).is_some_and(&pred)).count()
+    }
+
+    /// any reports whether some cell's value satisfies pred.  It is false for
+    /// an empty matrix.
+    fn any(&self, pred: impl Fn(&T) -> bool) -> bool
+    where
+        Self: Sized,
+    {
+        self.addresses().any(|addr| self.get(addr).is_some_and(&pred))
+    }
+
+    /// all reports whether every cell's value satisfies pred.  It is true for
+    /// an empty matrix.
+    fn all(&self, pred: impl Fn(&T) -> bool) -> bool
+    where
+        Self: Sized,
+    {
+        self.addresses().all(|addr| self.get(addr).is_some_and(&pred))
+    }
+
+    /// find returns the address of the first cell, in reading order, whose value
+    /// satisfies pred.
+    fn find(&self, pred: impl Fn(&T) -> bool) -> Option<MatrixAddress<I>>
+    where
+        Self: Sized,
+    {
+        self.addresses().find(|addr| self.get(*addr).is_some_and(&pred))
+    }
+
+    /// find_all returns the addresses of every cell whose value satisfies pred,
+    /// in reading order.
+    fn find_all(&self, pred: impl Fn(&T) -> bool) -> std::vec::IntoIter<MatrixAddress<I>>
+    where
+        Self: Sized,
+    {
+        let found: Vec<MatrixAddress<I>> = self.addresses()
+            .filter(|addr| self.get(*addr).is_some_and(&pred))
+            .collect();
+        found.into_iter()
+    }
+
+    /// find_pattern returns the addresses at which the upper left corner of
+    /// pattern can be placed so that every cell of pattern equals the cell
+    /// beneath it, in reading order.  Matches may overlap.  An empty pattern
+    /// matches nowhere.
+    fn find_pattern<'b>(&self, pattern: &dyn Matrix<'b, T, I>) -> Vec<MatrixAddress<I>>
+    where
+        T: PartialEq,
+    {
+        let transform = AddressTransform::identity(pattern.row_count(), pattern.column_count());
+        pattern_placements(self.range().end, &transform, |at, cell| {
+            match (self.get(at), transform.apply(cell).and_then(|p| pattern.get(p))) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            }
+        })
+    }
+
+    /// find_pattern_oriented is find_pattern repeated for each of the eight
+    /// rotations and reflections of pattern (see AddressTransform::dihedral).
+    /// Each match is paired with the transform from the oriented pattern to
+    /// pattern.  A symmetric pattern matches once per orientation that
+    /// reproduces it.
+    fn find_pattern_oriented<'b>(&self, pattern: &dyn Matrix<'b, T, I>) -> Vec<(MatrixAddress<I>, AddressTransform<I>)>
+    where
+        T: PartialEq,
+    {
+        let mut found = Vec::new();
+        for transform in AddressTransform::dihedral(pattern.row_count(), pattern.column_count()) {
+            let placements = pattern_placements(self.range().end, &transform, |at, cell| {
+                match (self.get(at), transform.apply(cell).and_then(|p| pattern.get(p))) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => false,
+                }
+            });
+            found.extend(placements.into_iter().map(|at| (at, transform)));
+        }
+        found
+    }
+
+    /// zip pairs each cell of the matrix with the cell at the same address in
+    /// other.  An error is returned if the shapes differ.
+    fn zip<'b, U>(&self, other: &dyn Matrix<'b, U, I>) -> crate::error::Result<DenseMatrix<(T, U), I>>
+    where
+        Self: Sized,
+        T: Clone,
+        U: 'static + Clone,
+    {
+        self.combine(other, |a, b| (a.clone(), b.clone()))
+    }
+
+    /// combine returns the matrix whose cells are f applied to the cells at the
+    /// same address in the matrix and in other.  An error is returned if the
+    /// shapes differ.
+    fn combine<'b, U, V>(&self, other: &dyn Matrix<'b, U, I>, f: impl Fn(&T, &U) -> V) -> crate::error::Result<DenseMatrix<V, I>>
+    where
+        Self: Sized,
+        U: 'static,
+    {
+        assert_same_shape(self, other)?;
+        let data: Vec<V> = self.addresses()
+            .filter_map(|addr| Some(f(self.get(addr)?, other.get(addr)?)))
+            .collect();
+        if data.is_empty() {
+            return Ok(DenseMatrix::new(I::default(), I::default(), data));
+        }
+        Ok(DenseMatrix::new(self.column_count(), self.row_count(), data))
+    }
+
+    /// zip_iter returns each address with the cells at that address in the
+    /// matrix and in other, in reading order, without copying.  An error is
+    /// returned if the shapes differ.
+    fn zip_iter<'b, U>(&'a self, other: &'b dyn Matrix<'b, U, I>) -> crate::error::Result<std::vec::IntoIter<(MatrixAddress<I>, &'a T, &'b U)>>
+    where
+        Self: Sized,
+        U: 'static,
+    {
+        assert_same_shape(self, other)?;
+        let cells: Vec<(MatrixAddress<I>, &'a T, &'b U)> = self.addresses()
+            .filter_map(|addr| Some((addr, self.get(addr)?, other.get(addr)?)))
+            .collect();
+        Ok(cells.into_iter())
+    }
+
+    /// address_pairs returns every unordered pair of addresses whose cells
+    /// satisfy pred, each pair once with its addresses in reading order.  The
+    /// matching addresses are gathered up front, but pairs are produced lazily.
+    fn address_pairs(&self, pred: impl Fn(&T) -> bool) -> AddressPairsIterator<I>
+    where
+        Self: Sized,
+    {
+        let matching: Vec<MatrixAddress<I>> = self.addresses()
+            .filter(|addr| self.get(*addr).is_some_and(&pred))
+            .collect();
+        AddressPairsIterator::new(vec![matching])
+    }
+
+    /// same_value_address_pairs is address_pairs restricted to pairs of cells
+    /// holding equal values.  Pairs are grouped by value, with the groups in
+    /// reading order of their first address.
+    fn same_value_address_pairs(&self, pred: impl Fn(&T) -> bool) -> AddressPairsIterator<I>
+    where
+        Self: Sized,
+        T: Clone + Eq + Hash,
+    {
+        let mut groups: Vec<Vec<MatrixAddress<I>>> = self.group_addresses_by_value()
+            .into_iter()
+            .filter(|(value, _)| pred(value))
+            .map(|(_, addresses)| addresses)
+            .collect();
+        groups.sort();
+        AddressPairsIterator::new(groups)
+    }
+
+    /// row_nonzero_counts returns, for each row, the number of cells that differ
+    /// from T::default().  For an adjacency matrix these are the out-degrees.
+    fn row_nonzero_counts(&self) -> Vec<usize>
+    where
+        T: Default + PartialEq,
+    {
+        let zero = T::default();
+        let mut counts = vec![0; coordinate_index(self.row_count())];
+        for addr in self.addresses() {
+            if self.get(addr).is_some_and(|v| *v != zero) {
+                counts[coordinate_index(addr.row)] += 1;
+            }
+        }
+        counts
+    }
+
+    /// column_nonzero_counts returns, for each column, the number of cells that
+    /// differ from T::default().  For an adjacency matrix these are the
+    /// in-degrees.
+    fn column_nonzero_counts(&self) -> Vec<usize>
+    where
+        T: Default + PartialEq,
+    {
+        let zero = T::default();
+        let mut counts = vec![0; coordinate_index(self.column_count())];
+        for addr in self.addresses() {
+            if self.get(addr).is_some_and(|v| *v != zero) {
+                counts[coordinate_index(addr.column)] += 1;
+            }
+        }
+        counts
+    }
+
+    /// degree returns the out-degree of node in a square adjacency matrix: the
+    /// number of cells in row node that differ from T::default().  Out of range
+    /// nodes have degree 0.
+    fn degree(&self, node: I) -> usize
+    where
+        T: Default + PartialEq,
+    {
+        let zero = T::default();
+        let mut count = 0;
+        let mut column = I::default();
+        while column < self.column_count() {
+            if self.get(MatrixAddress { row: node, column }).is_some_and(|v| *v != zero) {
+                count += 1;
+            }
+            column = column + I::unit();
+        }
+        count
+    }
+
+    /// address_to_index converts an address to its position in a row-major
+    /// flattening of the matrix.  None is returned for out of bounds addresses.
+    fn address_to_index(&self, address: MatrixAddress<I>) -> Option<usize> {
+        if !self.contains(address) {
+            return None;
+        }
+        let row: usize = address.row.try_into().ok()?;
+        let column: usize = address.column.try_into().ok()?;
+        let columns: usize = self.column_count().try_into().ok()?;
+        Some(row * columns + column)
+    }
+
+    /// index_to_address converts a position in a row-major flattening of the
+    /// matrix back to an address.  None is returned for out of bounds indices.
+    fn index_to_address(&self, index: usize) -> Option<MatrixAddress<I>> {
+        let columns: usize = self.column_count().try_into().ok()?;
+        let rows: usize = self.row_count().try_into().ok()?;
+        if columns == 0 || index / columns >= rows {
+            return None;
+        }
+        Some(MatrixAddress {
+            row: (index / columns).try_into().ok()?,
+            column: (index % columns).try_into().ok()?,
+        })
+    }
+}
+
+/// MatrixMut is a Matrix whose cells can be borrowed mutably, through IndexMut
+/// and mutable iteration.  Read-only implementations, such as computed or
+/// bit-packed matrices, implement only Matrix.
+pub trait MatrixMut<'a, T, I>
+where
+    Self: Matrix<'a, T, I> + IndexMut<MatrixAddress<I>, Output = T>,
+    T: 'static,
+    I: Coordinate,
+{
+    /// iter_mut iterates over mutable references to the values in a matrix in
+    /// row-major order.
+    fn iter_mut(&mut self) -> MatrixValueIterMut<'_, T, I>;
+
+    /// indexed_iter_mut returns addresses and mutable references to their cell's
+    /// contents as an iterator, in row-major order.
+    fn indexed_iter_mut(&mut self) -> MatrixForwardIndexedIterMut<'_, T, I>;
+}
+
+/// tiled_address reduces plane coordinates to an address within a matrix whose
+/// exclusive upper bound is end, and the tile they fall in.
+fn tiled_address<I>(end: MatrixAddress<I>, row: i64, column: i64) -> (MatrixAddress<I>, (i64, i64))
+where
+    I: Coordinate,
+{
+    let extent = |v: I| -> i64 {
+        match v.try_into() {
+            Ok(v) => { let v: usize = v; v as i64 },
+            Err(_) => panic!("matrix dimensions overflow usize.  This should be unreachable."),
+        }
+    };
+    let (rows, columns) = (extent(end.row), extent(end.column));
+    if rows == 0 || columns == 0 {
+        panic!("an empty matrix cannot be tiled");
+    }
+    let coordinate = |v: i64| -> I {
+        match (v as usize).try_into() {
+            Ok(v) => v,
+            Err(_) => panic!("tiled coordinate overflows index type.  This should be unreachable."),
+        }
+    };
+    let address = MatrixAddress { row: coordinate(row.rem_euclid(rows)), column: coordinate(column.rem_euclid(columns)) };
+    (address, (row.div_euclid(rows), column.div_euclid(columns)))
+}
+
+/// extreme_by returns the first cell that compares as wanted against every
+/// earlier cell, i.e. the earliest minimum or maximum.
+fn extreme_by<'a, T, I>(cells: impl Iterator<Item = (MatrixAddress<I>, &'a T)>, compare: impl Fn(&T, &T) -> Ordering, wanted: Ordering) -> Option<(MatrixAddress<I>, &'a T)>
+where
+    I: Coordinate,
+{
+    let mut best: Option<(MatrixAddress<I>, &'a T)> = None;
+    for (addr, value) in cells {
+        if best.is_none_or(|(_, b)| compare(value, b) == wanted) {
+            best = Some((addr, value));
+        }
+    }
+    best
+}
+
+/// k_best returns the k cells with the smallest keys, in increasing key order
+/// and then reading order.  A max-heap holds the best k seen so far, so its top
+/// is the cell to evict when a better one arrives.
+fn k_best<'a, T, I, K>(cells: impl Iterator<Item = (MatrixAddress<I>, &'a T)>, k: usize, key: impl Fn(&'a T) -> K) -> Vec<(MatrixAddress<I>, &'a T)>
+where
+    T: Ord,
+    I: Coordinate,
+    K: Ord,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut heap: BinaryHeap<(K, MatrixAddress<I>, &'a T)> = BinaryHeap::with_capacity(k);
+    for (addr, value) in cells {
+        let entry = (key(value), addr, value);
+        if heap.len() < k {
+            heap.push(entry);
+        } else if let Some(mut worst) = heap.peek_mut() && entry < *worst {
+            *worst = entry;
+        }
+    }
+    heap.into_sorted_vec().into_iter().map(|(_, addr, value)| (addr, value)).collect()
+}
+
+/// pattern_placements returns the upper left corners, in reading order, at
+/// which the view of transform fits within a matrix whose exclusive upper bound
+/// is end and matches(matrix address, view address) holds for every view cell.
+fn pattern_placements<I>(end: MatrixAddress<I>, transform: &AddressTransform<I>, matches: impl Fn(MatrixAddress<I>, MatrixAddress<I>) -> bool) -> Vec<MatrixAddress<I>>
+where
+    I: Coordinate,
+{
+    let size = MatrixAddress { row: transform.row_count(), column: transform.column_count() };
+    let zero = I::default();
+    if size.row <= zero || size.column <= zero || size.row > end.row || size.column > end.column {
+        return Vec::new();
+    }
+    let corners = MatrixAddress { row: end.row - size.row + I::unit(), column: end.column - size.column + I::unit() };
+    MatrixForwardIterator::new(corners)
+        .filter(|corner| MatrixForwardIterator::new(size).all(|cell| matches(*corner + cell, cell)))
+        .collect()
+}
+
+/// coordinate_index converts a non-negative coordinate to usize.
+fn coordinate_index<I: Coordinate>(value: I) -> usize {
+    match value.try_into() {
+        Ok(v) => v,
+        Err(_) => panic!("coordinate overflows usize.  This should be unreachable."),
+    }
 }
 
 /// MatrixMap provides convenience functions to transform one matrix into another.
@@ -107,6 +720,24 @@ where
     /// function that takes the address and value of each element.
     fn map_indexed_matrix(&'a self, f: &mut dyn FnMut(MatrixAddress<I>, &T) -> V) -> DenseMatrix<V, I>;
 
+    /// map_with_neighbors creates a Matrix<V, I> from a Matrix<T, I> using a
+    /// helper function that takes the value of each element and the values of
+    /// its in-bounds neighbors of the given kind, in row-major order.
+    fn map_with_neighbors(&'a self, connectivity: NeighborKind, f: &dyn Fn(&T, &[&T]) -> V) -> DenseMatrix<V, I>;
+
+    /// try_map_matrix is map_matrix with a helper function that can fail.  The
+    /// first error, in reading order, is returned and the remaining elements are
+    /// not visited.
+    fn try_map_matrix<E>(&'a self, f: impl Fn(&T) -> std::result::Result<V, E>) -> std::result::Result<DenseMatrix<V, I>, E>
+    where
+        Self: Sized;
+
+    /// try_map_indexed_matrix is map_indexed_matrix with a helper function that
+    /// can fail.  The first error, in reading order, is returned.
+    fn try_map_indexed_matrix<E>(&'a self, f: impl FnMut(MatrixAddress<I>, &T) -> std::result::Result<V, E>) -> std::result::Result<DenseMatrix<V, I>, E>
+    where
+        Self: Sized;
+
     /*
     /// transpose returns a view on the underlying matrix with rows and columns swapped.
     /// self must be mutable in order to support the IndexedMut trait.
@@ -133,6 +764,35 @@ where
         new_matrix(self.row_count(), values).unwrap()
     }
 
+    fn map_with_neighbors(&'a self, connectivity: NeighborKind, f: &dyn Fn(&T, &[&T]) -> V) -> DenseMatrix<V, I> {
+        let end = self.range().end;
+        let mut neighbors = Vec::with_capacity(8);
+        let values: Vec<V> = self.addresses()
+            .zip(self.data.iter())
+            .map(|(addr, value)| {
+                neighbors.clear();
+                neighbors.extend(connectivity.offsets().iter()
+                    .filter_map(|offset| addr.offset_within(*offset, end))
+                    .map(|n| &self.data[self.index_address(n)]));
+                f(value, &neighbors)
+            })
+            .collect();
+        DenseMatrix::new(self.columns, self.rows, values)
+    }
+
+    fn try_map_matrix<E>(&'a self, f: impl Fn(&T) -> std::result::Result<V, E>) -> std::result::Result<DenseMatrix<V, I>, E> {
+        let values = self.data.iter().map(f).collect::<std::result::Result<Vec<V>, E>>()?;
+        Ok(DenseMatrix::new(self.columns, self.rows, values))
+    }
+
+    fn try_map_indexed_matrix<E>(&'a self, mut f: impl FnMut(MatrixAddress<I>, &T) -> std::result::Result<V, E>) -> std::result::Result<DenseMatrix<V, I>, E> {
+        let values = self.addresses()
+            .zip(self.data.iter())
+            .map(|(addr, value)| f(addr, value))
+            .collect::<std::result::Result<Vec<V>, E>>()?;
+        Ok(DenseMatrix::new(self.columns, self.rows, values))
+    }
+
     /*
     /// transpose returns a view of the matrix where the rows and columns are swapped.
     fn transpose(&'a mut self) -> impl Matrix<'c, T, I> {
@@ -140,6 +800,21 @@ where
     }*/
 }
 
+/// CollectMatrix lets an iterator of row-major values be collected straight into
+/// a DenseMatrix, as in `values.into_iter().collect_matrix(rows)`.
+pub trait CollectMatrix<T>: Iterator<Item = T> + Sized {
+    /// collect_matrix gathers the iterator into a matrix with the given number of rows.
+    /// The number of values must be a multiple of rows.
+    fn collect_matrix<I>(self, rows: I) -> crate::error::Result<DenseMatrix<T, I>>
+    where
+        I: Coordinate,
+    {
+        new_matrix_from_iter(rows, self)
+    }
+}
+
+impl<T, It> CollectMatrix<T> for It where It: Iterator<Item = T> {}
+
 /// Tensor is a generic multidimensional data store trait.  Think of it as a shared
 /// interface for a vector, a matrix, a cube, and a hypercube.
 pub trait Tensor<
@@ -147,7 +822,7 @@ pub trait Tensor<
     V: Copy + Unit + Add<Output = V> + Sub<Output = V> + PartialOrd,
     A: Address<V, DIMENSION>,
     const DIMENSION: usize,
->: IndexMut<A, Output = T>
+>: Index<A, Output = T>
 {
     /// range provides the bounds of the address space for the Tensor.
     /// The lower (inclusive bound) is the origin, conceptually placed at the left of
@@ -205,6 +880,94 @@ impl<T> Coordinate for T where
 {
 }
 
+/// coordinate_newtype! makes a single-field tuple struct wrapping a built-in
+/// integer usable as a Coordinate, by implementing the arithmetic, conversion
+/// and Unit traits in terms of the wrapped value.  The struct must derive
+/// Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd and Ord itself.
+/// Distinct index types for rows and columns turn swapped arguments into type
+/// errors, although a matrix still uses a single index type for both.  NonZero
+/// integers cannot be wrapped, as a Coordinate must have a zero Default.
+///
+/// ```
+/// use rust_advent_matrix::{coordinate_newtype, new_default_matrix_of, Matrix, Shape};
+/// #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+/// struct Idx(u16);
+/// coordinate_newtype!(Idx(u16));
+/// let m = new_default_matrix_of::<u8, Idx>(Shape { rows: Idx(2), columns: Idx(3) }).unwrap();
+/// assert_eq!(m.row_count(), Idx(2));
+/// ```
+#[macro_export]
+macro_rules! coordinate_newtype {
+    ($name:ident($inner:ty)) => {
+        impl ::std::ops::Add for $name {
+            type Output = $name;
+            fn add(self, rhs: Self) -> Self::Output {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl ::std::ops::Sub for $name {
+            type Output = $name;
+            fn sub(self, rhs: Self) -> Self::Output {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl ::std::ops::Mul for $name {
+            type Output = $name;
+            fn mul(self, rhs: Self) -> Self::Output {
+                $name(self.0 * rhs.0)
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::std::convert::TryFrom<usize> for $name {
+            type Error = <$inner as ::std::convert::TryFrom<usize>>::Error;
+            fn try_from(value: usize) -> ::std::result::Result<Self, Self::Error> {
+                <$inner>::try_from(value).map($name)
+            }
+        }
+
+        // Narrow wrapped types convert infallibly, but wider ones do not.
+        #[allow(clippy::infallible_try_from)]
+        impl ::std::convert::TryFrom<$name> for usize {
+            type Error = <usize as ::std::convert::TryFrom<$inner>>::Error;
+            fn try_from(value: $name) -> ::std::result::Result<Self, Self::Error> {
+                usize::try_from(value.0)
+            }
+        }
+
+        impl $crate::CheckedAdd for $name {
+            fn checked_addition(&self, rhs: Self) -> Option<Self> {
+                $crate::CheckedAdd::checked_addition(&self.0, rhs.0).map($name)
+            }
+        }
+
+        impl $crate::CheckedSub for $name {
+            fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+                $crate::CheckedSub::checked_subtraction(&self.0, rhs.0).map($name)
+            }
+        }
+
+        impl $crate::CheckedMul for $name {
+            fn checked_multiply(&self, rhs: Self) -> Option<usize> {
+                $crate::CheckedMul::checked_multiply(&self.0, rhs.0)
+            }
+        }
+
+        impl $crate::Unit for $name {
+            fn unit() -> Self {
+                $name(<$inner as $crate::Unit>::unit())
+            }
+        }
+    };
+}
+
 // I had a blanket implementation of Unit for anything that converted from u8, but that didn't
 // work for i8, and adding an i8 explicit implementation complains that something might add a
 // From<u8> for i8 in the future.  Unlikely, but let's just enumerate the built ins here.
@@ -354,9 +1117,150 @@ impl CheckedMul for char {
     }
 }
 
+impl CheckedAdd for u8 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
 
+impl CheckedAdd for u16 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
 
+impl CheckedAdd for u32 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
 
+impl CheckedAdd for u64 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
 
+impl CheckedAdd for i8 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
+
+impl CheckedAdd for i16 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
+
+impl CheckedAdd for i32 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
 
+impl CheckedAdd for i64 {
+    fn checked_addition(&self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
 
+impl CheckedSub for u8 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl CheckedSub for u16 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl CheckedSub for u32 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl CheckedSub for u64 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl CheckedSub for i8 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl CheckedSub for i16 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl CheckedSub for i32 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+impl CheckedSub for i64 {
+    fn checked_subtraction(&self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_default_matrix_of, new_matrix_of};
+    use crate::matrix_address::MatrixAddress;
+    use crate::shape::Shape;
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    struct Idx(u8);
+    coordinate_newtype!(Idx(u8));
+
+    #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+    struct Offset(i16);
+    coordinate_newtype!(Offset(i16));
+
+    #[test]
+    fn newtype_arithmetic() {
+        assert_eq!(Idx(3) + Idx(4), Idx(7));
+        assert_eq!(Idx(7) - Idx(4), Idx(3));
+        assert_eq!(Idx(3) * Idx(4), Idx(12));
+        assert_eq!(Idx::unit(), Idx(1));
+        assert_eq!(Idx(250).checked_addition(Idx(6)), None);
+        assert_eq!(Idx(0).checked_subtraction(Idx(1)), None);
+        assert_eq!(Offset(0).checked_subtraction(Offset(1)), Some(Offset(-1)));
+        assert_eq!(Idx(200).checked_multiply(Idx(2)), Some(400));
+        assert_eq!(Offset(-1).checked_multiply(Offset(2)), None);
+        assert_eq!(Idx(9).to_string(), "9");
+    }
+
+    #[test]
+    fn newtype_conversions() {
+        assert_eq!(Idx::try_from(255usize), Ok(Idx(255)));
+        assert!(Idx::try_from(256usize).is_err());
+        assert_eq!(usize::try_from(Idx(42)), Ok(42));
+        assert!(usize::try_from(Offset(-1)).is_err());
+    }
+
+    #[test]
+    fn newtype_matrix_index() {
+        let mut m = new_default_matrix_of::<u32, Idx>(Shape { rows: Idx(2), columns: Idx(3) }).unwrap();
+        m[MatrixAddress { row: Idx(1), column: Idx(2) }] = 5;
+        assert_eq!(m.get(MatrixAddress { row: Idx(1), column: Idx(2) }), Some(&5));
+        assert_eq!(m.get(MatrixAddress { row: Idx(2), column: Idx(0) }), None);
+        assert_eq!(m.column_count(), Idx(3));
+        let addresses: Vec<MatrixAddress<Idx>> = m.addresses().skip(2).take(2).collect();
+        assert_eq!(addresses, vec![MatrixAddress { row: Idx(0), column: Idx(2) }, MatrixAddress { row: Idx(1), column: Idx(0) }]);
+        let signed = new_matrix_of(Shape { rows: Offset(1), columns: Offset(2) }, vec!['a', 'b']).unwrap();
+        assert_eq!(signed[MatrixAddress { row: Offset(0), column: Offset(1) }], 'b');
+        assert!(new_default_matrix_of::<u8, Offset>(Shape { rows: Offset(-1), columns: Offset(1) }).is_err());
+    }
+}
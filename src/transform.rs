@@ -0,0 +1,500 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use std::ops::{Index, IndexMut, Range};
+use crate::{Coordinate, Matrix, MatrixAddress, MatrixColumnsIterator, MatrixForwardIndexedIterator, MatrixForwardIterator, MatrixRowsIterator, MatrixValueIterator, Tensor};
+use crate::column::Column;
+use crate::error::{Error, Result};
+use crate::row::Row;
+
+/// AddressTransform is an invertible mapping from the addresses of a view onto
+/// the addresses of an underlying matrix.  It is any combination of a
+/// rectangular window of the underlying matrix (a translation) and one of the
+/// eight rotations and reflections of that window.  Transforms are built by
+/// composing operations on the view, each of which describes how the view
+/// changes: so identity(r, c).transposed().cropped(range) crops the transposed
+/// view.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AddressTransform<I>
+where
+    I: Coordinate,
+{
+    /// offset is the underlying address of the window's upper left corner.
+    offset: MatrixAddress<I>,
+    /// window_rows and window_columns are the window's size in the underlying matrix.
+    window_rows: I,
+    window_columns: I,
+    /// transpose swaps the view's row and column before any flips.
+    transpose: bool,
+    /// flip_rows and flip_columns reflect the window's row and column axes.
+    flip_rows: bool,
+    flip_columns: bool,
+}
+
+impl <I> AddressTransform<I>
+where
+    I: Coordinate,
+{
+    /// identity maps a view onto an entire rows x columns matrix unchanged.
+    pub fn identity(rows: I, columns: I) -> Self {
+        AddressTransform {
+            offset: MatrixAddress::default(),
+            window_rows: rows,
+            window_columns: columns,
+            transpose: false,
+            flip_rows: false,
+            flip_columns: false,
+        }
+    }
+
+    /// dihedral returns the eight rotations and reflections of an entire
+    /// rows x columns matrix, starting with the identity.
+    pub fn dihedral(rows: I, columns: I) -> [Self; 8] {
+        let identity = Self::identity(rows, columns);
+        [
+            identity,
+            identity.rotated_cw(),
+            identity.rotated_180(),
+            identity.rotated_ccw(),
+            identity.flipped_rows(),
+            identity.flipped_columns(),
+            identity.transposed(),
+            identity.transposed().rotated_180(),
+        ]
+    }
+
+    /// row_count returns the number of rows in the transformed view.
+    pub fn row_count(&self) -> I {
+        if self.transpose { self.window_columns } else { self.window_rows }
+    }
+
+    /// column_count returns the number of columns in the transformed view.
+    pub fn column_count(&self) -> I {
+        if self.transpose { self.window_rows } else { self.window_columns }
+    }
+
+    /// window returns the range of underlying addresses visible through the view.
+    pub fn window(&self) -> Range<MatrixAddress<I>> {
+        Range {
+            start: self.offset,
+            end: self.offset + MatrixAddress { row: self.window_rows, column: self.window_columns },
+        }
+    }
+
+    /// transposed swaps the rows and columns of the view.
+    pub fn transposed(mut self) -> Self {
+        self.transpose = !self.transpose;
+        self
+    }
+
+    /// flipped_rows reverses the order of the view's rows (top becomes bottom).
+    pub fn flipped_rows(mut self) -> Self {
+        if self.transpose {
+            self.flip_columns = !self.flip_columns;
+        } else {
+            self.flip_rows = !self.flip_rows;
+        }
+        self
+    }
+
+    /// flipped_columns reverses the order of the view's columns (left becomes right).
+    pub fn flipped_columns(mut self) -> Self {
+        if self.transpose {
+            self.flip_rows = !self.flip_rows;
+        } else {
+            self.flip_columns = !self.flip_columns;
+        }
+        self
+    }
+
+    /// rotated_cw turns the view a quarter turn clockwise.
+    pub fn rotated_cw(self) -> Self {
+        self.transposed().flipped_columns()
+    }
+
+    /// rotated_ccw turns the view a quarter turn counter-clockwise.
+    pub fn rotated_ccw(self) -> Self {
+        self.transposed().flipped_rows()
+    }
+
+    /// rotated_180 turns the view a half turn.
+    pub fn rotated_180(self) -> Self {
+        self.flipped_rows().flipped_columns()
+    }
+
+    /// cropped restricts the view to a range of its own addresses, which become
+    /// the new view re-based at (0, 0).  The range must be non-empty and lie within
+    /// the view.
+    pub fn cropped(self, range: Range<MatrixAddress<I>>) -> Result<Self> {
+        let zero = I::default();
+        if range.start.row < zero || range.start.column < zero
+            || range.end.row > self.row_count() || range.end.column > self.column_count()
+            || range.start.row >= range.end.row || range.start.column >= range.end.column {
+            return Err(Error::new(format!(
+                "crop range {}..{} is empty or outside the view", range.start, range.end)));
+        }
+        // work in window axes: (x, y) is (row, column) of the window before flips.
+        let (x_start, x_len, y_start, y_len) = if self.transpose {
+            (range.start.column, range.end.column - range.start.column,
+             range.start.row, range.end.row - range.start.row)
+        } else {
+            (range.start.row, range.end.row - range.start.row,
+             range.start.column, range.end.column - range.start.column)
+        };
+        let row_shift = if self.flip_rows { self.window_rows - x_start - x_len } else { x_start };
+        let column_shift = if self.flip_columns { self.window_columns - y_start - y_len } else { y_start };
+        Ok(AddressTransform {
+            offset: self.offset + MatrixAddress { row: row_shift, column: column_shift },
+            window_rows: x_len,
+            window_columns: y_len,
+            ..self
+        })
+    }
+
+    /// apply maps a view address to its underlying address.  None is returned for
+    /// addresses outside the view.
+    pub fn apply(&self, address: MatrixAddress<I>) -> Option<MatrixAddress<I>> {
+        let zero = I::default();
+        if address.row < zero || address.column < zero
+            || address.row >= self.row_count() || address.column >= self.column_count() {
+            return None;
+        }
+        let (x, y) = if self.transpose {
+            (address.column, address.row)
+        } else {
+            (address.row, address.column)
+        };
+        let ione = I::unit();
+        Some(self.offset + MatrixAddress {
+            row: if self.flip_rows { self.window_rows - ione - x } else { x },
+            column: if self.flip_columns { self.window_columns - ione - y } else { y },
+        })
+    }
+
+    /// invert maps an underlying address to its view address.  None is returned
+    /// for addresses outside the window.
+    pub fn invert(&self, address: MatrixAddress<I>) -> Option<MatrixAddress<I>> {
+        let window = self.window();
+        if address.row < window.start.row || address.column < window.start.column
+            || address.row >= window.end.row || address.column >= window.end.column {
+            return None;
+        }
+        let local = address - self.offset;
+        let ione = I::unit();
+        let x = if self.flip_rows { self.window_rows - ione - local.row } else { local.row };
+        let y = if self.flip_columns { self.window_columns - ione - local.column } else { local.column };
+        Some(if self.transpose {
+            MatrixAddress { row: y, column: x }
+        } else {
+            MatrixAddress { row: x, column: y }
+        })
+    }
+}
+
+/// TransformedView presents another Matrix through an AddressTransform, without
+/// copying.  The underlay is borrowed mutably so that get_mut and IndexMut can
+/// write through the view.  Like TransposedMatrix, it does not implement
+/// MatrixMut, as iterating the underlay mutably in view order would mean
+/// buffering every cell.
+pub struct TransformedView<'a, T, I>
+where
+    I: Coordinate,
+{
+    pub(crate) underlay: &'a mut dyn Matrix<'a, T, I>,
+    pub(crate) transform: AddressTransform<I>,
+}
+
+/// RotatedMatrix is a TransformedView whose transform is a rotation, as created
+/// by new_rotated_cw, new_rotated_180 and new_rotated_ccw.  Cells are remapped
+/// lazily; nothing is copied.
+pub type RotatedMatrix<'a, T, I> = TransformedView<'a, T, I>;
+
+/// FlippedMatrix is a TransformedView whose transform is a reflection, as created
+/// by new_flipped_columns and new_flipped_rows.  Cells are remapped lazily;
+/// nothing is copied.
+pub type FlippedMatrix<'a, T, I> = TransformedView<'a, T, I>;
+
+impl <'a, T, I> TransformedView<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    /// transform returns the mapping from this view onto the underlay.
+    pub fn transform(&self) -> AddressTransform<I> {
+        self.transform
+    }
+
+    /// transposed swaps the rows and columns of the view.
+    pub fn transposed(mut self) -> Self {
+        self.transform = self.transform.transposed();
+        self
+    }
+
+    /// flipped_rows reverses the order of the view's rows.
+    pub fn flipped_rows(mut self) -> Self {
+        self.transform = self.transform.flipped_rows();
+        self
+    }
+
+    /// flipped_columns reverses the order of the view's columns.
+    pub fn flipped_columns(mut self) -> Self {
+        self.transform = self.transform.flipped_columns();
+        self
+    }
+
+    /// rotated_cw turns the view a quarter turn clockwise.
+    pub fn rotated_cw(mut self) -> Self {
+        self.transform = self.transform.rotated_cw();
+        self
+    }
+
+    /// rotated_ccw turns the view a quarter turn counter-clockwise.
+    pub fn rotated_ccw(mut self) -> Self {
+        self.transform = self.transform.rotated_ccw();
+        self
+    }
+
+    /// rotated_180 turns the view a half turn.
+    pub fn rotated_180(mut self) -> Self {
+        self.transform = self.transform.rotated_180();
+        self
+    }
+
+    /// cropped restricts the view to a range of its own addresses.
+    pub fn cropped(mut self, range: Range<MatrixAddress<I>>) -> Result<Self> {
+        self.transform = self.transform.cropped(range)?;
+        Ok(self)
+    }
+}
+
+impl <'a, T, I> Tensor<T, I, MatrixAddress<I>, 2> for TransformedView<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn range(&self) -> Range<MatrixAddress<I>> {
+        Range {
+            start: MatrixAddress::default(),
+            end: MatrixAddress {
+                row: self.transform.row_count(),
+                column: self.transform.column_count(),
+            },
+        }
+    }
+
+    fn get(&self, address: MatrixAddress<I>) -> Option<&T> {
+        self.underlay.get(self.transform.apply(address)?)
+    }
+
+    fn get_mut(&mut self, address: MatrixAddress<I>) -> Option<&mut T> {
+        self.underlay.get_mut(self.transform.apply(address)?)
+    }
+}
+
+impl<'a, T, I> Index<MatrixAddress<I>> for TransformedView<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    type Output = T;
+
+    fn index(&self, address: MatrixAddress<I>) -> &Self::Output {
+        match self.get(address) {
+            None => panic!("out of range index via Index trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl<'a, T, I> IndexMut<MatrixAddress<I>> for TransformedView<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn index_mut(&mut self, address: MatrixAddress<I>) -> &mut Self::Output {
+        match self.get_mut(address) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
+    }
+}
+
+impl <'a: 'c, 'c, T, I> Matrix<'c, T, I> for TransformedView<'a, T, I>
+where
+    T: 'static,
+    I: Coordinate,
+{
+    fn row_count(&self) -> I {
+        self.transform.row_count()
+    }
+
+    fn column_count(&self) -> I {
+        self.transform.column_count()
+    }
+
+    fn iter(&'c self) -> MatrixValueIterator<'c, T, I> {
+        MatrixValueIterator::new(self)
+    }
+
+    fn addresses(&self) -> MatrixForwardIterator<I> {
+        MatrixForwardIterator::new(MatrixAddress {
+            row: self.row_count(),
+            column: self.column_count(),
+        })
+    }
+
+    fn indexed_iter(&'c self) -> MatrixForwardIndexedIterator<'c, T, I> {
+        MatrixForwardIndexedIterator::new(self)
+    }
+
+    fn row(&'c self, row_num: I) -> Option<Row<'c, T, I>> {
+        if row_num >= I::default() && row_num < self.row_count() {
+            Some(Row::new(self, row_num))
+        } else {
+            None
+        }
+    }
+
+    fn column(&'c self, col_num: I) -> Option<Column<'c, T, I>> {
+        if col_num >= I::default() && col_num < self.column_count() {
+            Some(Column::new(self, col_num))
+        } else {
+            None
+        }
+    }
+
+    fn rows(&'c self) -> MatrixRowsIterator<'c, T, I> {
+        MatrixRowsIterator::new(self)
+    }
+
+    fn columns(&'c self) -> MatrixColumnsIterator<'c, T, I> {
+        MatrixColumnsIterator::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::factories::{new_flipped_columns, new_flipped_rows, new_rotated_180, new_rotated_ccw, new_rotated_cw, new_transformed_view};
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn u8addr(row: u8, column: u8) -> MatrixAddress<u8> {
+        MatrixAddress { row, column }
+    }
+
+    fn base() -> crate::DenseMatrix<String, u8> {
+        FormatOptions::default()
+            .parse_matrix::<String, u8>("123\n456", |x| x.to_string())
+            .unwrap()
+    }
+
+    fn render(view: &TransformedView<String, u8>) -> String {
+        FormatOptions::default().format(view, |x| x.to_string())
+    }
+
+    #[test]
+    fn orientations() {
+        let mut m = base();
+        assert_eq!(render(&new_transformed_view(&mut m)), "123\n456");
+        assert_eq!(render(&new_transformed_view(&mut m).transposed()), "14\n25\n36");
+        assert_eq!(render(&new_transformed_view(&mut m).flipped_rows()), "456\n123");
+        assert_eq!(render(&new_transformed_view(&mut m).flipped_columns()), "321\n654");
+        assert_eq!(render(&new_transformed_view(&mut m).rotated_cw()), "41\n52\n63");
+        assert_eq!(render(&new_transformed_view(&mut m).rotated_ccw()), "36\n25\n14");
+        assert_eq!(render(&new_transformed_view(&mut m).rotated_180()), "654\n321");
+        assert_eq!(render(&new_transformed_view(&mut m).rotated_cw().rotated_cw()), "654\n321");
+        assert_eq!(render(&new_transformed_view(&mut m).rotated_cw().rotated_ccw()), "123\n456");
+    }
+
+    #[test]
+    fn find_pattern_in_orientations() {
+        let haystack = FormatOptions::default()
+            .parse_matrix::<char, u8>("ab..\ncd..\n..bd\n..ac", |x| x.chars().next().unwrap())
+            .unwrap();
+        let pattern = FormatOptions::default()
+            .parse_matrix::<char, u8>("ab\ncd", |x| x.chars().next().unwrap())
+            .unwrap();
+        assert_eq!(haystack.find_pattern(&pattern), vec![u8addr(0, 0)]);
+        let oriented = haystack.find_pattern_oriented(&pattern);
+        assert_eq!(oriented.len(), 2);
+        assert_eq!(oriented[0], (u8addr(0, 0), AddressTransform::identity(2, 2)));
+        assert_eq!(oriented[1].0, u8addr(2, 2));
+        let mut m = pattern.clone();
+        let view = TransformedView { underlay: &mut m, transform: oriented[1].1 };
+        assert_eq!(FormatOptions::default().format(&view, |c| c.to_string()), "bd\nac");
+        let empty = crate::factories::new_default_matrix_of::<char, u8>(crate::shape::Shape::default()).unwrap();
+        assert!(haystack.find_pattern(&empty).is_empty());
+    }
+
+    #[test]
+    fn crop_composes_with_orientation() {
+        let mut m = base();
+        let view = new_transformed_view(&mut m).rotated_cw().cropped(u8addr(1, 0)..u8addr(3, 1)).unwrap();
+        assert_eq!(render(&view), "5\n6");
+        let flipped = view.flipped_rows();
+        assert_eq!(render(&flipped), "6\n5");
+        assert_eq!(flipped.transform().window(), u8addr(1, 1)..u8addr(2, 3));
+        let err = flipped.cropped(u8addr(0, 0)..u8addr(3, 1));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn apply_and_invert_round_trip() {
+        let t = AddressTransform::<u8>::identity(3, 5)
+            .rotated_ccw()
+            .flipped_columns()
+            .cropped(u8addr(1, 1)..u8addr(4, 3))
+            .unwrap();
+        for row in 0..t.row_count() {
+            for column in 0..t.column_count() {
+                let v = u8addr(row, column);
+                let u = t.apply(v).unwrap();
+                assert_eq!(t.invert(u), Some(v));
+            }
+        }
+        assert_eq!(t.apply(u8addr(3, 0)), None);
+    }
+
+    #[test]
+    fn mutation_through_view() {
+        let mut m = base();
+        let mut view = new_transformed_view(&mut m).rotated_cw();
+        view[u8addr(0, 0)] = "x".to_string();
+        for column in 0..view.column_count() {
+            view[u8addr(2, column)].push('!');
+        }
+        let got = FormatOptions::default().format(&m, |x| x.to_string());
+        assert_eq!(got, "123!\nx56!");
+    }
+
+    #[test]
+    fn rotation_factories() {
+        let mut m = base();
+        assert_eq!(render(&new_rotated_cw(&mut m)), "41\n52\n63");
+        assert_eq!(render(&new_rotated_180(&mut m)), "654\n321");
+        let mut ccw: RotatedMatrix<String, u8> = new_rotated_ccw(&mut m);
+        assert_eq!(ccw[u8addr(0, 1)], "6");
+        ccw[u8addr(2, 0)] = "x".to_string();
+        assert_eq!(FormatOptions::default().format(&m, |x| x.to_string()), "x23\n456");
+    }
+
+    #[test]
+    fn flip_factories() {
+        let mut m = base();
+        let mirrored: FlippedMatrix<String, u8> = new_flipped_columns(&mut m);
+        let rows: Vec<Vec<String>> = mirrored.rows().map(|r| r.iter().cloned().collect()).collect();
+        assert_eq!(rows, vec![vec!["3", "2", "1"], vec!["6", "5", "4"]]);
+        let flipped = new_flipped_rows(&mut m);
+        let columns: Vec<Vec<String>> = flipped.columns().map(|c| c.iter().cloned().collect()).collect();
+        assert_eq!(columns, vec![vec!["4", "1"], vec!["5", "2"], vec!["6", "3"]]);
+    }
+
+    #[test]
+    fn to_dense_materializes_view() {
+        let mut m = base();
+        let rotated = new_rotated_cw(&mut m).to_dense();
+        assert_eq!(rotated.row_count(), 3);
+        assert_eq!(FormatOptions::default().format(&rotated, |x| x.to_string()), "41\n52\n63");
+        m[u8addr(0, 0)] = "x".to_string();
+        assert_eq!(rotated[u8addr(0, 1)], "1");
+    }
+}
@@ -4,8 +4,9 @@ use crate::column::Column;
 use crate::row::Row;
 
 /// TransposedMatrix builds a transposed view over another Matrix.
-/// Because IndexMut is a required trait of Matrix, the matrix we
-/// construct the transposed view over must be mutable.
+/// The underlay is borrowed mutably so that get_mut and IndexMut can write
+/// through the view.  It does not implement MatrixMut: iterating the underlay's
+/// cells mutably in transposed order would mean buffering every cell.
 pub struct TransposedMatrix<'a, T, I>
 where
     I: Coordinate {
@@ -47,10 +48,14 @@ where
 
 impl<'a, T, I> IndexMut<MatrixAddress<I>> for TransposedMatrix<'a, T, I>
 where
+    T: 'static,
     I: Coordinate,
 {
     fn index_mut(&mut self, index: MatrixAddress<I>) -> &mut Self::Output {
-        self.underlay.index_mut(index.transpose())
+        match self.get_mut(index) {
+            None => panic!("out of range index via IndexMut trait"),
+            Some(v) => v,
+        }
     }
 }
 
@@ -193,6 +198,17 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn transpose_linear_index() {
+        let mut base = FormatOptions::default()
+            .parse_matrix::<String, u8>("123\n456", |x| x.to_string())
+            .unwrap();
+        let transposed = new_transposed_matrix(&mut base);
+        assert_eq!(transposed.address_to_index(u8addr(2, 1)), Some(5));
+        assert_eq!(transposed.index_to_address(3), Some(u8addr(1, 1)));
+        assert_eq!(transposed.index_to_address(6), None);
+    }
+
     #[test]
     fn transpose_row() {
         let mut base = FormatOptions::default()
@@ -0,0 +1,96 @@
+// Copyright 2025 Jeffrey B. Stewart <jeff@stewart.net>.  All Rights Reserved.
+
+use crate::Coordinate;
+use crate::dense_matrix::DenseMatrix;
+use crate::matrix_address::Direction;
+use crate::scan::ScanStrategy;
+
+const EDGES: [Direction; 4] = [Direction::North, Direction::South, Direction::East, Direction::West];
+
+impl <T, I> DenseMatrix<T, I>
+where
+    I: Coordinate,
+{
+    /// visibility_map returns a matrix that is true for each cell visible from
+    /// at least one edge when looking along its row or column: every cell
+    /// between it and that edge must be shorter, where taller(a, b) reports
+    /// whether a is taller than b.  Edge cells are always visible.  Each line is
+    /// scanned once per direction, keeping only the tallest cell so far.
+    pub fn visibility_map(&self, taller: impl Fn(&T, &T) -> bool) -> DenseMatrix<bool, I> {
+        let mut visible = vec![false; self.data.len()];
+        self.scan_edges(|line| {
+            let mut tallest: Option<usize> = None;
+            for &index in line.iter() {
+                if tallest.is_none_or(|t| taller(&self.data[index], &self.data[t])) {
+                    visible[index] = true;
+                    tallest = Some(index);
+                }
+            }
+        });
+        DenseMatrix::new(self.columns, self.rows, visible)
+    }
+
+    /// scenic_scores returns, for each cell, the product of its viewing
+    /// distances in the four cardinal directions.  A viewing distance counts
+    /// the cells seen before the view is blocked by a cell at least as tall, or
+    /// the edge is reached, so edge cells score 0.  A stack of the cells that
+    /// could still block the view makes each line linear.
+    pub fn scenic_scores(&self) -> DenseMatrix<usize, I>
+    where
+        T: PartialOrd,
+    {
+        let mut scores = vec![1usize; self.data.len()];
+        self.scan_edges(|line| {
+            // Lines start at the edge being looked towards.
+            let mut blockers: Vec<usize> = Vec::new();
+            for (position, &index) in line.iter().enumerate() {
+                while blockers.last().is_some_and(|b| self.data[line[*b]] < self.data[index]) {
+                    blockers.pop();
+                }
+                scores[index] *= position - blockers.last().copied().unwrap_or(0);
+                blockers.push(position);
+            }
+        });
+        DenseMatrix::new(self.columns, self.rows, scores)
+    }
+
+    // scan_edges calls kernel with each row and column of cell indices, once in
+    // each direction, starting from the edge the direction points towards.
+    fn scan_edges(&self, mut kernel: impl FnMut(&[usize])) {
+        let mut indices = DenseMatrix::new(self.columns, self.rows, (0..self.data.len()).collect());
+        for direction in EDGES {
+            if indices.scan_lines(direction, ScanStrategy::Strided, |line| kernel(line)).is_err() {
+                panic!("scan of a cardinal direction failed.  This should be unreachable.");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::FormatOptions;
+    use super::*;
+
+    fn forest() -> DenseMatrix<u8, u8> {
+        FormatOptions::default()
+            .parse_matrix("30373\n25512\n65332\n33549\n35390", |x| x.parse().unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn visible_trees() {
+        let visible = forest().visibility_map(|a, b| a > b);
+        assert_eq!(visible.data.iter().filter(|v| **v).count(), 21);
+        assert!(visible.data[6]);
+        assert!(!visible.data[8]);
+    }
+
+    #[test]
+    fn scenic_score() {
+        let scores = forest().scenic_scores();
+        assert_eq!(scores.data[2 + 5], 4);
+        assert_eq!(scores.data[3 * 5 + 2], 8);
+        assert_eq!(scores.data.iter().max(), Some(&8));
+        assert_eq!(scores.data[0], 0);
+    }
+}